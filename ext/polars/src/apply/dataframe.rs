@@ -4,7 +4,8 @@ use polars_core::frame::row::{rows_to_schema_first_non_null, Row};
 use polars_core::series::SeriesIter;
 
 use super::*;
-use crate::{RbDataFrame, RbPolarsErr, RbSeries, Wrap};
+use crate::conversion::get_df;
+use crate::{RbDataFrame, RbPolarsErr, RbSeries, RbValueError, Wrap};
 
 fn get_iters(df: &DataFrame) -> Vec<SeriesIter> {
     df.get_columns().iter().map(|s| s.iter()).collect()
@@ -301,3 +302,58 @@ pub fn apply_lambda_with_rows_output<'a>(
         DataFrame::from_rows_iter_and_schema(iter, &schema)
     }
 }
+
+// calls the lambda with a group and checks the frame it returns against the declared schema
+pub fn apply_lambda_group(
+    lambda: Value,
+    group: DataFrame,
+    schema: &Schema,
+) -> PolarsResult<DataFrame> {
+    let result: Value = lambda
+        .funcall("call", (RbDataFrame::new(group),))
+        .map_err(|e| PolarsError::ComputeError(format!("{}", e).into()))?;
+    let result = get_df(result).map_err(|e| PolarsError::ComputeError(format!("{}", e).into()))?;
+    let result_schema = result.schema();
+    if &result_schema != schema {
+        return Err(PolarsError::ComputeError(
+            format!(
+                "expected schema {}, got {}",
+                format_schema(schema),
+                format_schema(&result_schema)
+            )
+            .into(),
+        ));
+    }
+    Ok(result)
+}
+
+// calls the lambda with each group and stacks the frames it returns
+pub fn apply_lambda_groups(groups: Vec<DataFrame>, lambda: Value) -> RbResult<DataFrame> {
+    let mut out: Option<DataFrame> = None;
+    for group in groups {
+        let result: Value = lambda.funcall("call", (RbDataFrame::new(group),))?;
+        let result = get_df(result)?;
+        match &mut out {
+            Some(df) => {
+                if df.schema() != result.schema() {
+                    return Err(RbValueError::new_err(format!(
+                        "groups returned frames with different schemas: expected {}, got {}",
+                        format_schema(&df.schema()),
+                        format_schema(&result.schema())
+                    )));
+                }
+                df.vstack_mut(&result).map_err(RbPolarsErr::from)?;
+            }
+            None => out = Some(result),
+        }
+    }
+    Ok(out.unwrap_or_else(DataFrame::empty))
+}
+
+fn format_schema(schema: &Schema) -> String {
+    let fields: Vec<String> = schema
+        .iter()
+        .map(|(name, dtype)| format!("{}: {}", name, dtype))
+        .collect();
+    format!("{{{}}}", fields.join(", "))
+}
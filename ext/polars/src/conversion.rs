@@ -1,4 +1,4 @@
-use magnus::{class, r_hash::ForEach, RArray, RHash, Symbol, TryConvert, Value, QNIL};
+use magnus::{class, r_hash::ForEach, RArray, RHash, RString, Symbol, TryConvert, Value, QNIL};
 use polars::chunked_array::object::PolarsObjectSafe;
 use polars::chunked_array::ops::{FillNullLimit, FillNullStrategy};
 use polars::datatypes::AnyValue;
@@ -131,6 +131,22 @@ impl From<Wrap<AnyValue<'_>>> for Value {
                     t.funcall::<_, _, Value>("utc", ()).unwrap()
                 }
             }
+            AnyValue::Utf8Owned(v) => Value::from(v.as_str()),
+            AnyValue::List(s) => RArray::from_iter(s.iter().map(|av| Value::from(Wrap(av)))).into(),
+            ref av @ AnyValue::Struct(_, _, flds) => {
+                let h = RHash::new();
+                for (fld, v) in flds.iter().zip(av._iter_struct_av()) {
+                    h.aset(fld.name().as_str(), Value::from(Wrap(v))).unwrap();
+                }
+                h.into()
+            }
+            AnyValue::StructOwned(payload) => {
+                let h = RHash::new();
+                for (fld, v) in payload.1.iter().zip(payload.0) {
+                    h.aset(fld.name().as_str(), Value::from(Wrap(v))).unwrap();
+                }
+                h.into()
+            }
             _ => todo!(),
         }
     }
@@ -181,7 +197,15 @@ impl TryConvert for Wrap<DataType> {
 impl<'s> TryConvert for Wrap<AnyValue<'s>> {
     fn try_convert(ob: Value) -> RbResult<Self> {
         // TODO improve
-        if let Ok(v) = ob.try_convert::<i64>() {
+        if ob.is_nil() {
+            Ok(AnyValue::Null.into())
+        } else if ob.is_kind_of(class::true_class()) {
+            Ok(AnyValue::Boolean(true).into())
+        } else if ob.is_kind_of(class::false_class()) {
+            Ok(AnyValue::Boolean(false).into())
+        } else if let Some(v) = RString::from_value(ob) {
+            Ok(AnyValue::Utf8Owned(v.to_string()?.into()).into())
+        } else if let Ok(v) = ob.try_convert::<i64>() {
             Ok(AnyValue::Int64(v).into())
         } else if let Ok(v) = ob.try_convert::<f64>() {
             Ok(AnyValue::Float64(v).into())
@@ -333,8 +357,7 @@ impl TryConvert for Wrap<JoinType> {
             "outer" => JoinType::Outer,
             "semi" => JoinType::Semi,
             "anti" => JoinType::Anti,
-            // #[cfg(feature = "cross_join")]
-            // "cross" => JoinType::Cross,
+            "cross" => JoinType::Cross,
             v => {
                 return Err(RbValueError::new_err(format!(
                 "how must be one of {{'inner', 'left', 'outer', 'semi', 'anti', 'cross'}}, got {}",
@@ -1,22 +1,270 @@
+use calamine::DataType as CellType;
+use magnus::block::yield_value;
 use magnus::{r_hash::ForEach, RArray, RHash, RString, Value};
+use polars::frame::row::Row;
 use polars::frame::NullStrategy;
 use polars::io::avro::AvroCompression;
 use polars::io::mmap::ReaderBytes;
 use polars::io::RowCount;
 use polars::prelude::pivot::{pivot, pivot_stable};
 use polars::prelude::*;
+use polars_core::utils::get_supertype;
 use std::cell::RefCell;
 use std::io::{BufWriter, Cursor};
 use std::ops::Deref;
 
 use crate::apply::dataframe::{
-    apply_lambda_unknown, apply_lambda_with_bool_out_type, apply_lambda_with_primitive_out_type,
-    apply_lambda_with_utf8_out_type,
+    apply_lambda_groups, apply_lambda_unknown, apply_lambda_with_bool_out_type,
+    apply_lambda_with_primitive_out_type, apply_lambda_with_utf8_out_type,
 };
 use crate::conversion::*;
 use crate::file::{get_file_like, get_mmap_bytes_reader};
-use crate::series::{to_rbseries_collection, to_series_collection};
-use crate::{series, RbExpr, RbLazyFrame, RbPolarsErr, RbResult, RbSeries};
+use crate::lazy::utils::rb_exprs_to_exprs;
+use crate::series::{numo_binary, numo_class, to_rbseries_collection, to_series_collection};
+use crate::utils::{escape_partition_value, HIVE_DEFAULT_PARTITION};
+use crate::{
+    series, RbExpr, RbLazyFrame, RbPolarsErr, RbResult, RbSeries, RbTypeError, RbValueError,
+};
+
+// add the suffix to new columns whose names are already taken
+fn suffix_duplicate_names(df: &DataFrame, columns: &mut [Series], suffix: &str) {
+    let mut names: PlHashSet<String> = df
+        .get_column_names()
+        .into_iter()
+        .map(|name| name.to_string())
+        .collect();
+    for s in columns.iter_mut() {
+        if names.contains(s.name()) {
+            let name = format!("{}{}", s.name(), suffix);
+            s.rename(&name);
+        }
+        names.insert(s.name().to_string());
+    }
+}
+
+// the supertype of the values in the inference window, falling back to the
+// first value after it for columns that are only null within the window
+fn infer_row_dtypes(
+    rows: &[Row],
+    width: usize,
+    infer_schema_length: Option<usize>,
+) -> PolarsResult<Vec<DataType>> {
+    let window = infer_schema_length.unwrap_or(rows.len());
+    (0..width)
+        .map(|i| {
+            let mut dtype = DataType::Null;
+            for row in rows.iter().take(window) {
+                let dt = row.0[i].dtype();
+                if dt != DataType::Null && dt != dtype {
+                    dtype = if dtype == DataType::Null {
+                        dt
+                    } else {
+                        get_supertype(&dtype, &dt)?
+                    };
+                }
+            }
+            if dtype == DataType::Null {
+                if let Some(dt) = rows
+                    .iter()
+                    .skip(window)
+                    .map(|row| row.0[i].dtype())
+                    .find(|dt| *dt != DataType::Null)
+                {
+                    dtype = dt;
+                }
+            }
+            Ok(dtype)
+        })
+        .collect()
+}
+
+// cast numeric columns of both frames to their supertype so they can be stacked,
+// leaving both frames untouched if any column cannot be coerced
+fn coerce_supertypes(df: &mut DataFrame, other: &mut DataFrame) -> RbResult<()> {
+    let mut casts = Vec::new();
+    for i in 0..df.width().min(other.width()) {
+        let left = &df.get_columns()[i];
+        let right = &other.get_columns()[i];
+        if left.dtype() == right.dtype() {
+            continue;
+        }
+        let supertype = if left.dtype().is_numeric() && right.dtype().is_numeric() {
+            get_supertype(left.dtype(), right.dtype()).ok()
+        } else {
+            None
+        };
+        let supertype = supertype.ok_or_else(|| {
+            RbTypeError::new_err(format!(
+                "cannot vstack column '{}': incompatible dtypes {} and {}",
+                left.name(),
+                left.dtype(),
+                right.dtype()
+            ))
+        })?;
+        let left = left.cast(&supertype).map_err(RbPolarsErr::from)?;
+        let right = right.cast(&supertype).map_err(RbPolarsErr::from)?;
+        casts.push((i, left, right));
+    }
+    for (i, left, right) in casts {
+        df.replace_at_idx(i, left).map_err(RbPolarsErr::from)?;
+        other.replace_at_idx(i, right).map_err(RbPolarsErr::from)?;
+    }
+    Ok(())
+}
+
+// infers the dtype of a sheet column from the non-empty cells of its first rows,
+// reading whole numbers as integers like the csv reader does
+fn excel_dtype(cells: &[&CellType], infer_schema_length: Option<usize>) -> DataType {
+    let mut dtype = None;
+    let cells = cells
+        .iter()
+        .take(infer_schema_length.unwrap_or(usize::MAX))
+        .filter(|cell| !matches!(cell, CellType::Empty | CellType::Error(_)));
+    for cell in cells {
+        let cell_dtype = match cell {
+            CellType::Int(_) => DataType::Int64,
+            CellType::Float(v) if v.fract() == 0.0 => DataType::Int64,
+            CellType::Float(_) => DataType::Float64,
+            CellType::Bool(_) => DataType::Boolean,
+            CellType::DateTime(_) => match cell.as_datetime() {
+                Some(dt) if dt.timestamp() % 86_400 == 0 => DataType::Date,
+                Some(_) => DataType::Datetime(TimeUnit::Milliseconds, None),
+                None => DataType::Utf8,
+            },
+            _ => DataType::Utf8,
+        };
+        dtype = Some(match (dtype, cell_dtype) {
+            (None, dt) => dt,
+            (Some(a), b) if a == b => a,
+            (Some(DataType::Int64 | DataType::Float64), DataType::Int64 | DataType::Float64) => {
+                DataType::Float64
+            }
+            (
+                Some(DataType::Date | DataType::Datetime(_, _)),
+                DataType::Date | DataType::Datetime(_, _),
+            ) => DataType::Datetime(TimeUnit::Milliseconds, None),
+            _ => DataType::Utf8,
+        });
+    }
+    dtype.unwrap_or(DataType::Utf8)
+}
+
+fn excel_column(name: &str, cells: &[&CellType], dtype: &DataType) -> RbResult<Series> {
+    let invalid = |cell: &CellType| {
+        RbPolarsErr::other(format!(
+            "could not read cell '{}' in column '{}' as {}",
+            cell, name, dtype
+        ))
+    };
+    let epoch = polars::export::chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    let mut s = match dtype {
+        DataType::Int64 => cells
+            .iter()
+            .map(|cell| match cell {
+                CellType::Empty | CellType::Error(_) => Ok(None),
+                CellType::Int(v) => Ok(Some(*v)),
+                CellType::Float(v) if v.fract() == 0.0 => Ok(Some(*v as i64)),
+                cell => Err(invalid(cell)),
+            })
+            .collect::<RbResult<Int64Chunked>>()?
+            .into_series(),
+        DataType::Float64 => cells
+            .iter()
+            .map(|cell| match cell {
+                CellType::Empty | CellType::Error(_) => Ok(None),
+                CellType::Int(v) => Ok(Some(*v as f64)),
+                CellType::Float(v) => Ok(Some(*v)),
+                cell => Err(invalid(cell)),
+            })
+            .collect::<RbResult<Float64Chunked>>()?
+            .into_series(),
+        DataType::Boolean => cells
+            .iter()
+            .map(|cell| match cell {
+                CellType::Empty | CellType::Error(_) => Ok(None),
+                CellType::Bool(v) => Ok(Some(*v)),
+                cell => Err(invalid(cell)),
+            })
+            .collect::<RbResult<BooleanChunked>>()?
+            .into_series(),
+        DataType::Date => cells
+            .iter()
+            .map(|cell| match cell {
+                CellType::Empty | CellType::Error(_) => Ok(None),
+                cell => match cell.as_datetime() {
+                    Some(dt) => Ok(Some((dt.date() - epoch).num_days() as i32)),
+                    None => Err(invalid(cell)),
+                },
+            })
+            .collect::<RbResult<Int32Chunked>>()?
+            .into_date()
+            .into_series(),
+        DataType::Datetime(tu, _) => cells
+            .iter()
+            .map(|cell| match cell {
+                CellType::Empty | CellType::Error(_) => Ok(None),
+                cell => match cell.as_datetime() {
+                    Some(dt) => Ok(Some(dt.timestamp_millis())),
+                    None => Err(invalid(cell)),
+                },
+            })
+            .collect::<RbResult<Int64Chunked>>()?
+            .into_datetime(*tu, None)
+            .into_series(),
+        _ => cells
+            .iter()
+            .map(|cell| match cell {
+                CellType::Empty | CellType::Error(_) => None,
+                CellType::String(v) => Some(v.clone()),
+                CellType::DateTime(_) => cell.as_datetime().map(|dt| dt.to_string()),
+                cell => Some(cell.to_string()),
+            })
+            .collect::<Utf8Chunked>()
+            .into_series(),
+    };
+    s.rename(name);
+    Ok(s)
+}
+
+fn pivot_agg(
+    df: &DataFrame,
+    values: &[String],
+    index: &[String],
+    columns: &[String],
+    aggregate_expr: Expr,
+    maintain_order: bool,
+    sort_columns: bool,
+) -> PolarsResult<DataFrame> {
+    let fun = match maintain_order {
+        true => pivot_stable,
+        false => pivot,
+    };
+    let is_count = matches!(aggregate_expr, Expr::Count);
+    let df = fun(
+        df,
+        values.to_vec(),
+        index.to_vec(),
+        columns.to_vec(),
+        aggregate_expr,
+        sort_columns,
+    )?;
+    if !is_count {
+        return Ok(df);
+    }
+    // missing combinations have a count of zero
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|s| {
+            if index.iter().any(|name| name == s.name()) {
+                Ok(s.clone())
+            } else {
+                s.fill_null(FillNullStrategy::Zero)
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(DataFrame::new_no_checks(columns))
+}
 
 #[magnus::wrap(class = "Polars::RbDataFrame")]
 pub struct RbDataFrame {
@@ -151,8 +399,39 @@ impl RbDataFrame {
         row_count: Option<(String, IdxSize)>,
         low_memory: bool,
     ) -> RbResult<Self> {
+        use polars::export::arrow::io::parquet::read::{infer_schema, read_metadata};
+        use std::io::{Seek, SeekFrom};
+
         let row_count = row_count.map(|(name, offset)| RowCount { name, offset });
-        let mmap_bytes_r = get_mmap_bytes_reader(rb_f)?;
+        let mut mmap_bytes_r = get_mmap_bytes_reader(rb_f)?;
+        if columns.is_some() || projection.is_some() {
+            // check the selection against the file schema before reading any data
+            let metadata = read_metadata(&mut mmap_bytes_r).map_err(RbPolarsErr::arrow)?;
+            let schema = infer_schema(&metadata).map_err(RbPolarsErr::arrow)?;
+            if let Some(columns) = &columns {
+                if let Some(name) = columns
+                    .iter()
+                    .find(|name| !schema.fields.iter().any(|f| &f.name == *name))
+                {
+                    return Err(RbValueError::new_err(format!(
+                        "column '{}' not found in parquet schema",
+                        name
+                    )));
+                }
+            }
+            if let Some(projection) = &projection {
+                if let Some(i) = projection.iter().find(|i| **i >= schema.fields.len()) {
+                    return Err(RbValueError::new_err(format!(
+                        "projection index {} is out of bounds for {} columns",
+                        i,
+                        schema.fields.len()
+                    )));
+                }
+            }
+            mmap_bytes_r
+                .seek(SeekFrom::Start(0))
+                .map_err(RbPolarsErr::io)?;
+        }
         let df = ParquetReader::new(mmap_bytes_r)
             .with_projection(projection)
             .with_columns(columns)
@@ -204,6 +483,50 @@ impl RbDataFrame {
         Ok(RbDataFrame::new(df))
     }
 
+    pub fn read_excel(
+        path: String,
+        sheet_name: Option<String>,
+        sheet_id: Option<usize>,
+        has_header: bool,
+        infer_schema_length: Option<usize>,
+    ) -> RbResult<Self> {
+        use calamine::{open_workbook_auto, Reader};
+
+        let mut workbook =
+            open_workbook_auto(&path).map_err(|e| RbPolarsErr::other(e.to_string()))?;
+        let range = match (&sheet_name, sheet_id) {
+            (Some(name), _) => workbook.worksheet_range(name),
+            (None, Some(0)) => {
+                return Err(RbValueError::new_err(
+                    "sheet_id must be greater than 0".into(),
+                ))
+            }
+            // sheet ids start at 1
+            (None, id) => workbook.worksheet_range_at(id.unwrap_or(1) - 1),
+        }
+        .ok_or_else(|| RbValueError::new_err("sheet not found".into()))?
+        .map_err(|e| RbPolarsErr::other(e.to_string()))?;
+
+        let rows: Vec<&[CellType]> = range.rows().collect();
+        let (header, rows) = match rows.split_first() {
+            Some((header, rows)) if has_header => (Some(*header), rows),
+            _ => (None, &rows[..]),
+        };
+        let columns = (0..range.width())
+            .map(|i| {
+                let name = match header.map(|h| &h[i]) {
+                    None | Some(CellType::Empty) => format!("column_{}", i + 1),
+                    Some(cell) => cell.to_string(),
+                };
+                let cells: Vec<&CellType> = rows.iter().map(|row| &row[i]).collect();
+                let dtype = excel_dtype(&cells, infer_schema_length);
+                excel_column(&name, &cells, &dtype)
+            })
+            .collect::<RbResult<Vec<_>>>()?;
+        let df = DataFrame::new(columns).map_err(RbPolarsErr::from)?;
+        Ok(df.into())
+    }
+
     pub fn write_avro(
         &self,
         rb_f: Value,
@@ -228,6 +551,73 @@ impl RbDataFrame {
         Ok(())
     }
 
+    pub fn write_excel(&self, path: String, sheet_name: Option<String>) -> RbResult<()> {
+        use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+        // days between the Excel epoch (1899-12-30) and the Unix epoch
+        const EPOCH_OFFSET: f64 = 25569.0;
+        const NS_PER_DAY: f64 = 86_400_000_000_000.0;
+
+        let xlsx_err = |e: XlsxError| RbPolarsErr::other(e.to_string());
+        let date_format = Format::new().set_num_format("yyyy-mm-dd");
+        let datetime_format = Format::new().set_num_format("yyyy-mm-dd hh:mm:ss");
+        let time_format = Format::new().set_num_format("hh:mm:ss");
+
+        let df = self.df.borrow();
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        if let Some(name) = sheet_name {
+            worksheet.set_name(&name).map_err(xlsx_err)?;
+        }
+
+        for (j, s) in df.get_columns().iter().enumerate() {
+            let col = j as u16;
+            worksheet.write_string(0, col, s.name()).map_err(xlsx_err)?;
+            for i in 0..s.len() {
+                let row = i as u32 + 1;
+                // temporal values are written as Excel serial dates
+                let serial = match s.get(i).map_err(RbPolarsErr::from)? {
+                    // leave nulls as blank cells
+                    AnyValue::Null => None,
+                    AnyValue::Boolean(v) => {
+                        worksheet.write_boolean(row, col, v).map_err(xlsx_err)?;
+                        None
+                    }
+                    AnyValue::Utf8(v) => {
+                        worksheet.write_string(row, col, v).map_err(xlsx_err)?;
+                        None
+                    }
+                    AnyValue::Date(v) => Some((v as f64 + EPOCH_OFFSET, &date_format)),
+                    AnyValue::Datetime(v, tu, _) => {
+                        let per_day = match tu {
+                            TimeUnit::Nanoseconds => NS_PER_DAY,
+                            TimeUnit::Microseconds => NS_PER_DAY / 1e3,
+                            TimeUnit::Milliseconds => NS_PER_DAY / 1e6,
+                        };
+                        Some((v as f64 / per_day + EPOCH_OFFSET, &datetime_format))
+                    }
+                    AnyValue::Time(v) => Some((v as f64 / NS_PER_DAY, &time_format)),
+                    v => {
+                        match v.extract::<f64>() {
+                            Some(n) => worksheet.write_number(row, col, n),
+                            None => worksheet.write_string(row, col, v.to_string()),
+                        }
+                        .map_err(xlsx_err)?;
+                        None
+                    }
+                };
+                if let Some((serial, format)) = serial {
+                    worksheet
+                        .write_number_with_format(row, col, serial, format)
+                        .map_err(xlsx_err)?;
+                }
+            }
+        }
+
+        workbook.save(&path).map_err(xlsx_err)?;
+        Ok(())
+    }
+
     pub fn read_json(rb_f: Value) -> RbResult<Self> {
         // memmap the file first
         let mmap_bytes_r = get_mmap_bytes_reader(rb_f)?;
@@ -288,11 +678,69 @@ impl RbDataFrame {
     }
 
     pub fn read_hashes(
-        _dicts: Value,
-        _infer_schema_length: Option<usize>,
-        _schema_overwrite: Option<Wrap<Schema>>,
+        dicts: RArray,
+        infer_schema_length: Option<usize>,
+        schema_overwrite: Option<Wrap<Schema>>,
     ) -> RbResult<Self> {
-        Err(RbPolarsErr::todo())
+        // column names in order of first appearance
+        let mut names: Vec<String> = Vec::new();
+        let mut hashes = Vec::with_capacity(dicts.len());
+        for d in dicts.each() {
+            let mut values = PlHashMap::new();
+            d?.try_convert::<RHash>()?
+                .foreach(|key: Value, value: Value| {
+                    let key = key.funcall::<_, _, String>("to_s", ())?;
+                    if !names.contains(&key) {
+                        names.push(key.clone());
+                    }
+                    values.insert(key, value);
+                    Ok(ForEach::Continue)
+                })?;
+            hashes.push(values);
+        }
+        let overwrite = schema_overwrite.map(|s| s.0).unwrap_or_default();
+        for name in overwrite.iter_names() {
+            if !names.iter().any(|n| n == name.as_str()) {
+                names.push(name.to_string());
+            }
+        }
+
+        // missing keys become null
+        let rows = hashes
+            .iter()
+            .map(|values| {
+                let row = names
+                    .iter()
+                    .map(|name| match values.get(name) {
+                        Some(v) => v.try_convert::<Wrap<AnyValue>>().map(|v| v.0),
+                        None => Ok(AnyValue::Null),
+                    })
+                    .collect::<RbResult<Vec<_>>>()?;
+                Ok(Row(row))
+            })
+            .collect::<RbResult<Vec<_>>>()?;
+
+        let inferred =
+            infer_row_dtypes(&rows, names.len(), infer_schema_length).map_err(RbPolarsErr::from)?;
+        let fields: Vec<Field> = names
+            .iter()
+            .zip(inferred)
+            .map(|(name, dtype)| {
+                let dtype = match overwrite.get(name) {
+                    Some(DataType::Unknown) | None => dtype,
+                    Some(dt) => dt.clone(),
+                };
+                // columns without any values
+                let dtype = match dtype {
+                    DataType::Null | DataType::Unknown => DataType::Utf8,
+                    dt => dt,
+                };
+                Field::new(name, dtype)
+            })
+            .collect();
+        let schema: Schema = fields.into_iter().into();
+        let df = DataFrame::from_rows_and_schema(&rows, &schema).map_err(RbPolarsErr::from)?;
+        Ok(df.into())
     }
 
     pub fn read_hash(data: RHash) -> RbResult<Self> {
@@ -382,6 +830,109 @@ impl RbDataFrame {
         Ok(())
     }
 
+    pub fn to_arrow(&self, array_ptrs: Vec<usize>, schema_ptrs: Vec<usize>) -> RbResult<()> {
+        use polars::export::arrow::array::{Array, StructArray};
+        use polars::export::arrow::ffi;
+
+        let df = self.df.borrow();
+        // record batches need columns with the same chunk boundaries
+        if df.should_rechunk() {
+            return Err(RbValueError::new_err(
+                "chunks are not aligned, rechunk the DataFrame first".into(),
+            ));
+        }
+        // polars has nowhere to keep schema metadata, so none is exported
+        let dtype = ArrowDataType::Struct(df.schema().to_arrow().fields);
+        let field = ArrowField::new("", dtype.clone(), false);
+        let chunks: Vec<_> = df.iter_chunks().collect();
+        if chunks.len() != array_ptrs.len() || chunks.len() != schema_ptrs.len() {
+            return Err(RbValueError::new_err(format!(
+                "expected {} record batch pointers, got {} array and {} schema pointers",
+                chunks.len(),
+                array_ptrs.len(),
+                schema_ptrs.len()
+            )));
+        }
+        for ((chunk, array_ptr), schema_ptr) in chunks.into_iter().zip(array_ptrs).zip(schema_ptrs)
+        {
+            let array = StructArray::new(dtype.clone(), chunk.into_arrays(), None).boxed();
+            // the caller allocates the structs and takes ownership of their contents
+            unsafe {
+                std::ptr::write(
+                    array_ptr as *mut ffi::ArrowArray,
+                    ffi::export_array_to_c(array),
+                );
+                std::ptr::write(
+                    schema_ptr as *mut ffi::ArrowSchema,
+                    ffi::export_field_to_c(&field),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    pub fn from_arrow(array_ptrs: Vec<usize>, schema_ptrs: Vec<usize>) -> RbResult<Self> {
+        use polars::export::arrow::array::StructArray;
+        use polars::export::arrow::ffi;
+
+        if array_ptrs.len() != schema_ptrs.len() {
+            return Err(RbValueError::new_err(format!(
+                "expected the same number of array and schema pointers, got {} and {}",
+                array_ptrs.len(),
+                schema_ptrs.len()
+            )));
+        }
+        // move every struct's contents out first, leaving the caller's structs
+        // released so they can be freed even if an import below fails
+        let exported: Vec<_> = array_ptrs
+            .into_iter()
+            .zip(schema_ptrs)
+            .map(|(array_ptr, schema_ptr)| unsafe {
+                (
+                    std::ptr::replace(array_ptr as *mut ffi::ArrowArray, ffi::ArrowArray::empty()),
+                    std::ptr::replace(
+                        schema_ptr as *mut ffi::ArrowSchema,
+                        ffi::ArrowSchema::empty(),
+                    ),
+                )
+            })
+            .collect();
+
+        let mut out: Option<DataFrame> = None;
+        for (array, schema) in exported {
+            let (field, array) = unsafe {
+                let field = ffi::import_field_from_c(&schema).map_err(RbPolarsErr::arrow)?;
+                let array = ffi::import_array_from_c(array, field.data_type.clone())
+                    .map_err(RbPolarsErr::arrow)?;
+                (field, array)
+            };
+            let array = array
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .ok_or_else(|| {
+                    RbValueError::new_err(format!(
+                        "expected a record batch, got an array of type {:?}",
+                        field.data_type
+                    ))
+                })?;
+            let columns = array
+                .fields()
+                .iter()
+                .zip(array.values())
+                .map(|(f, values)| Series::try_from((f.name.as_str(), values.clone())))
+                .collect::<PolarsResult<Vec<_>>>()
+                .map_err(RbPolarsErr::from)?;
+            let df = DataFrame::new(columns).map_err(RbPolarsErr::from)?;
+            match out.as_mut() {
+                Some(acc) => {
+                    acc.vstack_mut(&df).map_err(RbPolarsErr::from)?;
+                }
+                None => out = Some(df),
+            }
+        }
+        Ok(out.unwrap_or_default().into())
+    }
+
     pub fn row_tuple(&self, idx: i64) -> Value {
         let idx = if idx < 0 {
             (self.df.borrow().height() as i64 + idx) as usize
@@ -431,6 +982,26 @@ impl RbDataFrame {
         .into()
     }
 
+    pub fn to_hashes(&self) -> RbResult<RArray> {
+        let df = self.df.borrow();
+        let out = RArray::with_capacity(df.height());
+        for idx in 0..df.height() {
+            let h = RHash::new();
+            for s in df.get_columns() {
+                let v = match s.dtype() {
+                    DataType::Object(_) => {
+                        let obj: Option<&ObjectValue> = s.get_object(idx).map(|any| any.into());
+                        obj.unwrap().to_object()
+                    }
+                    _ => Wrap(s.get(idx).map_err(RbPolarsErr::from)?).into(),
+                };
+                h.aset(s.name(), v)?;
+            }
+            out.push(h)?;
+        }
+        Ok(out)
+    }
+
     pub fn write_parquet(
         &self,
         rb_f: Value,
@@ -450,7 +1021,66 @@ impl RbDataFrame {
                 .finish(&mut self.df.borrow_mut())
                 .map_err(RbPolarsErr::from)?;
         } else {
-            todo!();
+            let buf = get_file_like(rb_f, true)?;
+            ParquetWriter::new(buf)
+                .with_compression(compression)
+                .with_statistics(statistics)
+                .with_row_group_size(row_group_size)
+                .finish(&mut self.df.borrow_mut())
+                .map_err(RbPolarsErr::from)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn write_parquet_partitioned(
+        &self,
+        path: String,
+        partition_by: Vec<String>,
+        compression: String,
+        compression_level: Option<i32>,
+        statistics: bool,
+        row_group_size: Option<usize>,
+        include_partition_columns: bool,
+    ) -> RbResult<()> {
+        if partition_by.is_empty() {
+            return Err(RbValueError::new_err(
+                "partition_by must not be empty".into(),
+            ));
+        }
+        let compression = parse_parquet_compression(&compression, compression_level)?;
+
+        let df = self.df.borrow();
+        let partitions = df
+            .partition_by(partition_by.clone())
+            .map_err(RbPolarsErr::from)?;
+        for mut part in partitions {
+            let mut dir = std::path::PathBuf::from(&path);
+            for name in &partition_by {
+                let s = part
+                    .column(name)
+                    .map_err(RbPolarsErr::from)?
+                    .cast(&DataType::Utf8)
+                    .map_err(RbPolarsErr::from)?;
+                let value = s.utf8().map_err(RbPolarsErr::from)?.get(0);
+                let value = match value {
+                    Some(v) => escape_partition_value(v),
+                    None => HIVE_DEFAULT_PARTITION.to_string(),
+                };
+                dir.push(format!("{}={}", escape_partition_value(name), value));
+            }
+            std::fs::create_dir_all(&dir).map_err(RbPolarsErr::io)?;
+
+            if !include_partition_columns {
+                part = part.drop_many(&partition_by);
+            }
+            let f = std::fs::File::create(dir.join("data.parquet")).map_err(RbPolarsErr::io)?;
+            ParquetWriter::new(f)
+                .with_compression(compression)
+                .with_statistics(statistics)
+                .with_row_group_size(row_group_size)
+                .finish(&mut part)
+                .map_err(RbPolarsErr::from)?;
         }
 
         Ok(())
@@ -536,6 +1166,47 @@ impl RbDataFrame {
         Ok(df.into())
     }
 
+    pub fn shuffle(&self, seed: Option<u64>) -> RbResult<Self> {
+        let df = self.df.borrow();
+        let df = df
+            .sample_n(df.height(), false, true, seed)
+            .map_err(RbPolarsErr::from)?;
+        Ok(df.into())
+    }
+
+    pub fn sample_stratified(
+        &self,
+        by: String,
+        n: Option<usize>,
+        frac: Option<f64>,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> RbResult<Self> {
+        let df = self.df.borrow();
+        let groups = df
+            .partition_by_stable(vec![by])
+            .map_err(RbPolarsErr::from)?;
+        let mut out = df.head(Some(0));
+        for (i, group) in groups.iter().enumerate() {
+            // vary the seed per group so equal-sized groups are not sampled identically
+            let seed = seed.map(|seed| seed.wrapping_add(i as u64));
+            let sampled = match (n, frac) {
+                (Some(n), None) if n > group.height() && !with_replacement => group.clone(),
+                (Some(n), None) => group.sample_n(n, with_replacement, shuffle, seed),
+                (None, Some(frac)) => group.sample_frac(frac, with_replacement, shuffle, seed),
+                _ => {
+                    return Err(RbValueError::new_err(
+                        "must specify exactly one of n or frac".into(),
+                    ))
+                }
+            }
+            .map_err(RbPolarsErr::from)?;
+            out.vstack_mut(&sampled).map_err(RbPolarsErr::from)?;
+        }
+        Ok(out.into())
+    }
+
     pub fn rechunk(&self) -> Self {
         self.df.borrow().agg_chunks().into()
     }
@@ -549,6 +1220,18 @@ impl RbDataFrame {
         to_rbseries_collection(cols)
     }
 
+    pub fn iter_columns(&self) -> RbResult<()> {
+        let width = self.df.borrow().width();
+        for i in 0..width {
+            // release the borrow before yielding
+            let s = self.df.borrow().select_at_idx(i).cloned();
+            if let Some(s) = s {
+                let _: Value = yield_value(RbSeries::new(s))?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn columns(&self) -> Vec<String> {
         self.df
             .borrow()
@@ -578,6 +1261,10 @@ impl RbDataFrame {
         self.df.borrow().n_chunks()
     }
 
+    pub fn should_rechunk(&self) -> bool {
+        self.df.borrow().should_rechunk()
+    }
+
     pub fn shape(&self) -> (usize, usize) {
         self.df.borrow().shape()
     }
@@ -590,47 +1277,87 @@ impl RbDataFrame {
         self.df.borrow().width()
     }
 
-    pub fn hstack_mut(&self, columns: RArray) -> RbResult<()> {
-        let columns = to_series_collection(columns)?;
-        self.df
-            .borrow_mut()
-            .hstack_mut(&columns)
-            .map_err(RbPolarsErr::from)?;
+    pub fn hstack_mut(&self, columns: RArray, suffix: Option<String>) -> RbResult<()> {
+        let mut columns = to_series_collection(columns)?;
+        let mut df = self.df.borrow_mut();
+        if let Some(suffix) = suffix {
+            suffix_duplicate_names(&df, &mut columns, &suffix);
+        }
+        df.hstack_mut(&columns).map_err(RbPolarsErr::from)?;
         Ok(())
     }
 
-    pub fn hstack(&self, columns: RArray) -> RbResult<Self> {
-        let columns = to_series_collection(columns)?;
-        let df = self
-            .df
-            .borrow()
-            .hstack(&columns)
-            .map_err(RbPolarsErr::from)?;
+    pub fn hstack(&self, columns: RArray, suffix: Option<String>) -> RbResult<Self> {
+        let mut columns = to_series_collection(columns)?;
+        let df = self.df.borrow();
+        if let Some(suffix) = suffix {
+            suffix_duplicate_names(&df, &mut columns, &suffix);
+        }
+        let df = df.hstack(&columns).map_err(RbPolarsErr::from)?;
         Ok(df.into())
     }
 
     pub fn extend(&self, df: &RbDataFrame) -> RbResult<()> {
+        // clone so a frame can be extended with itself
+        let other = df.df.borrow().clone();
+        {
+            let df = self.df.borrow();
+            if df.width() != other.width() {
+                return Err(RbValueError::new_err(format!(
+                    "cannot extend DataFrame of width {} with DataFrame of width {}",
+                    df.width(),
+                    other.width()
+                )));
+            }
+            for (left, right) in df.get_columns().iter().zip(other.get_columns()) {
+                if left.name() != right.name() {
+                    return Err(RbValueError::new_err(format!(
+                        "cannot extend: expected column '{}', got '{}'",
+                        left.name(),
+                        right.name()
+                    )));
+                }
+                if left.dtype() != right.dtype() {
+                    return Err(RbTypeError::new_err(format!(
+                        "cannot extend: column '{}' has dtype {}, got {}",
+                        left.name(),
+                        left.dtype(),
+                        right.dtype()
+                    )));
+                }
+            }
+        }
         self.df
             .borrow_mut()
-            .extend(&df.df.borrow())
+            .extend(&other)
             .map_err(RbPolarsErr::from)?;
         Ok(())
     }
 
-    pub fn vstack_mut(&self, df: &RbDataFrame) -> RbResult<()> {
-        self.df
-            .borrow_mut()
-            .vstack_mut(&df.df.borrow())
-            .map_err(RbPolarsErr::from)?;
+    pub fn vstack_mut(&self, df: &RbDataFrame, coerce: bool) -> RbResult<()> {
+        let mut other = df.df.borrow().clone();
+        if coerce {
+            // stack onto a copy so a failure leaves the receiver unchanged
+            let mut df = self.df.borrow().clone();
+            coerce_supertypes(&mut df, &mut other)?;
+            df.vstack_mut(&other).map_err(RbPolarsErr::from)?;
+            self.df.replace(df);
+        } else {
+            self.df
+                .borrow_mut()
+                .vstack_mut(&other)
+                .map_err(RbPolarsErr::from)?;
+        }
         Ok(())
     }
 
-    pub fn vstack(&self, df: &RbDataFrame) -> RbResult<Self> {
-        let df = self
-            .df
-            .borrow()
-            .vstack(&df.df.borrow())
-            .map_err(RbPolarsErr::from)?;
+    pub fn vstack(&self, df: &RbDataFrame, coerce: bool) -> RbResult<Self> {
+        let mut other = df.df.borrow().clone();
+        let mut df = self.df.borrow().clone();
+        if coerce {
+            coerce_supertypes(&mut df, &mut other)?;
+        }
+        let df = df.vstack(&other).map_err(RbPolarsErr::from)?;
         Ok(df.into())
     }
 
@@ -776,12 +1503,41 @@ impl RbDataFrame {
         }
     }
 
-    pub fn with_row_count(&self, name: String, offset: Option<IdxSize>) -> RbResult<Self> {
-        let df = self
-            .df
-            .borrow()
-            .with_row_count(&name, offset)
-            .map_err(RbPolarsErr::from)?;
+    pub fn with_row_count(
+        &self,
+        name: String,
+        offset: Option<u64>,
+        dtype: Wrap<DataType>,
+    ) -> RbResult<Self> {
+        let df = self.df.borrow();
+        let offset = offset.unwrap_or(0);
+        let max = match &dtype.0 {
+            DataType::UInt32 => u32::MAX as u64,
+            DataType::UInt64 => u64::MAX,
+            dt => {
+                return Err(RbValueError::new_err(format!(
+                    "row count dtype must be u32 or u64, got {}",
+                    dt
+                )))
+            }
+        };
+        let end = offset
+            .checked_add(df.height() as u64)
+            .filter(|end| *end == offset || end - 1 <= max)
+            .ok_or_else(|| {
+                RbValueError::new_err(format!("row count offset {} overflows {}", offset, dtype.0))
+            })?;
+
+        let df = if dtype.0 == DataType::UInt64 {
+            let s = UInt64Chunked::from_vec(&name, (offset..end).collect()).into_series();
+            let mut columns = Vec::with_capacity(df.width() + 1);
+            columns.push(s);
+            columns.extend(df.get_columns().iter().cloned());
+            DataFrame::new(columns)
+        } else {
+            df.with_row_count(&name, Some(offset as IdxSize))
+        }
+        .map_err(RbPolarsErr::from)?;
         Ok(df.into())
     }
 
@@ -793,9 +1549,56 @@ impl RbDataFrame {
         &self,
         id_vars: Vec<String>,
         value_vars: Vec<String>,
+        value_dtypes: Vec<Wrap<DataType>>,
         value_name: Option<String>,
         variable_name: Option<String>,
     ) -> RbResult<Self> {
+        let is_regex = |name: &str| name.starts_with('^') && name.ends_with('$');
+        let mut id_vars = id_vars;
+        let mut value_vars = value_vars;
+        if !value_dtypes.is_empty() || value_vars.iter().any(|v| is_regex(v)) {
+            let df = self.df.borrow();
+            let mut selected: Vec<String> = Vec::new();
+            let mut select = |name: &str| {
+                if !selected.iter().any(|v| v == name) && !id_vars.iter().any(|v| v == name) {
+                    selected.push(name.to_string());
+                }
+            };
+            for name in &value_vars {
+                if is_regex(name) {
+                    let re = regex::Regex::new(name)
+                        .map_err(|e| RbValueError::new_err(e.to_string()))?;
+                    for column in df.get_column_names() {
+                        if re.is_match(column) {
+                            select(column);
+                        }
+                    }
+                } else {
+                    select(name);
+                }
+            }
+            for s in df.get_columns() {
+                if value_dtypes.iter().any(|dt| &dt.0 == s.dtype()) {
+                    select(s.name());
+                }
+            }
+            if selected.is_empty() {
+                return Err(RbValueError::new_err(
+                    "no columns matched value_vars".into(),
+                ));
+            }
+            // index columns default to the remaining columns
+            if id_vars.is_empty() {
+                id_vars = df
+                    .get_column_names()
+                    .into_iter()
+                    .filter(|c| !selected.iter().any(|v| v == c))
+                    .map(|c| c.to_string())
+                    .collect();
+            }
+            value_vars = selected;
+        }
+
         let args = MeltArgs {
             id_vars,
             value_vars,
@@ -807,29 +1610,99 @@ impl RbDataFrame {
         Ok(RbDataFrame::new(df))
     }
 
+    pub fn unpivot(
+        &self,
+        on: RArray,
+        index: Vec<String>,
+        variable_name: Option<String>,
+        value_name: Option<String>,
+    ) -> RbResult<Self> {
+        let on = rb_exprs_to_exprs(on)?;
+        let df = self.df.borrow();
+        // resolve selector expressions to the names of the columns to unpivot
+        let value_vars = if on.is_empty() {
+            Vec::new()
+        } else {
+            let schema = df
+                .clone()
+                .lazy()
+                .select(on)
+                .schema()
+                .map_err(RbPolarsErr::from)?;
+            schema
+                .iter_names()
+                .filter(|name| !index.iter().any(|i| i == name.as_str()))
+                .map(|name| name.to_string())
+                .collect()
+        };
+
+        let args = MeltArgs {
+            id_vars: index,
+            value_vars,
+            value_name,
+            variable_name,
+        };
+
+        let df = df.melt2(args).map_err(RbPolarsErr::from)?;
+        Ok(RbDataFrame::new(df))
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn pivot_expr(
         &self,
         values: Vec<String>,
         index: Vec<String>,
         columns: Vec<String>,
-        aggregate_expr: &RbExpr,
+        aggregate_exprs: RArray,
+        aggregate_names: Vec<String>,
         maintain_order: bool,
         sort_columns: bool,
     ) -> RbResult<Self> {
-        let fun = match maintain_order {
-            true => pivot_stable,
-            false => pivot,
-        };
-        let df = fun(
-            &self.df.borrow(),
-            values,
-            index,
-            columns,
-            aggregate_expr.inner.clone(),
-            sort_columns,
-        )
-        .map_err(RbPolarsErr::from)?;
-        Ok(RbDataFrame::new(df))
+        let aggregate_exprs = rb_exprs_to_exprs(aggregate_exprs)?;
+        if aggregate_exprs.len() != aggregate_names.len() {
+            return Err(RbValueError::new_err(format!(
+                "expected {} aggregation names, got {}",
+                aggregate_exprs.len(),
+                aggregate_names.len()
+            )));
+        }
+        let df = self.df.borrow();
+        let mut out: Option<DataFrame> = None;
+        for (aggregate_expr, aggregate_name) in aggregate_exprs.into_iter().zip(&aggregate_names) {
+            let mut pivoted = pivot_agg(
+                &df,
+                &values,
+                &index,
+                &columns,
+                aggregate_expr,
+                maintain_order,
+                sort_columns,
+            )
+            .map_err(RbPolarsErr::from)?;
+            // an unnamed aggregation keeps the plain column names
+            if !aggregate_name.is_empty() {
+                let names: Vec<String> = pivoted
+                    .get_column_names()
+                    .into_iter()
+                    .filter(|name| !index.iter().any(|i| i == name))
+                    .map(|name| name.to_string())
+                    .collect();
+                for name in names {
+                    pivoted
+                        .rename(&name, &format!("{}_{}", name, aggregate_name))
+                        .map_err(RbPolarsErr::from)?;
+                }
+            }
+            out = Some(match out {
+                None => pivoted,
+                Some(out) => out
+                    .left_join(&pivoted, &index, &index)
+                    .map_err(RbPolarsErr::from)?,
+            });
+        }
+        let out =
+            out.ok_or_else(|| RbValueError::new_err("expected at least one aggregation".into()))?;
+        Ok(out.into())
     }
 
     pub fn partition_by(&self, groups: Vec<String>, stable: bool) -> RbResult<Vec<Self>> {
@@ -842,6 +1715,22 @@ impl RbDataFrame {
         Ok(out.into_iter().map(RbDataFrame::new).collect())
     }
 
+    pub fn groupby_apply(
+        &self,
+        by: Vec<String>,
+        lambda: Value,
+        maintain_order: bool,
+    ) -> RbResult<Self> {
+        let groups = if maintain_order {
+            self.df.borrow().partition_by_stable(by)
+        } else {
+            self.df.borrow().partition_by(by)
+        }
+        .map_err(RbPolarsErr::from)?;
+        let df = apply_lambda_groups(groups, lambda)?;
+        Ok(df.into())
+    }
+
     pub fn shift(&self, periods: i64) -> Self {
         self.df.borrow().shift(periods).into()
     }
@@ -1009,6 +1898,34 @@ impl RbDataFrame {
         self.df.borrow_mut().shrink_to_fit();
     }
 
+    pub fn shrink_dtype(&self, categorical_threshold: Option<usize>) -> RbResult<Self> {
+        let expr = RbExpr::from(col("*")).shrink_dtype(categorical_threshold);
+        let df = self
+            .df
+            .borrow()
+            .clone()
+            .lazy()
+            .select([expr.inner])
+            .collect()
+            .map_err(RbPolarsErr::from)?;
+        Ok(df.into())
+    }
+
+    pub fn set_sorted(&self, column: String, reverse: bool, verify: bool) -> RbResult<Self> {
+        let mut df = self.df.borrow().clone();
+        let s = RbSeries::new(df.column(&column).map_err(RbPolarsErr::from)?.clone());
+        if verify && !(s.is_sorted(reverse, false)? || s.is_sorted(reverse, true)?) {
+            return Err(RbValueError::new_err(format!(
+                "column '{}' is not sorted",
+                column
+            )));
+        }
+        let s = s.set_sorted(reverse);
+        df.replace(&column, s.series.into_inner())
+            .map_err(RbPolarsErr::from)?;
+        Ok(df.into())
+    }
+
     pub fn hash_rows(&self, k0: u64, k1: u64, k2: u64, k3: u64) -> RbResult<RbSeries> {
         let hb = ahash::RandomState::with_seeds(k0, k1, k2, k3);
         let hash = self
@@ -1020,15 +1937,35 @@ impl RbDataFrame {
     }
 
     pub fn transpose(&self, include_header: bool, names: String) -> RbResult<Self> {
-        let mut df = self.df.borrow().transpose().map_err(RbPolarsErr::from)?;
+        // rows per batch, so only one batch of intermediate values is alive at a time
+        const BATCH_SIZE: usize = 10_000;
+
+        let df = self.df.borrow();
+        let height = df.height();
+        let mut columns = Vec::with_capacity(height + 1);
         if include_header {
-            let s = Utf8Chunked::from_iter_values(
-                &names,
-                self.df.borrow().get_columns().iter().map(|s| s.name()),
-            )
-            .into_series();
-            df.insert_at_idx(0, s).unwrap();
+            let s =
+                Utf8Chunked::from_iter_values(&names, df.get_columns().iter().map(|s| s.name()))
+                    .into_series();
+            columns.push(s);
+        }
+        if height <= BATCH_SIZE {
+            let out = df.transpose().map_err(RbPolarsErr::from)?;
+            columns.extend(out.get_columns().iter().cloned());
+        } else {
+            for offset in (0..height).step_by(BATCH_SIZE) {
+                let batch = df
+                    .slice(offset as i64, BATCH_SIZE)
+                    .transpose()
+                    .map_err(RbPolarsErr::from)?;
+                for (i, s) in batch.get_columns().iter().enumerate() {
+                    let mut s = s.clone();
+                    s.rename(&format!("column_{}", offset + i));
+                    columns.push(s);
+                }
+            }
         }
+        let df = DataFrame::new(columns).map_err(RbPolarsErr::from)?;
         Ok(df.into())
     }
 
@@ -1059,6 +1996,43 @@ impl RbDataFrame {
         Ok(out.into())
     }
 
+    // columns are stacked as rows, so the array has shape [width, height]
+    pub fn to_numo(&self) -> RbResult<Value> {
+        let df = self.df.borrow();
+        let mut supertype: Option<DataType> = None;
+        for s in df.get_columns() {
+            if !s.dtype().is_numeric() {
+                return Err(RbTypeError::new_err(format!(
+                    "cannot convert column '{}' of dtype {} to Numo",
+                    s.name(),
+                    s.dtype()
+                )));
+            }
+            supertype = Some(match supertype {
+                Some(dt) => get_supertype(&dt, s.dtype()).map_err(RbPolarsErr::from)?,
+                None => s.dtype().clone(),
+            });
+        }
+        let supertype = match supertype {
+            Some(dt) => dt,
+            None => {
+                return Err(RbValueError::new_err(
+                    "cannot convert empty DataFrame to Numo".into(),
+                ))
+            }
+        };
+
+        let mut stacked = Series::new_empty("", &supertype);
+        for s in df.get_columns() {
+            stacked
+                .append(&s.cast(&supertype).map_err(RbPolarsErr::from)?)
+                .map_err(RbPolarsErr::from)?;
+        }
+        let (class_name, data) = numo_binary(&stacked)?;
+        let shape = RArray::from_vec(vec![df.width(), df.height()]);
+        numo_class(class_name)?.funcall("from_binary", (data, shape))
+    }
+
     pub fn to_struct(&self, name: String) -> RbSeries {
         let s = self.df.borrow().clone().into_struct(&name);
         s.into_series().into()
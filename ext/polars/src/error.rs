@@ -1,4 +1,4 @@
-use magnus::exception::arg_error;
+use magnus::exception::{arg_error, type_error};
 use magnus::Error;
 use polars::error::ArrowError;
 use polars::prelude::PolarsError;
@@ -36,6 +36,14 @@ impl RbValueError {
     }
 }
 
+pub struct RbTypeError {}
+
+impl RbTypeError {
+    pub fn new_err(message: String) -> Error {
+        Error::new(type_error(), message)
+    }
+}
+
 pub struct ComputeError {}
 
 impl ComputeError {
@@ -1,15 +1,84 @@
-use magnus::{RArray, RHash, Value};
+use magnus::{gc, RArray, RHash, Value};
 use polars::io::RowCount;
 use polars::lazy::frame::{LazyFrame, LazyGroupBy};
 use polars::prelude::*;
 use std::cell::RefCell;
 use std::io::{BufWriter, Read};
+use std::path::PathBuf;
+use std::sync::Arc;
 
+use crate::apply::dataframe::apply_lambda_group;
 use crate::conversion::*;
 use crate::file::get_file_like;
 use crate::lazy::utils::rb_exprs_to_exprs;
+use crate::utils::{check_duration, unescape_partition_value, HIVE_DEFAULT_PARTITION};
 use crate::{RbDataFrame, RbExpr, RbPolarsErr, RbResult, RbValueError};
 
+// parses key=value directories into literal columns, typed per key across all files
+fn hive_partitions(paths: &[PathBuf]) -> RbResult<Vec<Vec<Expr>>> {
+    let mut parsed = Vec::with_capacity(paths.len());
+    for file in paths {
+        let pairs: Vec<(String, String)> = file
+            .parent()
+            .into_iter()
+            .flat_map(|dir| dir.components())
+            .filter_map(|c| {
+                let segment = c.as_os_str().to_str()?;
+                let (key, value) = segment.split_once('=')?;
+                let value = match value {
+                    HIVE_DEFAULT_PARTITION => None,
+                    v => Some(unescape_partition_value(v)),
+                };
+                Some((unescape_partition_value(key), value))
+            })
+            .collect();
+        parsed.push(pairs);
+    }
+
+    let keys: Vec<&String> = parsed[0].iter().map(|(k, _)| k).collect();
+    for (file, pairs) in paths.iter().zip(&parsed) {
+        if pairs.len() != keys.len() || pairs.iter().zip(&keys).any(|((k, _), key)| k != *key) {
+            return Err(RbValueError::new_err(format!(
+                "hive partition keys of file '{}' do not match keys of file '{}'",
+                file.display(),
+                paths[0].display()
+            )));
+        }
+    }
+
+    let dtypes: Vec<DataType> = (0..keys.len())
+        .map(|i| {
+            let mut values = parsed.iter().filter_map(|pairs| pairs[i].1.as_deref());
+            if values.clone().all(|v| v.parse::<i64>().is_ok()) {
+                DataType::Int64
+            } else if values.all(|v| v.parse::<f64>().is_ok()) {
+                DataType::Float64
+            } else {
+                DataType::Utf8
+            }
+        })
+        .collect();
+
+    Ok(parsed
+        .iter()
+        .map(|pairs| {
+            pairs
+                .iter()
+                .zip(&dtypes)
+                .map(|((key, value), dtype)| {
+                    let value = match (value, dtype) {
+                        (None, _) => lit(Null {}).cast(dtype.clone()),
+                        (Some(v), DataType::Int64) => lit(v.parse::<i64>().unwrap()),
+                        (Some(v), DataType::Float64) => lit(v.parse::<f64>().unwrap()),
+                        (Some(v), _) => lit(v.as_str()),
+                    };
+                    value.alias(key)
+                })
+                .collect()
+        })
+        .collect())
+}
+
 #[magnus::wrap(class = "Polars::RbLazyGroupBy")]
 pub struct RbLazyGroupBy {
     lgb: RefCell<Option<LazyGroupBy>>,
@@ -31,6 +100,16 @@ impl RbLazyGroupBy {
         let lgb = self.lgb.take().unwrap();
         lgb.tail(Some(n)).into()
     }
+
+    pub fn apply(&self, lambda: Value, schema: Wrap<Schema>) -> RbLazyFrame {
+        let lgb = self.lgb.take().unwrap();
+        let schema = Arc::new(schema.0);
+        // the plan keeps calling the lambda whenever it is collected
+        gc::register_mark_object(lambda);
+        let expected = schema.clone();
+        let function = move |df: DataFrame| apply_lambda_group(lambda, df, &expected);
+        lgb.apply(function, schema).into()
+    }
 }
 
 #[magnus::wrap(class = "Polars::RbLazyFrame")]
@@ -171,17 +250,84 @@ impl RbLazyFrame {
         rechunk: bool,
         row_count: Option<(String, IdxSize)>,
         low_memory: bool,
+        hive_partitioning: bool,
     ) -> RbResult<Self> {
         let row_count = row_count.map(|(name, offset)| RowCount { name, offset });
-        let args = ScanArgsParquet {
-            n_rows,
-            cache,
-            parallel: parallel.0,
-            rechunk,
-            row_count,
-            low_memory,
+        // an existing path is read as is, even if it contains glob characters
+        let is_glob = path.contains(['*', '?', '[']) && !std::path::Path::new(&path).exists();
+
+        if !is_glob && !hive_partitioning {
+            let args = ScanArgsParquet {
+                n_rows,
+                cache,
+                parallel: parallel.0,
+                rechunk,
+                row_count,
+                low_memory,
+            };
+            let lf = LazyFrame::scan_parquet(path, args).map_err(RbPolarsErr::from)?;
+            return Ok(lf.into());
+        }
+
+        let paths = if is_glob {
+            let mut paths = glob::glob(&path)
+                .map_err(|e| RbValueError::new_err(format!("invalid glob pattern: {}", e)))?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| RbPolarsErr::io(e.into_error()))?;
+            paths.sort();
+            if paths.is_empty() {
+                return Err(RbValueError::new_err(format!(
+                    "no files matched glob pattern '{}'",
+                    path
+                )));
+            }
+            paths
+        } else {
+            vec![PathBuf::from(&path)]
         };
-        let lf = LazyFrame::scan_parquet(path, args).map_err(RbPolarsErr::from)?;
+        let partitions = if hive_partitioning {
+            hive_partitions(&paths)?
+        } else {
+            vec![Vec::new(); paths.len()]
+        };
+
+        // n_rows and row_count apply to the combined scan, not to each file
+        let mut lfs = Vec::with_capacity(paths.len());
+        let mut first: Option<(PathBuf, SchemaRef)> = None;
+        for (file, partition) in paths.into_iter().zip(partitions) {
+            let args = ScanArgsParquet {
+                n_rows: None,
+                cache,
+                parallel: parallel.0,
+                rechunk: false,
+                row_count: None,
+                low_memory,
+            };
+            let lf = LazyFrame::scan_parquet(&file, args).map_err(RbPolarsErr::from)?;
+            let schema = lf.schema().map_err(RbPolarsErr::from)?;
+            match &first {
+                Some((first_file, first_schema)) => {
+                    if schema != *first_schema {
+                        return Err(RbValueError::new_err(format!(
+                            "schema of file '{}' does not match schema of file '{}'",
+                            file.display(),
+                            first_file.display()
+                        )));
+                    }
+                }
+                None => first = Some((file, schema)),
+            }
+            lfs.push(lf.with_columns(partition));
+        }
+
+        let mut lf =
+            polars::lazy::dsl::concat(lfs, rechunk, !low_memory).map_err(RbPolarsErr::from)?;
+        if let Some(n) = n_rows {
+            lf = lf.limit(n as IdxSize);
+        }
+        if let Some(rc) = row_count {
+            lf = lf.with_row_count(&rc.name, Some(rc.offset));
+        }
         Ok(lf.into())
     }
 
@@ -212,6 +358,50 @@ impl RbLazyFrame {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn sink_parquet(
+        &self,
+        path: String,
+        compression: String,
+        compression_level: Option<i32>,
+        statistics: bool,
+        row_group_size: Option<usize>,
+        data_pagesize_limit: Option<usize>,
+        maintain_order: bool,
+    ) -> RbResult<()> {
+        let compression = parse_parquet_compression(&compression, compression_level)?;
+
+        let options = ParquetWriteOptions {
+            compression,
+            statistics,
+            row_group_size,
+            data_pagesize_limit,
+            maintain_order,
+        };
+
+        let ldf = self.ldf.clone();
+        ldf.sink_parquet(PathBuf::from(path), options)
+            .map_err(RbPolarsErr::from)?;
+        Ok(())
+    }
+
+    pub fn sink_ipc(
+        &self,
+        path: String,
+        compression: Wrap<Option<IpcCompression>>,
+        maintain_order: bool,
+    ) -> RbResult<()> {
+        let options = IpcWriterOptions {
+            compression: compression.0,
+            maintain_order,
+        };
+
+        let ldf = self.ldf.clone();
+        ldf.sink_ipc(PathBuf::from(path), options)
+            .map_err(RbPolarsErr::from)?;
+        Ok(())
+    }
+
     pub fn describe_plan(&self) -> String {
         self.ldf.describe_plan()
     }
@@ -228,25 +418,54 @@ impl RbLazyFrame {
     pub fn optimization_toggle(
         &self,
         type_coercion: bool,
-        predicate_pushdown: bool,
-        projection_pushdown: bool,
+        predicate_pushdown: Option<bool>,
+        projection_pushdown: Option<bool>,
         simplify_expr: bool,
-        slice_pushdown: bool,
-        cse: bool,
+        slice_pushdown: Option<bool>,
+        cse: Option<bool>,
         allow_streaming: bool,
     ) -> RbLazyFrame {
         let ldf = self.ldf.clone();
-        let ldf = ldf
+        let mut ldf = ldf
             .with_type_coercion(type_coercion)
-            .with_predicate_pushdown(predicate_pushdown)
             .with_simplify_expr(simplify_expr)
-            .with_slice_pushdown(slice_pushdown)
-            .with_common_subplan_elimination(cse)
-            .with_streaming(allow_streaming)
-            .with_projection_pushdown(projection_pushdown);
+            .with_streaming(allow_streaming);
+        // keep the frame's own settings unless explicitly overridden
+        if let Some(predicate_pushdown) = predicate_pushdown {
+            ldf = ldf.with_predicate_pushdown(predicate_pushdown);
+        }
+        if let Some(projection_pushdown) = projection_pushdown {
+            ldf = ldf.with_projection_pushdown(projection_pushdown);
+        }
+        if let Some(slice_pushdown) = slice_pushdown {
+            ldf = ldf.with_slice_pushdown(slice_pushdown);
+        }
+        if let Some(cse) = cse {
+            ldf = ldf.with_common_subplan_elimination(cse);
+        }
         ldf.into()
     }
 
+    pub fn with_predicate_pushdown(&self, toggle: bool) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.with_predicate_pushdown(toggle).into()
+    }
+
+    pub fn with_projection_pushdown(&self, toggle: bool) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.with_projection_pushdown(toggle).into()
+    }
+
+    pub fn with_slice_pushdown(&self, toggle: bool) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.with_slice_pushdown(toggle).into()
+    }
+
+    pub fn with_comm_subplan_elim(&self, toggle: bool) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.with_common_subplan_elimination(toggle).into()
+    }
+
     pub fn sort(&self, by_column: String, reverse: bool, nulls_last: bool) -> Self {
         let ldf = self.ldf.clone();
         ldf.sort(
@@ -281,6 +500,19 @@ impl RbLazyFrame {
         Ok(df.into())
     }
 
+    pub fn collect_streaming(&self) -> RbResult<RbDataFrame> {
+        let ldf = self.ldf.clone().with_streaming(true);
+        let df = ldf.collect().map_err(RbPolarsErr::from)?;
+        Ok(df.into())
+    }
+
+    pub fn collect_with_plan(&self) -> RbResult<(RbDataFrame, String)> {
+        let ldf = self.ldf.clone();
+        let plan = ldf.describe_optimized_plan().map_err(RbPolarsErr::from)?;
+        let df = ldf.collect().map_err(RbPolarsErr::from)?;
+        Ok((df.into(), plan))
+    }
+
     pub fn fetch(&self, n_rows: usize) -> RbResult<RbDataFrame> {
         let ldf = self.ldf.clone();
         let df = ldf.fetch(n_rows).map_err(RbPolarsErr::from)?;
@@ -403,6 +635,30 @@ impl RbLazyFrame {
         let other = other.ldf.clone();
         let left_on = left_on.inner.clone();
         let right_on = right_on.inner.clone();
+        if let Some(tolerance_str) = &tolerance_str {
+            check_duration(tolerance_str).map_err(RbValueError::new_err)?;
+        }
+        if tolerance.is_some() {
+            let schema = ldf
+                .clone()
+                .select([left_on.clone()])
+                .schema()
+                .map_err(RbPolarsErr::from)?;
+            if let Some((name, dtype)) = schema.get_index(0) {
+                if matches!(
+                    dtype,
+                    DataType::Date
+                        | DataType::Datetime(_, _)
+                        | DataType::Duration(_)
+                        | DataType::Time
+                ) {
+                    return Err(RbValueError::new_err(format!(
+                        "tolerance for temporal key '{}' of type {} must be a duration string such as \"10s\"",
+                        name, dtype
+                    )));
+                }
+            }
+        }
         Ok(ldf
             .join_builder()
             .with(other)
@@ -432,13 +688,41 @@ impl RbLazyFrame {
         force_parallel: bool,
         how: Wrap<JoinType>,
         suffix: String,
+        coalesce: bool,
     ) -> RbResult<Self> {
-        let ldf = self.ldf.clone();
-        let other = other.ldf.clone();
-        let left_on = rb_exprs_to_exprs(left_on)?;
-        let right_on = rb_exprs_to_exprs(right_on)?;
+        let mut ldf = self.ldf.clone();
+        let mut other = other.ldf.clone();
+        let mut left_on = rb_exprs_to_exprs(left_on)?;
+        let mut right_on = rb_exprs_to_exprs(right_on)?;
+        if matches!(how.0, JoinType::Cross) && !(left_on.is_empty() && right_on.is_empty()) {
+            return Err(RbValueError::new_err(
+                "cross join does not take join keys".into(),
+            ));
+        }
 
-        Ok(ldf
+        // join on copies of the keys so both original key columns are kept
+        let key_names: Vec<String> = if coalesce {
+            Vec::new()
+        } else {
+            (0..left_on.len())
+                .map(|i| format!("__POLARS_JOIN_KEY_{}", i))
+                .collect()
+        };
+        if !key_names.is_empty() {
+            let alias_keys = |exprs: Vec<Expr>| {
+                exprs
+                    .into_iter()
+                    .zip(&key_names)
+                    .map(|(e, name)| e.alias(name))
+                    .collect::<Vec<_>>()
+            };
+            ldf = ldf.with_columns(alias_keys(left_on));
+            other = other.with_columns(alias_keys(right_on));
+            left_on = key_names.iter().map(|name| col(name)).collect();
+            right_on = left_on.clone();
+        }
+
+        let out = ldf
             .join_builder()
             .with(other)
             .left_on(left_on)
@@ -447,8 +731,12 @@ impl RbLazyFrame {
             .force_parallel(force_parallel)
             .how(how.0)
             .suffix(suffix)
-            .finish()
-            .into())
+            .finish();
+        if key_names.is_empty() {
+            Ok(out.into())
+        } else {
+            Ok(out.drop_columns(key_names).into())
+        }
     }
 
     pub fn with_columns(&self, exprs: RArray) -> RbResult<Self> {
@@ -466,9 +754,47 @@ impl RbLazyFrame {
         ldf.reverse().into()
     }
 
-    pub fn shift(&self, periods: i64) -> Self {
+    pub fn shift(
+        &self,
+        periods: i64,
+        fill_value: Option<&RbExpr>,
+        fill_columns: Vec<String>,
+        fill_values: RArray,
+    ) -> RbResult<Self> {
         let ldf = self.ldf.clone();
-        ldf.shift(periods).into()
+        let fill_value = fill_value.map(|e| e.inner.clone());
+        if fill_columns.is_empty() {
+            return Ok(match fill_value {
+                Some(fill_value) => ldf.shift_and_fill(periods, fill_value),
+                None => ldf.shift(periods),
+            }
+            .into());
+        }
+
+        let fill_values = rb_exprs_to_exprs(fill_values)?;
+        let schema = ldf.schema().map_err(RbPolarsErr::from)?;
+        if let Some(name) = fill_columns.iter().find(|name| schema.get(name).is_none()) {
+            return Err(RbValueError::new_err(format!(
+                "column '{}' not found",
+                name
+            )));
+        }
+        // columns without their own fill use the shared fill value, if any
+        let exprs: Vec<Expr> = schema
+            .iter_names()
+            .map(|name| {
+                let fill = fill_columns
+                    .iter()
+                    .position(|c| c == name.as_str())
+                    .map(|i| fill_values[i].clone())
+                    .or_else(|| fill_value.clone());
+                match fill {
+                    Some(fill) => col(name.as_str()).shift_and_fill(periods, fill),
+                    None => col(name.as_str()).shift(periods),
+                }
+            })
+            .collect();
+        Ok(ldf.select(exprs).into())
     }
 
     pub fn shift_and_fill(&self, periods: i64, fill_value: &RbExpr) -> Self {
@@ -525,9 +851,19 @@ impl RbLazyFrame {
         ldf.quantile(quantile.inner.clone(), interpolation.0).into()
     }
 
-    pub fn explode(&self, column: RArray) -> RbResult<Self> {
-        let ldf = self.ldf.clone();
+    pub fn explode(&self, column: RArray, empty_as_null: bool) -> RbResult<Self> {
+        let mut ldf = self.ldf.clone();
         let column = rb_exprs_to_exprs(column)?;
+        if !empty_as_null {
+            // explode gives a null row for an empty list, so drop those rows first
+            let predicate = column
+                .iter()
+                .map(|e| e.clone().arr().lengths().neq(lit(0)).fill_null(lit(true)))
+                .reduce(|acc, e| acc.and(e));
+            if let Some(predicate) = predicate {
+                ldf = ldf.filter(predicate);
+            }
+        }
         Ok(ldf.explode(column).into())
     }
 
@@ -5,12 +5,20 @@ use polars::lazy::dsl;
 use polars::lazy::dsl::Operator;
 use polars::prelude::*;
 use polars::series::ops::NullBehavior;
+use polars::series::IsSorted;
 
 use crate::conversion::*;
 use crate::lazy::apply::*;
 use crate::lazy::utils::rb_exprs_to_exprs;
-use crate::utils::reinterpret;
-use crate::{RbResult, RbSeries};
+use crate::utils::{
+    check_duration, check_hist_args, check_int_range_args, check_replace_args, combine,
+    combine_output_type, cut, cut_output_type, diff_by, extract_groups, extract_groups_output_type,
+    hist, hist_output_type, is_sorted, list_to_struct, list_to_struct_output_type,
+    literal_alternation, month_boundary, reinterpret, repeat_by, replace, replace_output_type, rle,
+    rle_id, rolling_rank, rolling_rank_output_type, search_sorted_side, struct_field_path,
+    struct_field_path_output_type, struct_path, tz_offset, tz_offset_output_type, wrapping_cast,
+};
+use crate::{RbPolarsErr, RbResult, RbSeries, RbValueError};
 
 #[magnus::wrap(class = "Polars::RbExpr")]
 #[derive(Clone)]
@@ -85,6 +93,22 @@ impl RbExpr {
         self.clone().inner.not().into()
     }
 
+    pub fn not_(&self) -> Self {
+        self.clone()
+            .inner
+            .map(
+                |s| match s.dtype() {
+                    DataType::Boolean => Ok((!s.bool()?).into_series()),
+                    dt => Err(PolarsError::InvalidOperation(
+                        format!("`not_` operation not supported for dtype `{}`", dt).into(),
+                    )),
+                },
+                GetOutput::from_type(DataType::Boolean),
+            )
+            .with_fmt("not_")
+            .into()
+    }
+
     pub fn is_null(&self) -> Self {
         self.clone().inner.is_null().into()
     }
@@ -198,9 +222,23 @@ impl RbExpr {
         self.inner.clone().null_count().into()
     }
 
-    pub fn cast(&self, data_type: Wrap<DataType>, strict: bool) -> RbResult<Self> {
+    pub fn cast(
+        &self,
+        data_type: Wrap<DataType>,
+        strict: bool,
+        wrap_numerical: bool,
+    ) -> RbResult<Self> {
         let dt = data_type.0;
-        let expr = if strict {
+        let expr = if wrap_numerical {
+            let output_type = dt.clone();
+            self.inner
+                .clone()
+                .map(
+                    move |s| wrapping_cast(&s, &dt),
+                    GetOutput::from_type(output_type),
+                )
+                .with_fmt("cast")
+        } else if strict {
             self.inner.clone().strict_cast(dt)
         } else {
             self.inner.clone().cast(dt)
@@ -240,19 +278,78 @@ impl RbExpr {
         self.clone().inner.arg_min().into()
     }
 
-    pub fn search_sorted(&self, element: &RbExpr) -> Self {
-        self.inner
+    pub fn search_sorted(&self, element: &RbExpr, side: String) -> RbResult<Self> {
+        let right = match side.as_str() {
+            "any" => {
+                return Ok(self
+                    .inner
+                    .clone()
+                    .search_sorted(element.inner.clone())
+                    .into())
+            }
+            "left" => false,
+            "right" => true,
+            v => {
+                return Err(RbValueError::new_err(format!(
+                    "side must be one of {{'any', 'left', 'right'}}, got {}",
+                    v
+                )))
+            }
+        };
+        Ok(self
+            .inner
             .clone()
-            .search_sorted(element.inner.clone())
-            .into()
+            .map_many(
+                move |s| search_sorted_side(&s[0], &s[1], right),
+                &[element.inner.clone()],
+                GetOutput::from_type(IDX_DTYPE),
+            )
+            .with_fmt("search_sorted")
+            .into())
     }
 
     pub fn take(&self, idx: &RbExpr) -> Self {
         self.clone().inner.take(idx.inner.clone()).into()
     }
 
+    pub fn gather(&self, idx: &RbExpr, null_on_oob: bool) -> Self {
+        if !null_on_oob {
+            return self.clone().inner.take(idx.inner.clone()).into();
+        }
+        let function = |s: &mut [Series]| {
+            let len = s[0].len() as IdxSize;
+            // negative indices cast to null
+            let idx = s[1].cast(&IDX_DTYPE)?;
+            let idx: IdxCa = idx
+                .idx()?
+                .into_iter()
+                .map(|opt_i| opt_i.filter(|i| *i < len))
+                .collect();
+            s[0].take(&idx)
+        };
+        self.clone()
+            .inner
+            .apply_many(function, &[idx.inner.clone()], GetOutput::same_type())
+            .with_fmt("gather")
+            .into()
+    }
+
     pub fn sort_by(&self, by: RArray, reverse: Vec<bool>) -> RbResult<Self> {
         let by = rb_exprs_to_exprs(by)?;
+        // a single flag applies to every key, so multi-key sorts
+        // within groups (e.g. inside over) use the same direction
+        let reverse = if reverse.len() == 1 && by.len() > 1 {
+            vec![reverse[0]; by.len()]
+        } else {
+            reverse
+        };
+        if reverse.len() != by.len() {
+            return Err(RbValueError::new_err(format!(
+                "the length of `reverse` ({}) does not match the length of `by` ({})",
+                reverse.len(),
+                by.len()
+            )));
+        }
         Ok(self.clone().inner.sort_by(by, reverse).into())
     }
 
@@ -264,9 +361,17 @@ impl RbExpr {
         self.clone().inner.forward_fill(limit).into()
     }
 
-    pub fn shift(&self, periods: i64) -> Self {
-        self.clone().inner.shift(periods).into()
+    pub fn shift(&self, periods: i64, fill_value: Option<&RbExpr>) -> Self {
+        match fill_value {
+            Some(fill_value) => self
+                .clone()
+                .inner
+                .shift_and_fill(periods, fill_value.inner.clone()),
+            None => self.clone().inner.shift(periods),
+        }
+        .into()
     }
+
     pub fn shift_and_fill(&self, periods: i64, fill_value: &RbExpr) -> Self {
         self.clone()
             .inner
@@ -332,12 +437,23 @@ impl RbExpr {
         self.clone().inner.explode().into()
     }
 
-    pub fn take_every(&self, n: usize) -> Self {
-        self.clone()
+    pub fn take_every(&self, n: usize, offset: usize) -> RbResult<Self> {
+        if n == 0 {
+            return Err(RbValueError::new_err("step must be greater than 0".into()));
+        }
+        Ok(self
+            .clone()
             .inner
-            .map(move |s: Series| Ok(s.take_every(n)), GetOutput::same_type())
+            .map(
+                move |s: Series| {
+                    let len = s.len();
+                    Ok(s.slice(offset as i64, len.saturating_sub(offset))
+                        .take_every(n))
+                },
+                GetOutput::same_type(),
+            )
             .with_fmt("take_every")
-            .into()
+            .into())
     }
 
     pub fn tail(&self, n: Option<usize>) -> Self {
@@ -478,8 +594,16 @@ impl RbExpr {
         self.clone().inner.is_in(expr.inner.clone()).into()
     }
 
-    pub fn repeat_by(&self, by: &RbExpr) -> Self {
-        self.clone().inner.repeat_by(by.inner.clone()).into()
+    pub fn repeat_by(&self, by: &RbExpr, negative_as_empty: bool) -> Self {
+        self.clone()
+            .inner
+            .map_many(
+                move |s| repeat_by(&s[0], &s[1], negative_as_empty),
+                &[by.inner.clone()],
+                GetOutput::map_dtype(|dt| DataType::List(Box::new(dt.clone()))),
+            )
+            .with_fmt("repeat_by")
+            .into()
     }
 
     pub fn pow(&self, exponent: &RbExpr) -> Self {
@@ -506,8 +630,30 @@ impl RbExpr {
         self.clone().inner.product().into()
     }
 
-    pub fn shrink_dtype(&self) -> Self {
-        self.inner.clone().shrink_dtype().into()
+    pub fn shrink_dtype(&self, categorical_threshold: Option<usize>) -> Self {
+        match categorical_threshold {
+            Some(threshold) => self
+                .clone()
+                .inner
+                .map(
+                    move |s| match s.dtype() {
+                        DataType::Utf8 if s.n_unique()? < threshold => {
+                            s.cast(&DataType::Categorical(None))
+                        }
+                        DataType::Utf8 => Ok(s),
+                        _ => s.shrink_type(),
+                    },
+                    // the narrowed dtype depends on the data, so it is only known once computed
+                    GetOutput::map_dtype(|dt| match dt {
+                        DataType::Utf8 => DataType::Unknown,
+                        dt if dt.is_numeric() => DataType::Unknown,
+                        dt => dt.clone(),
+                    }),
+                )
+                .with_fmt("shrink_dtype")
+                .into(),
+            None => self.inner.clone().shrink_dtype().into(),
+        }
     }
 
     pub fn str_parse_date(
@@ -646,6 +792,23 @@ impl RbExpr {
             .into()
     }
 
+    pub fn str_reverse(&self) -> Self {
+        let function = |s: Series| {
+            let ca = s.utf8()?;
+            let mut out: Utf8Chunked = ca
+                .into_iter()
+                .map(|opt_v| opt_v.map(|v| v.chars().rev().collect::<String>()))
+                .collect();
+            out.rename(ca.name());
+            Ok(out.into_series())
+        };
+        self.clone()
+            .inner
+            .map(function, GetOutput::from_type(DataType::Utf8))
+            .with_fmt("str.reverse")
+            .into()
+    }
+
     pub fn str_replace(&self, pat: &RbExpr, val: &RbExpr, literal: bool) -> Self {
         self.inner
             .clone()
@@ -674,13 +837,156 @@ impl RbExpr {
         self.clone().inner.str().rjust(width, fillchar).into()
     }
 
-    pub fn str_contains(&self, pat: String, literal: Option<bool>) -> Self {
-        match literal {
-            Some(true) => self.inner.clone().str().contains_literal(pat).into(),
+    pub fn str_pad_start(&self, length: usize, fill_char: char) -> Self {
+        self.clone().inner.str().rjust(length, fill_char).into()
+    }
+
+    pub fn str_pad_end(&self, length: usize, fill_char: char) -> Self {
+        self.clone().inner.str().ljust(length, fill_char).into()
+    }
+
+    pub fn str_contains(
+        &self,
+        pat: String,
+        literal: Option<bool>,
+        case_insensitive: Option<bool>,
+    ) -> Self {
+        match (literal, case_insensitive) {
+            (Some(true), Some(true)) => {
+                // fast path only folds ASCII characters
+                let pat = pat.to_ascii_lowercase();
+                let function = move |s: Series| {
+                    let ca = s.utf8()?;
+                    let mut out: BooleanChunked = ca
+                        .into_iter()
+                        .map(|opt_v| opt_v.map(|v| v.to_ascii_lowercase().contains(&pat)))
+                        .collect();
+                    out.rename(ca.name());
+                    Ok(out.into_series())
+                };
+                self.clone()
+                    .inner
+                    .map(function, GetOutput::from_type(DataType::Boolean))
+                    .with_fmt("str.contains")
+                    .into()
+            }
+            (_, Some(true)) => self
+                .inner
+                .clone()
+                .str()
+                .contains(format!("(?i){}", pat))
+                .into(),
+            (Some(true), _) => self.inner.clone().str().contains_literal(pat).into(),
             _ => self.inner.clone().str().contains(pat).into(),
         }
     }
 
+    pub fn str_contains_any(
+        &self,
+        patterns: Vec<String>,
+        ascii_case_insensitive: bool,
+    ) -> RbResult<Self> {
+        let re = literal_alternation(&patterns, ascii_case_insensitive)
+            .map_err(|e| RbValueError::new_err(e.to_string()))?;
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            let mut out: BooleanChunked = ca
+                .into_iter()
+                .map(|opt_v| {
+                    opt_v.map(|v| {
+                        if patterns.is_empty() {
+                            false
+                        } else if ascii_case_insensitive {
+                            re.is_match(&v.to_ascii_lowercase())
+                        } else {
+                            re.is_match(v)
+                        }
+                    })
+                })
+                .collect();
+            out.rename(ca.name());
+            Ok(out.into_series())
+        };
+        Ok(self
+            .clone()
+            .inner
+            .map(function, GetOutput::from_type(DataType::Boolean))
+            .with_fmt("str.contains_any")
+            .into())
+    }
+
+    pub fn str_replace_many(
+        &self,
+        patterns: Vec<String>,
+        replacements: Vec<String>,
+        ascii_case_insensitive: bool,
+    ) -> RbResult<Self> {
+        let replacements = match replacements.len() {
+            1 => vec![replacements[0].clone(); patterns.len()],
+            n if n == patterns.len() => replacements,
+            n => {
+                return Err(RbValueError::new_err(format!(
+                    "expected 1 or {} replacements, got {}",
+                    patterns.len(),
+                    n
+                )))
+            }
+        };
+        if patterns.iter().any(|pat| pat.is_empty()) {
+            return Err(RbValueError::new_err(
+                "patterns must not contain empty strings".into(),
+            ));
+        }
+        let re = literal_alternation(&patterns, ascii_case_insensitive)
+            .map_err(|e| RbValueError::new_err(e.to_string()))?;
+        // the first pattern wins when several fold to the same text
+        let mut lookup: PlHashMap<String, String> = PlHashMap::new();
+        for (pat, replacement) in patterns.iter().zip(replacements) {
+            let pat = if ascii_case_insensitive {
+                pat.to_ascii_lowercase()
+            } else {
+                pat.clone()
+            };
+            lookup.entry(pat).or_insert(replacement);
+        }
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            let mut out: Utf8Chunked = ca
+                .into_iter()
+                .map(|opt_v| {
+                    opt_v.map(|v| {
+                        if lookup.is_empty() {
+                            return v.to_string();
+                        }
+                        // ASCII folding keeps byte offsets, so matches map back to v
+                        let haystack = if ascii_case_insensitive {
+                            v.to_ascii_lowercase()
+                        } else {
+                            v.to_string()
+                        };
+                        let mut replaced = String::with_capacity(v.len());
+                        let mut last = 0;
+                        for m in re.find_iter(&haystack) {
+                            replaced.push_str(&v[last..m.start()]);
+                            replaced.push_str(&lookup[m.as_str()]);
+                            last = m.end();
+                        }
+                        replaced.push_str(&v[last..]);
+                        replaced
+                    })
+                })
+                .collect();
+            out.rename(ca.name());
+            Ok(out.into_series())
+        };
+        Ok(self
+            .clone()
+            .inner
+            .map(function, GetOutput::from_type(DataType::Utf8))
+            .with_fmt("str.replace_many")
+            .into())
+    }
+
     pub fn str_ends_with(&self, sub: String) -> Self {
         self.inner.clone().str().ends_with(sub).into()
     }
@@ -711,6 +1017,43 @@ impl RbExpr {
             .into()
     }
 
+    pub fn str_to_integer(&self, base: u32, strict: bool) -> RbResult<Self> {
+        if !(2..=36).contains(&base) {
+            return Err(RbValueError::new_err(format!(
+                "base must be between 2 and 36, got {}",
+                base
+            )));
+        }
+        let function = move |s: Series| {
+            let ca = s.utf8()?;
+            let mut out: Int64Chunked = ca
+                .into_iter()
+                .map(|opt_v| match opt_v {
+                    Some(v) => match i64::from_str_radix(v, base) {
+                        Ok(v) => Ok(Some(v)),
+                        Err(_) if strict => Err(PolarsError::ComputeError(
+                            format!(
+                                "strict integer parsing failed for value '{}' with base {}",
+                                v, base
+                            )
+                            .into(),
+                        )),
+                        Err(_) => Ok(None),
+                    },
+                    None => Ok(None),
+                })
+                .collect::<PolarsResult<_>>()?;
+            out.rename(ca.name());
+            Ok(out.into_series())
+        };
+        Ok(self
+            .clone()
+            .inner
+            .map(function, GetOutput::from_type(DataType::Int64))
+            .with_fmt("str.to_integer")
+            .into())
+    }
+
     pub fn str_base64_encode(&self) -> Self {
         self.clone()
             .inner
@@ -752,6 +1095,23 @@ impl RbExpr {
         self.inner.clone().str().extract(&pat, group_index).into()
     }
 
+    pub fn str_extract_groups(&self, pat: String) -> RbResult<Self> {
+        let re = regex::Regex::new(&pat).map_err(|e| RbValueError::new_err(e.to_string()))?;
+        if re.captures_len() < 2 {
+            return Err(RbValueError::new_err(
+                "pattern must contain at least one capture group".into(),
+            ));
+        }
+        let output_type = extract_groups_output_type(&re);
+        let function = move |s: Series| extract_groups(&s, &re);
+        Ok(self
+            .clone()
+            .inner
+            .map(function, GetOutput::from_type(output_type))
+            .with_fmt("str.extract_groups")
+            .into())
+    }
+
     pub fn str_extract_all(&self, pat: &RbExpr) -> Self {
         self.inner
             .clone()
@@ -808,6 +1168,15 @@ impl RbExpr {
         self.clone().inner.dt().year().into()
     }
 
+    pub fn dt_is_leap_year(&self) -> Self {
+        let year = self.clone().inner.dt().year();
+        let divisible_by = |n: i32| (year.clone() % lit(n)).eq(lit(0));
+        divisible_by(4)
+            .and(divisible_by(100).not())
+            .or(divisible_by(400))
+            .into()
+    }
+
     pub fn iso_year(&self) -> Self {
         self.clone().inner.dt().iso_year().into()
     }
@@ -939,6 +1308,57 @@ impl RbExpr {
         self.inner.clone().dt().offset_by(by).into()
     }
 
+    pub fn dt_month_start(&self) -> Self {
+        self.clone()
+            .inner
+            .map(|s| month_boundary(&s, false), GetOutput::same_type())
+            .with_fmt("dt.month_start")
+            .into()
+    }
+
+    pub fn dt_month_end(&self) -> Self {
+        self.clone()
+            .inner
+            .map(|s| month_boundary(&s, true), GetOutput::same_type())
+            .with_fmt("dt.month_end")
+            .into()
+    }
+
+    pub fn dt_combine(&self, time: &RbExpr, time_unit: Wrap<TimeUnit>) -> Self {
+        let tu = time_unit.0;
+        self.clone()
+            .inner
+            .map_many(
+                move |s| combine(&s[0], &s[1], tu),
+                &[time.inner.clone()],
+                GetOutput::map_dtype(move |dt| combine_output_type(dt, tu)),
+            )
+            .with_fmt("dt.combine")
+            .into()
+    }
+
+    pub fn dt_base_utc_offset(&self) -> Self {
+        self.clone()
+            .inner
+            .map(
+                |s| tz_offset(&s, false),
+                GetOutput::map_dtype(tz_offset_output_type),
+            )
+            .with_fmt("dt.base_utc_offset")
+            .into()
+    }
+
+    pub fn dt_dst_offset(&self) -> Self {
+        self.clone()
+            .inner
+            .map(
+                |s| tz_offset(&s, true),
+                GetOutput::map_dtype(tz_offset_output_type),
+            )
+            .with_fmt("dt.dst_offset")
+            .into()
+    }
+
     pub fn dt_epoch_seconds(&self) -> Self {
         self.clone()
             .inner
@@ -976,8 +1396,10 @@ impl RbExpr {
         self.inner.clone().dt().truncate(&every, &offset).into()
     }
 
-    pub fn dt_round(&self, every: String, offset: String) -> Self {
-        self.inner.clone().dt().round(&every, &offset).into()
+    pub fn dt_round(&self, every: String, offset: String) -> RbResult<Self> {
+        check_duration(&every).map_err(RbValueError::new_err)?;
+        check_duration(&offset).map_err(RbValueError::new_err)?;
+        Ok(self.inner.clone().dt().round(&every, &offset).into())
     }
 
     pub fn map(&self, lambda: Value, output_type: Option<Wrap<DataType>>, agg_list: bool) -> Self {
@@ -990,15 +1412,13 @@ impl RbExpr {
 
     pub fn reinterpret(&self, signed: bool) -> Self {
         let function = move |s: Series| reinterpret(&s, signed);
-        let dt = if signed {
-            DataType::Int64
-        } else {
-            DataType::UInt64
-        };
-        self.clone()
-            .inner
-            .map(function, GetOutput::from_type(dt))
-            .into()
+        let output_type = GetOutput::map_dtype(move |dt| match (dt, signed) {
+            (DataType::Int32 | DataType::UInt32, true) => DataType::Int32,
+            (DataType::Int32 | DataType::UInt32, false) => DataType::UInt32,
+            (_, true) => DataType::Int64,
+            (_, false) => DataType::UInt64,
+        });
+        self.clone().inner.map(function, output_type).into()
     }
 
     pub fn mode(&self) -> Self {
@@ -1327,23 +1747,41 @@ impl RbExpr {
     pub fn lst_to_struct(
         &self,
         width_strat: Wrap<ListToStructWidthStrategy>,
-        _name_gen: Option<Value>,
+        name_gen: Option<Value>,
         upper_bound: usize,
+        truncate: bool,
     ) -> RbResult<Self> {
-        // TODO fix
-        let name_gen = None;
-        // let name_gen = name_gen.map(|lambda| {
-        //     Arc::new(move |idx: usize| {
-        //         let out: Value = lambda.funcall("call", (idx,)).unwrap();
-        //         out.try_convert::<String>().unwrap()
-        //     }) as NameGenerator
-        // });
+        if upper_bound == 0 {
+            if name_gen.is_some() {
+                return Err(RbValueError::new_err(
+                    "name_generator requires upper_bound to be set".into(),
+                ));
+            }
+            return Ok(self
+                .inner
+                .clone()
+                .arr()
+                .to_struct(width_strat.0, None, upper_bound)
+                .into());
+        }
 
+        // Ruby objects cannot be called from the query engine, so the names
+        // are generated up front for the declared width
+        let names = match name_gen {
+            Some(lambda) => (0..upper_bound)
+                .map(|idx| lambda.funcall::<_, _, String>("call", (idx,)))
+                .collect::<RbResult<Vec<_>>>()?,
+            None => (0..upper_bound).map(|idx| format!("field_{idx}")).collect(),
+        };
+        let output_names = names.clone();
         Ok(self
             .inner
             .clone()
-            .arr()
-            .to_struct(width_strat.0, name_gen, upper_bound)
+            .map(
+                move |s| list_to_struct(&s, &names, truncate),
+                GetOutput::map_dtype(move |dt| list_to_struct_output_type(dt, &output_names)),
+            )
+            .with_fmt("arr.to_struct")
             .into())
     }
 
@@ -1355,12 +1793,65 @@ impl RbExpr {
         self.inner.clone().rank(options).into()
     }
 
+    pub fn rolling_rank(
+        &self,
+        window_size: usize,
+        min_periods: usize,
+        method: Wrap<RankMethod>,
+    ) -> RbResult<Self> {
+        let method = method.0;
+        if matches!(method, RankMethod::Random) {
+            return Err(RbValueError::new_err(
+                "rolling_rank does not support method 'random'".into(),
+            ));
+        }
+        if window_size == 0 {
+            return Err(RbValueError::new_err(
+                "window_size must be greater than 0".into(),
+            ));
+        }
+        let output_type = rolling_rank_output_type(&method);
+        Ok(self
+            .clone()
+            .inner
+            .apply(
+                move |s| rolling_rank(&s, window_size, min_periods, &method),
+                GetOutput::from_type(output_type),
+            )
+            .with_fmt("rolling_rank")
+            .into())
+    }
+
     pub fn diff(&self, n: usize, null_behavior: Wrap<NullBehavior>) -> Self {
         self.inner.clone().diff(n, null_behavior.0).into()
     }
 
-    pub fn pct_change(&self, n: usize) -> Self {
-        self.inner.clone().pct_change(n).into()
+    pub fn diff_by(&self, n: &RbExpr) -> Self {
+        self.clone()
+            .inner
+            .map_many(
+                |s: &mut [Series]| diff_by(&s[0], &s[1]),
+                &[n.inner.clone()],
+                GetOutput::same_type(),
+            )
+            .with_fmt("diff")
+            .into()
+    }
+
+    pub fn pct_change(&self, n: usize, fill_method: Option<String>) -> RbResult<Self> {
+        let expr = self.inner.clone();
+        let filled = match fill_method.as_deref() {
+            Some("forward") => return Ok(expr.pct_change(n).into()),
+            Some("backward") => expr.cast(DataType::Float64).backward_fill(None),
+            None => expr.cast(DataType::Float64),
+            Some(v) => {
+                return Err(RbValueError::new_err(format!(
+                    "fill_method must be one of {{'forward', 'backward', nil}}, got {}",
+                    v
+                )))
+            }
+        };
+        Ok((filled.clone().diff(n, NullBehavior::Ignore) / filled.shift(n as i64)).into())
     }
 
     pub fn skew(&self, bias: bool) -> Self {
@@ -1387,6 +1878,15 @@ impl RbExpr {
         self.inner.clone().cumcount(reverse).into()
     }
 
+    pub fn cum_count(&self, reverse: bool) -> Self {
+        self.inner
+            .clone()
+            .is_not_null()
+            .cast(DataType::UInt32)
+            .cumsum(reverse)
+            .into()
+    }
+
     pub fn to_physical(&self) -> Self {
         self.inner
             .clone()
@@ -1458,6 +1958,262 @@ impl RbExpr {
         self.inner.clone().ewm_var(options).into()
     }
 
+    pub fn cut(
+        &self,
+        breaks: Vec<f64>,
+        labels: Option<Vec<String>>,
+        left_closed: bool,
+        include_breaks: bool,
+    ) -> RbResult<Self> {
+        if breaks.is_empty() {
+            return Err(RbValueError::new_err("breaks must not be empty".into()));
+        }
+        if !breaks.windows(2).all(|w| w[0] < w[1]) {
+            return Err(RbValueError::new_err(
+                "breaks must be strictly increasing".into(),
+            ));
+        }
+        if let Some(labels) = &labels {
+            if labels.len() != breaks.len() + 1 {
+                return Err(RbValueError::new_err(format!(
+                    "expected {} labels, got {}",
+                    breaks.len() + 1,
+                    labels.len()
+                )));
+            }
+        }
+        Ok(self
+            .clone()
+            .inner
+            .map(
+                move |s| cut(&s, &breaks, labels.as_deref(), left_closed, include_breaks),
+                GetOutput::from_type(cut_output_type(include_breaks)),
+            )
+            .with_fmt("cut")
+            .into())
+    }
+
+    pub fn to_dummies(
+        &self,
+        categories: Vec<String>,
+        separator: String,
+        drop_first: bool,
+    ) -> RbResult<Vec<RbExpr>> {
+        let name = self
+            .inner
+            .clone()
+            .meta()
+            .output_name()
+            .map_err(RbPolarsErr::from)?;
+        let values = self.inner.clone().cast(DataType::Utf8);
+        let skip = usize::from(drop_first);
+        Ok(categories
+            .iter()
+            .skip(skip)
+            .map(|category| {
+                values
+                    .clone()
+                    .eq(lit(category.as_str()))
+                    .fill_null(lit(false))
+                    .alias(&format!("{}{}{}", name, separator, category))
+                    .into()
+            })
+            .collect())
+    }
+
+    pub fn hist(
+        &self,
+        bins: Option<Vec<f64>>,
+        bin_count: Option<usize>,
+        include_category: bool,
+        include_breakpoint: bool,
+    ) -> RbResult<Self> {
+        check_hist_args(bins.as_deref(), bin_count).map_err(RbValueError::new_err)?;
+        Ok(self
+            .clone()
+            .inner
+            .apply(
+                move |s| {
+                    hist(
+                        &s,
+                        bins.as_deref(),
+                        bin_count,
+                        include_category,
+                        include_breakpoint,
+                    )
+                },
+                GetOutput::from_type(hist_output_type(include_category, include_breakpoint)),
+            )
+            .with_fmt("hist")
+            .into())
+    }
+
+    pub fn qcut(
+        &self,
+        quantiles: Vec<f64>,
+        labels: Option<Vec<String>>,
+        left_closed: bool,
+        allow_duplicates: bool,
+        include_breaks: bool,
+    ) -> RbResult<Self> {
+        if quantiles.is_empty() {
+            return Err(RbValueError::new_err("quantiles must not be empty".into()));
+        }
+        if !quantiles.iter().all(|q| (0.0..=1.0).contains(q)) {
+            return Err(RbValueError::new_err(
+                "quantiles must be between 0 and 1".into(),
+            ));
+        }
+        if !quantiles.windows(2).all(|w| w[0] < w[1]) {
+            return Err(RbValueError::new_err(
+                "quantiles must be strictly increasing".into(),
+            ));
+        }
+        Ok(self
+            .clone()
+            .inner
+            .map(
+                move |s| {
+                    let values = s.cast(&DataType::Float64)?;
+                    let ca = values.f64()?;
+                    let mut breaks = Vec::with_capacity(quantiles.len());
+                    for q in &quantiles {
+                        if let Some(b) = ca.quantile(*q, QuantileInterpolOptions::Linear)? {
+                            breaks.push(b);
+                        }
+                    }
+                    let n = breaks.len();
+                    breaks.dedup();
+                    if breaks.len() != n && !allow_duplicates {
+                        return Err(PolarsError::ComputeError(
+                            "quantile breaks are not unique, set allow_duplicates to drop them"
+                                .into(),
+                        ));
+                    }
+                    cut(&s, &breaks, labels.as_deref(), left_closed, include_breaks)
+                },
+                GetOutput::from_type(cut_output_type(include_breaks)),
+            )
+            .with_fmt("qcut")
+            .into())
+    }
+
+    pub fn rle(&self) -> Self {
+        self.clone()
+            .inner
+            .map(
+                |s| rle(&s),
+                GetOutput::map_dtype(|dt| {
+                    DataType::Struct(vec![
+                        Field::new("lengths", IDX_DTYPE),
+                        Field::new("values", dt.clone()),
+                    ])
+                }),
+            )
+            .with_fmt("rle")
+            .into()
+    }
+
+    pub fn rle_id(&self) -> Self {
+        self.clone()
+            .inner
+            .map(|s| rle_id(&s), GetOutput::from_type(IDX_DTYPE))
+            .with_fmt("rle_id")
+            .into()
+    }
+
+    pub fn peak_max(&self) -> Self {
+        self.clone()
+            .inner
+            .apply(
+                |s| {
+                    let mut out = s.peak_max().into_series();
+                    out.rename(s.name());
+                    Ok(out)
+                },
+                GetOutput::from_type(DataType::Boolean),
+            )
+            .with_fmt("peak_max")
+            .into()
+    }
+
+    pub fn peak_min(&self) -> Self {
+        self.clone()
+            .inner
+            .apply(
+                |s| {
+                    let mut out = s.peak_min().into_series();
+                    out.rename(s.name());
+                    Ok(out)
+                },
+                GetOutput::from_type(DataType::Boolean),
+            )
+            .with_fmt("peak_min")
+            .into()
+    }
+
+    pub fn replace(
+        &self,
+        old: &RbSeries,
+        new: &RbSeries,
+        default: Option<&RbExpr>,
+        return_dtype: Option<Wrap<DataType>>,
+    ) -> RbResult<Self> {
+        let old = old.series.borrow().clone();
+        let new = new.series.borrow().clone();
+        check_replace_args(&old, &new, None).map_err(RbValueError::new_err)?;
+        let return_dtype = return_dtype.map(|dt| dt.0);
+
+        let new_dtype = new.dtype().clone();
+        let output_type = match &return_dtype {
+            Some(dtype) => GetOutput::from_type(dtype.clone()),
+            None => GetOutput::map_dtypes(move |dtypes| {
+                replace_output_type(dtypes[0], &new_dtype, dtypes.get(1).copied())
+                    .unwrap_or_else(|_| new_dtype.clone())
+            }),
+        };
+        let function =
+            move |s: &mut [Series]| replace(&s[0], &old, &new, s.get(1), return_dtype.as_ref());
+        let arguments: Vec<Expr> = default.map(|d| d.inner.clone()).into_iter().collect();
+        Ok(self
+            .clone()
+            .inner
+            .map_many(function, &arguments, output_type)
+            .with_fmt("replace")
+            .into())
+    }
+
+    pub fn map_dict(&self, keys: &RbExpr, values: &RbExpr, default: Option<&RbExpr>) -> Self {
+        let output_type = GetOutput::map_dtypes(|dtypes| {
+            replace_output_type(dtypes[0], dtypes[2], dtypes.get(3).copied())
+                .unwrap_or_else(|_| dtypes[2].clone())
+        });
+        let function = move |s: &mut [Series]| {
+            let (keys, values) = (&s[1], &s[2]);
+            if keys.len() != values.len() {
+                return Err(PolarsError::ComputeError(
+                    format!(
+                        "map_dict keys and values must have the same length, got {} and {}",
+                        keys.len(),
+                        values.len()
+                    )
+                    .into(),
+                ));
+            }
+            // missing keys are null unless a default is given
+            let nulls = Series::full_null("", 1, values.dtype());
+            let default = s.get(3).unwrap_or(&nulls);
+            replace(&s[0], keys, values, Some(default), None)
+        };
+        let mut arguments = vec![keys.inner.clone(), values.inner.clone()];
+        arguments.extend(default.map(|d| d.inner.clone()));
+        self.clone()
+            .inner
+            .map_many(function, &arguments, output_type)
+            .with_fmt("map_dict")
+            .into()
+    }
+
     pub fn extend_constant(&self, value: Wrap<AnyValue>, n: usize) -> Self {
         let value = Value::from(value);
         self.inner
@@ -1465,6 +2221,26 @@ impl RbExpr {
             .apply(
                 move |s| {
                     let value = value.try_convert::<Wrap<AnyValue>>().unwrap().0;
+                    let value_dtype = value.dtype();
+                    let compatible = match (&value_dtype, s.dtype()) {
+                        (DataType::Null, _) => true,
+                        (left, right) if left == right => true,
+                        // do not silently truncate floats
+                        (DataType::Float32 | DataType::Float64, right) => {
+                            matches!(right, DataType::Float32 | DataType::Float64)
+                        }
+                        (left, right) => left.is_numeric() && right.is_numeric(),
+                    };
+                    if !compatible {
+                        return Err(PolarsError::ComputeError(
+                            format!(
+                                "cannot extend series of dtype {} with value of dtype {}",
+                                s.dtype(),
+                                value_dtype
+                            )
+                            .into(),
+                        ));
+                    }
                     s.extend_constant(value, n)
                 },
                 GetOutput::same_type(),
@@ -1473,16 +2249,100 @@ impl RbExpr {
             .into()
     }
 
-    pub fn any(&self) -> Self {
-        self.inner.clone().any().into()
+    pub fn set_sorted(&self, reverse: bool, verify: bool) -> Self {
+        self.clone()
+            .inner
+            .map(
+                move |mut s| {
+                    if verify && !(is_sorted(&s, reverse, false)? || is_sorted(&s, reverse, true)?)
+                    {
+                        return Err(PolarsError::ComputeError(
+                            format!("series '{}' is not sorted", s.name()).into(),
+                        ));
+                    }
+                    if reverse {
+                        s.set_sorted(IsSorted::Descending);
+                    } else {
+                        s.set_sorted(IsSorted::Ascending);
+                    }
+                    Ok(s)
+                },
+                GetOutput::same_type(),
+            )
+            .with_fmt("set_sorted")
+            .into()
+    }
+
+    pub fn any(&self, ignore_nulls: bool) -> Self {
+        if ignore_nulls {
+            return self.inner.clone().any().into();
+        }
+        // Kleene logic: true wins, otherwise nulls propagate
+        self.clone()
+            .inner
+            .apply(
+                |s| {
+                    let ca = s.bool()?;
+                    let out = if ca.any() {
+                        Some(true)
+                    } else if ca.null_count() > 0 {
+                        None
+                    } else {
+                        Some(false)
+                    };
+                    Ok(Series::new(s.name(), [out]))
+                },
+                GetOutput::from_type(DataType::Boolean),
+            )
+            .with_fmt("any")
+            .into()
     }
 
-    pub fn all(&self) -> Self {
-        self.inner.clone().all().into()
+    pub fn all(&self, ignore_nulls: bool) -> Self {
+        if ignore_nulls {
+            return self.inner.clone().all().into();
+        }
+        // Kleene logic: false wins, otherwise nulls propagate
+        self.clone()
+            .inner
+            .apply(
+                |s| {
+                    let ca = s.bool()?;
+                    let out = if ca.into_iter().any(|v| v == Some(false)) {
+                        Some(false)
+                    } else if ca.null_count() > 0 {
+                        None
+                    } else {
+                        Some(true)
+                    };
+                    Ok(Series::new(s.name(), [out]))
+                },
+                GetOutput::from_type(DataType::Boolean),
+            )
+            .with_fmt("all")
+            .into()
     }
 
     pub fn struct_field_by_name(&self, name: String) -> Self {
-        self.inner.clone().struct_().field_by_name(&name).into()
+        if !name.contains('.') {
+            return self.inner.clone().struct_().field_by_name(&name).into();
+        }
+
+        let output_name = name.clone();
+        self.inner
+            .clone()
+            .map(
+                move |s| struct_field_path(&s, &struct_path(s.dtype(), &name)),
+                GetOutput::map_field(move |fld| {
+                    let path = struct_path(fld.data_type(), &output_name);
+                    Field::new(
+                        path.last().unwrap(),
+                        struct_field_path_output_type(fld.data_type(), &path),
+                    )
+                }),
+            )
+            .with_fmt("struct.field")
+            .into()
     }
 
     pub fn struct_field_by_index(&self, index: i64) -> Self {
@@ -1575,6 +2435,49 @@ pub fn arange(low: &RbExpr, high: &RbExpr, step: usize) -> RbExpr {
     polars::lazy::dsl::arange(low.inner.clone(), high.inner.clone(), step).into()
 }
 
+pub fn int_range(
+    start: &RbExpr,
+    end: &RbExpr,
+    step: i64,
+    dtype: Wrap<DataType>,
+) -> RbResult<RbExpr> {
+    let dtype = dtype.0;
+    check_int_range_args(step, &dtype).map_err(RbValueError::new_err)?;
+    let output_dtype = dtype.clone();
+    Ok(start
+        .inner
+        .clone()
+        .map_many(
+            move |s| crate::utils::int_range(&s[0], &s[1], step, &dtype),
+            &[end.inner.clone()],
+            GetOutput::from_type(output_dtype),
+        )
+        .with_fmt("int_range")
+        .into())
+}
+
+pub fn int_ranges(
+    start: &RbExpr,
+    end: &RbExpr,
+    step: i64,
+    dtype: Wrap<DataType>,
+) -> RbResult<RbExpr> {
+    let dtype = dtype.0;
+    check_int_range_args(step, &dtype).map_err(RbValueError::new_err)?;
+    let list_dtype = DataType::List(Box::new(dtype));
+    let output_dtype = list_dtype.clone();
+    Ok(start
+        .inner
+        .clone()
+        .map_many(
+            move |s| crate::utils::int_ranges(&s[0], &s[1], step)?.cast(&list_dtype),
+            &[end.inner.clone()],
+            GetOutput::from_type(output_dtype),
+        )
+        .with_fmt("int_ranges")
+        .into())
+}
+
 pub fn repeat(value: Value, n_times: &RbExpr) -> RbResult<RbExpr> {
     if value.is_nil() {
         Ok(polars::lazy::dsl::repeat(Null {}, n_times.inner.clone()).into())
@@ -13,7 +13,7 @@ mod utils;
 use batched_csv::RbBatchedCsv;
 use conversion::*;
 use dataframe::RbDataFrame;
-use error::{RbPolarsErr, RbValueError};
+use error::{RbPolarsErr, RbTypeError, RbValueError};
 use file::get_file_like;
 use lazy::dataframe::{RbLazyFrame, RbLazyGroupBy};
 use lazy::dsl::{RbExpr, RbWhen, RbWhenThen};
@@ -26,7 +26,10 @@ use polars::datatypes::{DataType, TimeUnit};
 use polars::error::PolarsResult;
 use polars::frame::DataFrame;
 use polars::functions::{diag_concat_df, hor_concat_df};
-use polars::prelude::{ClosedWindow, Duration, DurationArgs, IntoSeries, TimeZone};
+use polars::prelude::{
+    ClosedWindow, Duration, DurationArgs, Int64Chunked, IntoSeries, NewChunkedArray, Series,
+    TimeZone,
+};
 use series::RbSeries;
 
 #[cfg(target_os = "linux")]
@@ -62,6 +65,8 @@ fn init() -> RbResult<()> {
     module.define_singleton_method("_diag_concat_df", function!(rb_diag_concat_df, 1))?;
     module.define_singleton_method("_hor_concat_df", function!(rb_hor_concat_df, 1))?;
     module.define_singleton_method("_concat_series", function!(concat_series, 1))?;
+    module.define_singleton_method("_repeat_series", function!(repeat_series, 3))?;
+    module.define_singleton_method("_int_range", function!(int_range, 4))?;
     module.define_singleton_method("_ipc_schema", function!(ipc_schema, 1))?;
     module.define_singleton_method("_parquet_schema", function!(parquet_schema, 1))?;
     module.define_singleton_method("_collect_all", function!(collect_all, 1))?;
@@ -81,19 +86,29 @@ fn init() -> RbResult<()> {
     class.define_singleton_method("read_parquet", function!(RbDataFrame::read_parquet, 7))?;
     class.define_singleton_method("read_ipc", function!(RbDataFrame::read_ipc, 6))?;
     class.define_singleton_method("read_avro", function!(RbDataFrame::read_avro, 4))?;
+    class.define_singleton_method("read_excel", function!(RbDataFrame::read_excel, 5))?;
     class.define_singleton_method("read_hashes", function!(RbDataFrame::read_hashes, 3))?;
     class.define_singleton_method("read_hash", function!(RbDataFrame::read_hash, 1))?;
     class.define_singleton_method("read_json", function!(RbDataFrame::read_json, 1))?;
     class.define_singleton_method("read_ndjson", function!(RbDataFrame::read_ndjson, 1))?;
+    class.define_singleton_method("from_arrow", function!(RbDataFrame::from_arrow, 2))?;
     class.define_method("estimated_size", method!(RbDataFrame::estimated_size, 0))?;
     class.define_method("write_avro", method!(RbDataFrame::write_avro, 2))?;
+    class.define_method("write_excel", method!(RbDataFrame::write_excel, 2))?;
     class.define_method("write_json", method!(RbDataFrame::write_json, 3))?;
     class.define_method("write_ndjson", method!(RbDataFrame::write_ndjson, 1))?;
     class.define_method("write_csv", method!(RbDataFrame::write_csv, 10))?;
     class.define_method("write_ipc", method!(RbDataFrame::write_ipc, 2))?;
+    class.define_method("to_arrow", method!(RbDataFrame::to_arrow, 2))?;
+    class.define_method("should_rechunk", method!(RbDataFrame::should_rechunk, 0))?;
     class.define_method("row_tuple", method!(RbDataFrame::row_tuple, 1))?;
     class.define_method("row_tuples", method!(RbDataFrame::row_tuples, 0))?;
+    class.define_method("to_hashes", method!(RbDataFrame::to_hashes, 0))?;
     class.define_method("write_parquet", method!(RbDataFrame::write_parquet, 5))?;
+    class.define_method(
+        "write_parquet_partitioned",
+        method!(RbDataFrame::write_parquet_partitioned, 7),
+    )?;
     class.define_method("add", method!(RbDataFrame::add, 1))?;
     class.define_method("sub", method!(RbDataFrame::sub, 1))?;
     class.define_method("div", method!(RbDataFrame::div, 1))?;
@@ -106,9 +121,15 @@ fn init() -> RbResult<()> {
     class.define_method("rem_df", method!(RbDataFrame::rem_df, 1))?;
     class.define_method("sample_n", method!(RbDataFrame::sample_n, 4))?;
     class.define_method("sample_frac", method!(RbDataFrame::sample_frac, 4))?;
+    class.define_method("shuffle", method!(RbDataFrame::shuffle, 1))?;
+    class.define_method(
+        "sample_stratified",
+        method!(RbDataFrame::sample_stratified, 6),
+    )?;
     class.define_method("rechunk", method!(RbDataFrame::rechunk, 0))?;
     class.define_method("to_s", method!(RbDataFrame::to_s, 0))?;
     class.define_method("get_columns", method!(RbDataFrame::get_columns, 0))?;
+    class.define_method("iter_columns", method!(RbDataFrame::iter_columns, 0))?;
     class.define_method("columns", method!(RbDataFrame::columns, 0))?;
     class.define_method(
         "set_column_names",
@@ -119,11 +140,11 @@ fn init() -> RbResult<()> {
     class.define_method("shape", method!(RbDataFrame::shape, 0))?;
     class.define_method("height", method!(RbDataFrame::height, 0))?;
     class.define_method("width", method!(RbDataFrame::width, 0))?;
-    class.define_method("hstack_mut", method!(RbDataFrame::hstack_mut, 1))?;
-    class.define_method("hstack", method!(RbDataFrame::hstack, 1))?;
+    class.define_method("hstack_mut", method!(RbDataFrame::hstack_mut, 2))?;
+    class.define_method("hstack", method!(RbDataFrame::hstack, 2))?;
     class.define_method("extend", method!(RbDataFrame::extend, 1))?;
-    class.define_method("vstack_mut", method!(RbDataFrame::vstack_mut, 1))?;
-    class.define_method("vstack", method!(RbDataFrame::vstack, 1))?;
+    class.define_method("vstack_mut", method!(RbDataFrame::vstack_mut, 2))?;
+    class.define_method("vstack", method!(RbDataFrame::vstack, 2))?;
     class.define_method("drop_in_place", method!(RbDataFrame::drop_in_place, 1))?;
     class.define_method("drop_nulls", method!(RbDataFrame::drop_nulls, 1))?;
     class.define_method("drop", method!(RbDataFrame::drop, 1))?;
@@ -149,11 +170,13 @@ fn init() -> RbResult<()> {
     class.define_method("is_unique", method!(RbDataFrame::is_unique, 0))?;
     class.define_method("is_duplicated", method!(RbDataFrame::is_duplicated, 0))?;
     class.define_method("frame_equal", method!(RbDataFrame::frame_equal, 2))?;
-    class.define_method("with_row_count", method!(RbDataFrame::with_row_count, 2))?;
+    class.define_method("with_row_count", method!(RbDataFrame::with_row_count, 3))?;
     class.define_method("_clone", method!(RbDataFrame::clone, 0))?;
-    class.define_method("melt", method!(RbDataFrame::melt, 4))?;
-    class.define_method("pivot_expr", method!(RbDataFrame::pivot_expr, 6))?;
+    class.define_method("melt", method!(RbDataFrame::melt, 5))?;
+    class.define_method("unpivot", method!(RbDataFrame::unpivot, 4))?;
+    class.define_method("pivot_expr", method!(RbDataFrame::pivot_expr, 7))?;
     class.define_method("partition_by", method!(RbDataFrame::partition_by, 2))?;
+    class.define_method("groupby_apply", method!(RbDataFrame::groupby_apply, 3))?;
     class.define_method("shift", method!(RbDataFrame::shift, 1))?;
     class.define_method("unique", method!(RbDataFrame::unique, 3))?;
     class.define_method("lazy", method!(RbDataFrame::lazy, 0))?;
@@ -173,9 +196,12 @@ fn init() -> RbResult<()> {
     class.define_method("null_count", method!(RbDataFrame::null_count, 0))?;
     class.define_method("apply", method!(RbDataFrame::apply, 3))?;
     class.define_method("shrink_to_fit", method!(RbDataFrame::shrink_to_fit, 0))?;
+    class.define_method("shrink_dtype", method!(RbDataFrame::shrink_dtype, 1))?;
+    class.define_method("set_sorted", method!(RbDataFrame::set_sorted, 3))?;
     class.define_method("hash_rows", method!(RbDataFrame::hash_rows, 4))?;
     class.define_method("transpose", method!(RbDataFrame::transpose, 2))?;
     class.define_method("upsample", method!(RbDataFrame::upsample, 5))?;
+    class.define_method("to_numo", method!(RbDataFrame::to_numo, 0))?;
     class.define_method("to_struct", method!(RbDataFrame::to_struct, 1))?;
     class.define_method("unnest", method!(RbDataFrame::unnest, 1))?;
 
@@ -195,6 +221,7 @@ fn init() -> RbResult<()> {
     class.define_method("lt", method!(RbExpr::lt, 1))?;
     class.define_method("_alias", method!(RbExpr::alias, 1))?;
     class.define_method("is_not", method!(RbExpr::is_not, 0))?;
+    class.define_method("not_", method!(RbExpr::not_, 0))?;
     class.define_method("is_null", method!(RbExpr::is_null, 0))?;
     class.define_method("is_not_null", method!(RbExpr::is_not_null, 0))?;
     class.define_method("is_infinite", method!(RbExpr::is_infinite, 0))?;
@@ -221,18 +248,19 @@ fn init() -> RbResult<()> {
     class.define_method("value_counts", method!(RbExpr::value_counts, 2))?;
     class.define_method("unique_counts", method!(RbExpr::unique_counts, 0))?;
     class.define_method("null_count", method!(RbExpr::null_count, 0))?;
-    class.define_method("cast", method!(RbExpr::cast, 2))?;
+    class.define_method("cast", method!(RbExpr::cast, 3))?;
     class.define_method("sort_with", method!(RbExpr::sort_with, 2))?;
     class.define_method("arg_sort", method!(RbExpr::arg_sort, 2))?;
     class.define_method("top_k", method!(RbExpr::top_k, 2))?;
     class.define_method("arg_max", method!(RbExpr::arg_max, 0))?;
     class.define_method("arg_min", method!(RbExpr::arg_min, 0))?;
-    class.define_method("search_sorted", method!(RbExpr::search_sorted, 1))?;
+    class.define_method("search_sorted", method!(RbExpr::search_sorted, 2))?;
     class.define_method("take", method!(RbExpr::take, 1))?;
+    class.define_method("gather", method!(RbExpr::gather, 2))?;
     class.define_method("sort_by", method!(RbExpr::sort_by, 2))?;
     class.define_method("backward_fill", method!(RbExpr::backward_fill, 1))?;
     class.define_method("forward_fill", method!(RbExpr::forward_fill, 1))?;
-    class.define_method("shift", method!(RbExpr::shift, 1))?;
+    class.define_method("shift", method!(RbExpr::shift, 2))?;
     class.define_method("shift_and_fill", method!(RbExpr::shift_and_fill, 2))?;
     class.define_method("fill_null", method!(RbExpr::fill_null, 1))?;
     class.define_method(
@@ -249,7 +277,7 @@ fn init() -> RbResult<()> {
     class.define_method("is_unique", method!(RbExpr::is_unique, 0))?;
     class.define_method("is_first", method!(RbExpr::is_first, 0))?;
     class.define_method("explode", method!(RbExpr::explode, 0))?;
-    class.define_method("take_every", method!(RbExpr::take_every, 1))?;
+    class.define_method("take_every", method!(RbExpr::take_every, 2))?;
     class.define_method("tail", method!(RbExpr::tail, 1))?;
     class.define_method("head", method!(RbExpr::head, 1))?;
     class.define_method("slice", method!(RbExpr::slice, 2))?;
@@ -281,14 +309,14 @@ fn init() -> RbResult<()> {
     class.define_method("_xor", method!(RbExpr::_xor, 1))?;
     class.define_method("_or", method!(RbExpr::_or, 1))?;
     class.define_method("is_in", method!(RbExpr::is_in, 1))?;
-    class.define_method("repeat_by", method!(RbExpr::repeat_by, 1))?;
+    class.define_method("repeat_by", method!(RbExpr::repeat_by, 2))?;
     class.define_method("pow", method!(RbExpr::pow, 1))?;
     class.define_method("cumsum", method!(RbExpr::cumsum, 1))?;
     class.define_method("cummax", method!(RbExpr::cummax, 1))?;
     class.define_method("cummin", method!(RbExpr::cummin, 1))?;
     class.define_method("cumprod", method!(RbExpr::cumprod, 1))?;
     class.define_method("product", method!(RbExpr::product, 0))?;
-    class.define_method("shrink_dtype", method!(RbExpr::shrink_dtype, 0))?;
+    class.define_method("shrink_dtype", method!(RbExpr::shrink_dtype, 1))?;
     class.define_method("str_parse_date", method!(RbExpr::str_parse_date, 4))?;
     class.define_method("str_parse_datetime", method!(RbExpr::str_parse_datetime, 5))?;
     class.define_method("str_parse_time", method!(RbExpr::str_parse_time, 4))?;
@@ -300,16 +328,22 @@ fn init() -> RbResult<()> {
     class.define_method("str_to_lowercase", method!(RbExpr::str_to_lowercase, 0))?;
     class.define_method("str_lengths", method!(RbExpr::str_lengths, 0))?;
     class.define_method("str_n_chars", method!(RbExpr::str_n_chars, 0))?;
+    class.define_method("str_reverse", method!(RbExpr::str_reverse, 0))?;
     class.define_method("str_replace", method!(RbExpr::str_replace, 3))?;
     class.define_method("str_replace_all", method!(RbExpr::str_replace_all, 3))?;
     class.define_method("str_zfill", method!(RbExpr::str_zfill, 1))?;
     class.define_method("str_ljust", method!(RbExpr::str_ljust, 2))?;
     class.define_method("str_rjust", method!(RbExpr::str_rjust, 2))?;
-    class.define_method("str_contains", method!(RbExpr::str_contains, 2))?;
+    class.define_method("str_pad_start", method!(RbExpr::str_pad_start, 2))?;
+    class.define_method("str_pad_end", method!(RbExpr::str_pad_end, 2))?;
+    class.define_method("str_contains", method!(RbExpr::str_contains, 3))?;
+    class.define_method("str_contains_any", method!(RbExpr::str_contains_any, 2))?;
+    class.define_method("str_replace_many", method!(RbExpr::str_replace_many, 3))?;
     class.define_method("str_ends_with", method!(RbExpr::str_ends_with, 1))?;
     class.define_method("str_starts_with", method!(RbExpr::str_starts_with, 1))?;
     class.define_method("str_hex_encode", method!(RbExpr::str_hex_encode, 0))?;
     class.define_method("str_hex_decode", method!(RbExpr::str_hex_decode, 1))?;
+    class.define_method("str_to_integer", method!(RbExpr::str_to_integer, 2))?;
     class.define_method("str_base64_encode", method!(RbExpr::str_base64_encode, 0))?;
     class.define_method("str_base64_decode", method!(RbExpr::str_base64_decode, 1))?;
     class.define_method(
@@ -318,6 +352,7 @@ fn init() -> RbResult<()> {
     )?;
     class.define_method("str_extract", method!(RbExpr::str_extract, 2))?;
     class.define_method("str_extract_all", method!(RbExpr::str_extract_all, 1))?;
+    class.define_method("str_extract_groups", method!(RbExpr::str_extract_groups, 1))?;
     class.define_method("count_match", method!(RbExpr::count_match, 1))?;
     class.define_method("strftime", method!(RbExpr::strftime, 1))?;
     class.define_method("str_split", method!(RbExpr::str_split, 1))?;
@@ -334,6 +369,7 @@ fn init() -> RbResult<()> {
     class.define_method("arr_lengths", method!(RbExpr::arr_lengths, 0))?;
     class.define_method("arr_contains", method!(RbExpr::arr_contains, 1))?;
     class.define_method("year", method!(RbExpr::year, 0))?;
+    class.define_method("dt_is_leap_year", method!(RbExpr::dt_is_leap_year, 0))?;
     class.define_method("iso_year", method!(RbExpr::iso_year, 0))?;
     class.define_method("quarter", method!(RbExpr::quarter, 0))?;
     class.define_method("month", method!(RbExpr::month, 0))?;
@@ -365,6 +401,11 @@ fn init() -> RbResult<()> {
     )?;
     class.define_method("timestamp", method!(RbExpr::timestamp, 1))?;
     class.define_method("dt_offset_by", method!(RbExpr::dt_offset_by, 1))?;
+    class.define_method("dt_month_start", method!(RbExpr::dt_month_start, 0))?;
+    class.define_method("dt_month_end", method!(RbExpr::dt_month_end, 0))?;
+    class.define_method("dt_combine", method!(RbExpr::dt_combine, 2))?;
+    class.define_method("dt_base_utc_offset", method!(RbExpr::dt_base_utc_offset, 0))?;
+    class.define_method("dt_dst_offset", method!(RbExpr::dt_dst_offset, 0))?;
     class.define_method("dt_epoch_seconds", method!(RbExpr::dt_epoch_seconds, 0))?;
     class.define_method("dt_with_time_unit", method!(RbExpr::dt_with_time_unit, 1))?;
     class.define_method("dt_with_time_zone", method!(RbExpr::dt_with_time_zone, 1))?;
@@ -410,16 +451,19 @@ fn init() -> RbResult<()> {
     class.define_method("lst_slice", method!(RbExpr::lst_slice, 2))?;
     class.define_method("lst_eval", method!(RbExpr::lst_eval, 2))?;
     class.define_method("cumulative_eval", method!(RbExpr::cumulative_eval, 3))?;
-    class.define_method("lst_to_struct", method!(RbExpr::lst_to_struct, 3))?;
+    class.define_method("lst_to_struct", method!(RbExpr::lst_to_struct, 4))?;
     class.define_method("rank", method!(RbExpr::rank, 2))?;
+    class.define_method("rolling_rank", method!(RbExpr::rolling_rank, 3))?;
     class.define_method("diff", method!(RbExpr::diff, 2))?;
-    class.define_method("pct_change", method!(RbExpr::pct_change, 1))?;
+    class.define_method("diff_by", method!(RbExpr::diff_by, 1))?;
+    class.define_method("pct_change", method!(RbExpr::pct_change, 2))?;
     class.define_method("skew", method!(RbExpr::skew, 1))?;
     class.define_method("kurtosis", method!(RbExpr::kurtosis, 2))?;
     class.define_method("str_concat", method!(RbExpr::str_concat, 1))?;
     class.define_method("cat_set_ordering", method!(RbExpr::cat_set_ordering, 1))?;
     class.define_method("reshape", method!(RbExpr::reshape, 1))?;
     class.define_method("cumcount", method!(RbExpr::cumcount, 1))?;
+    class.define_method("cum_count", method!(RbExpr::cum_count, 1))?;
     class.define_method("to_physical", method!(RbExpr::to_physical, 0))?;
     class.define_method("shuffle", method!(RbExpr::shuffle, 1))?;
     class.define_method("sample_n", method!(RbExpr::sample_n, 4))?;
@@ -427,9 +471,20 @@ fn init() -> RbResult<()> {
     class.define_method("ewm_mean", method!(RbExpr::ewm_mean, 3))?;
     class.define_method("ewm_std", method!(RbExpr::ewm_std, 4))?;
     class.define_method("ewm_var", method!(RbExpr::ewm_var, 4))?;
+    class.define_method("replace", method!(RbExpr::replace, 4))?;
+    class.define_method("map_dict", method!(RbExpr::map_dict, 3))?;
     class.define_method("extend_constant", method!(RbExpr::extend_constant, 2))?;
-    class.define_method("any", method!(RbExpr::any, 0))?;
-    class.define_method("all", method!(RbExpr::all, 0))?;
+    class.define_method("cut", method!(RbExpr::cut, 4))?;
+    class.define_method("hist", method!(RbExpr::hist, 4))?;
+    class.define_method("to_dummies", method!(RbExpr::to_dummies, 3))?;
+    class.define_method("qcut", method!(RbExpr::qcut, 5))?;
+    class.define_method("rle", method!(RbExpr::rle, 0))?;
+    class.define_method("rle_id", method!(RbExpr::rle_id, 0))?;
+    class.define_method("peak_max", method!(RbExpr::peak_max, 0))?;
+    class.define_method("peak_min", method!(RbExpr::peak_min, 0))?;
+    class.define_method("set_sorted", method!(RbExpr::set_sorted, 2))?;
+    class.define_method("any", method!(RbExpr::any, 1))?;
+    class.define_method("all", method!(RbExpr::all, 1))?;
     class.define_method(
         "struct_field_by_name",
         method!(RbExpr::struct_field_by_name, 1),
@@ -465,6 +520,8 @@ fn init() -> RbResult<()> {
     class.define_singleton_method("lit", function!(crate::lazy::dsl::lit, 1))?;
     class.define_singleton_method("arange", function!(crate::lazy::dsl::arange, 3))?;
     class.define_singleton_method("repeat", function!(crate::lazy::dsl::repeat, 2))?;
+    class.define_singleton_method("int_range", function!(crate::lazy::dsl::int_range, 4))?;
+    class.define_singleton_method("int_ranges", function!(crate::lazy::dsl::int_ranges, 4))?;
     class.define_singleton_method("pearson_corr", function!(crate::lazy::dsl::pearson_corr, 3))?;
     class.define_singleton_method(
         "spearman_rank_corr",
@@ -485,10 +542,12 @@ fn init() -> RbResult<()> {
     class.define_singleton_method("new_from_csv", function!(RbLazyFrame::new_from_csv, -1))?;
     class.define_singleton_method(
         "new_from_parquet",
-        function!(RbLazyFrame::new_from_parquet, 7),
+        function!(RbLazyFrame::new_from_parquet, 8),
     )?;
     class.define_singleton_method("new_from_ipc", function!(RbLazyFrame::new_from_ipc, 6))?;
     class.define_method("write_json", method!(RbLazyFrame::write_json, 1))?;
+    class.define_method("sink_parquet", method!(RbLazyFrame::sink_parquet, 7))?;
+    class.define_method("sink_ipc", method!(RbLazyFrame::sink_ipc, 3))?;
     class.define_method("describe_plan", method!(RbLazyFrame::describe_plan, 0))?;
     class.define_method(
         "describe_optimized_plan",
@@ -498,10 +557,34 @@ fn init() -> RbResult<()> {
         "optimization_toggle",
         method!(RbLazyFrame::optimization_toggle, 7),
     )?;
+    class.define_method(
+        "with_predicate_pushdown",
+        method!(RbLazyFrame::with_predicate_pushdown, 1),
+    )?;
+    class.define_method(
+        "with_projection_pushdown",
+        method!(RbLazyFrame::with_projection_pushdown, 1),
+    )?;
+    class.define_method(
+        "with_slice_pushdown",
+        method!(RbLazyFrame::with_slice_pushdown, 1),
+    )?;
+    class.define_method(
+        "with_comm_subplan_elim",
+        method!(RbLazyFrame::with_comm_subplan_elim, 1),
+    )?;
     class.define_method("sort", method!(RbLazyFrame::sort, 3))?;
     class.define_method("sort_by_exprs", method!(RbLazyFrame::sort_by_exprs, 3))?;
     class.define_method("cache", method!(RbLazyFrame::cache, 0))?;
     class.define_method("collect", method!(RbLazyFrame::collect, 0))?;
+    class.define_method(
+        "collect_streaming",
+        method!(RbLazyFrame::collect_streaming, 0),
+    )?;
+    class.define_method(
+        "collect_with_plan",
+        method!(RbLazyFrame::collect_with_plan, 0),
+    )?;
     class.define_method("fetch", method!(RbLazyFrame::fetch, 1))?;
     class.define_method("filter", method!(RbLazyFrame::filter, 1))?;
     class.define_method("select", method!(RbLazyFrame::select, 1))?;
@@ -510,11 +593,11 @@ fn init() -> RbResult<()> {
     class.define_method("groupby_dynamic", method!(RbLazyFrame::groupby_dynamic, 9))?;
     class.define_method("with_context", method!(RbLazyFrame::with_context, 1))?;
     class.define_method("join_asof", method!(RbLazyFrame::join_asof, 11))?;
-    class.define_method("join", method!(RbLazyFrame::join, 7))?;
+    class.define_method("join", method!(RbLazyFrame::join, 8))?;
     class.define_method("with_columns", method!(RbLazyFrame::with_columns, 1))?;
     class.define_method("rename", method!(RbLazyFrame::rename, 2))?;
     class.define_method("reverse", method!(RbLazyFrame::reverse, 0))?;
-    class.define_method("shift", method!(RbLazyFrame::shift, 1))?;
+    class.define_method("shift", method!(RbLazyFrame::shift, 4))?;
     class.define_method("shift_and_fill", method!(RbLazyFrame::shift_and_fill, 2))?;
     class.define_method("fill_nan", method!(RbLazyFrame::fill_nan, 1))?;
     class.define_method("min", method!(RbLazyFrame::min, 0))?;
@@ -525,7 +608,7 @@ fn init() -> RbResult<()> {
     class.define_method("var", method!(RbLazyFrame::var, 1))?;
     class.define_method("median", method!(RbLazyFrame::median, 0))?;
     class.define_method("quantile", method!(RbLazyFrame::quantile, 2))?;
-    class.define_method("explode", method!(RbLazyFrame::explode, 1))?;
+    class.define_method("explode", method!(RbLazyFrame::explode, 2))?;
     class.define_method("unique", method!(RbLazyFrame::unique, 3))?;
     class.define_method("drop_nulls", method!(RbLazyFrame::drop_nulls, 1))?;
     class.define_method("slice", method!(RbLazyFrame::slice, 2))?;
@@ -544,6 +627,7 @@ fn init() -> RbResult<()> {
     class.define_method("agg", method!(RbLazyGroupBy::agg, 1))?;
     class.define_method("head", method!(RbLazyGroupBy::head, 1))?;
     class.define_method("tail", method!(RbLazyGroupBy::tail, 1))?;
+    class.define_method("apply", method!(RbLazyGroupBy::apply, 2))?;
 
     let class = module.define_class("RbSeries", Default::default())?;
     class.define_singleton_method("new_opt_bool", function!(RbSeries::new_opt_bool, 3))?;
@@ -567,6 +651,7 @@ fn init() -> RbResult<()> {
         "is_sorted_reverse_flag",
         method!(RbSeries::is_sorted_reverse_flag, 0),
     )?;
+    class.define_method("is_sorted", method!(RbSeries::is_sorted, 2))?;
     class.define_method("estimated_size", method!(RbSeries::estimated_size, 0))?;
     class.define_method("get_fmt", method!(RbSeries::get_fmt, 2))?;
     class.define_method("rechunk", method!(RbSeries::rechunk, 1))?;
@@ -585,9 +670,15 @@ fn init() -> RbResult<()> {
     class.define_method("min", method!(RbSeries::min, 0))?;
     class.define_method("sum", method!(RbSeries::sum, 0))?;
     class.define_method("n_chunks", method!(RbSeries::n_chunks, 0))?;
-    class.define_method("append", method!(RbSeries::append, 1))?;
+    class.define_method("append", method!(RbSeries::append, 2))?;
     class.define_method("extend", method!(RbSeries::extend, 1))?;
     class.define_method("new_from_index", method!(RbSeries::new_from_index, 2))?;
+    class.define_method("gather_every", method!(RbSeries::gather_every, 2))?;
+    class.define_method("replace", method!(RbSeries::replace, 4))?;
+    class.define_method("rle", method!(RbSeries::rle, 0))?;
+    class.define_method("rle_id", method!(RbSeries::rle_id, 0))?;
+    class.define_method("to_struct", method!(RbSeries::to_struct, 1))?;
+    class.define_method("hist", method!(RbSeries::hist, 2))?;
     class.define_method("filter", method!(RbSeries::filter, 1))?;
     class.define_method("add", method!(RbSeries::add, 1))?;
     class.define_method("sub", method!(RbSeries::sub, 1))?;
@@ -603,6 +694,7 @@ fn init() -> RbResult<()> {
     class.define_method("has_validity", method!(RbSeries::has_validity, 0))?;
     class.define_method("sample_n", method!(RbSeries::sample_n, 4))?;
     class.define_method("sample_frac", method!(RbSeries::sample_frac, 4))?;
+    class.define_method("shuffle", method!(RbSeries::shuffle, 1))?;
     class.define_method("series_equal", method!(RbSeries::series_equal, 3))?;
     class.define_method("eq", method!(RbSeries::eq, 1))?;
     class.define_method("neq", method!(RbSeries::neq, 1))?;
@@ -614,12 +706,13 @@ fn init() -> RbResult<()> {
     class.define_method("to_s", method!(RbSeries::to_s, 0))?;
     class.define_method("len", method!(RbSeries::len, 0))?;
     class.define_method("to_a", method!(RbSeries::to_a, 0))?;
+    class.define_method("to_numo", method!(RbSeries::to_numo, 0))?;
     class.define_method("median", method!(RbSeries::median, 0))?;
     class.define_method("quantile", method!(RbSeries::quantile, 2))?;
     class.define_method("_clone", method!(RbSeries::clone, 0))?;
     class.define_method("apply_lambda", method!(RbSeries::apply_lambda, 3))?;
     class.define_method("zip_with", method!(RbSeries::zip_with, 2))?;
-    class.define_method("to_dummies", method!(RbSeries::to_dummies, 0))?;
+    class.define_method("to_dummies", method!(RbSeries::to_dummies, 3))?;
     class.define_method("peak_max", method!(RbSeries::peak_max, 0))?;
     class.define_method("peak_min", method!(RbSeries::peak_min, 0))?;
     class.define_method("n_unique", method!(RbSeries::n_unique, 0))?;
@@ -631,6 +724,25 @@ fn init() -> RbResult<()> {
     class.define_method("cast", method!(RbSeries::cast, 2))?;
     class.define_method("time_unit", method!(RbSeries::time_unit, 0))?;
     class.define_method("set_at_idx", method!(RbSeries::set_at_idx, 2))?;
+    class.define_method("str_lengths", method!(RbSeries::str_lengths, 0))?;
+    class.define_method("str_to_uppercase", method!(RbSeries::str_to_uppercase, 0))?;
+    class.define_method("str_to_lowercase", method!(RbSeries::str_to_lowercase, 0))?;
+    class.define_method("str_contains", method!(RbSeries::str_contains, 3))?;
+    class.define_method("str_slice", method!(RbSeries::str_slice, 2))?;
+    class.define_method("year", method!(RbSeries::year, 0))?;
+    class.define_method("iso_year", method!(RbSeries::iso_year, 0))?;
+    class.define_method("quarter", method!(RbSeries::quarter, 0))?;
+    class.define_method("month", method!(RbSeries::month, 0))?;
+    class.define_method("week", method!(RbSeries::week, 0))?;
+    class.define_method("weekday", method!(RbSeries::weekday, 0))?;
+    class.define_method("day", method!(RbSeries::day, 0))?;
+    class.define_method("ordinal_day", method!(RbSeries::ordinal_day, 0))?;
+    class.define_method("hour", method!(RbSeries::hour, 0))?;
+    class.define_method("minute", method!(RbSeries::minute, 0))?;
+    class.define_method("second", method!(RbSeries::second, 0))?;
+    class.define_method("millisecond", method!(RbSeries::millisecond, 0))?;
+    class.define_method("microsecond", method!(RbSeries::microsecond, 0))?;
+    class.define_method("nanosecond", method!(RbSeries::nanosecond, 0))?;
 
     // set
     // class.define_method("set_with_mask_str", method!(RbSeries::set_with_mask_str, 2))?;
@@ -891,6 +1003,33 @@ fn concat_series(seq: RArray) -> RbResult<RbSeries> {
     Ok(s.into())
 }
 
+fn repeat_series(value: &RbSeries, n: usize, dtype: Option<Wrap<DataType>>) -> RbResult<RbSeries> {
+    let mut value = value.series.borrow().clone();
+    if value.len() != 1 {
+        return Err(RbValueError::new_err(format!(
+            "expected a single value, got {}",
+            value.len()
+        )));
+    }
+    if let Some(dtype) = dtype {
+        value = value.cast(&dtype.0).map_err(RbPolarsErr::from)?;
+    }
+    if value.null_count() == 1 {
+        return Ok(Series::full_null(value.name(), n, value.dtype()).into());
+    }
+    Ok(value.new_from_index(0, n).into())
+}
+
+fn int_range(start: i64, end: i64, step: i64, dtype: Wrap<DataType>) -> RbResult<RbSeries> {
+    utils::check_int_range_args(step, &dtype.0).map_err(RbValueError::new_err)?;
+    let values = utils::int_range_values(start, end, step);
+    let s = Int64Chunked::from_vec("int_range", values)
+        .into_series()
+        .strict_cast(&dtype.0)
+        .map_err(RbPolarsErr::from)?;
+    Ok(s.into())
+}
+
 fn ipc_schema(rb_f: Value) -> RbResult<Value> {
     use polars::export::arrow::io::ipc::read::read_file_metadata;
     let mut r = get_file_like(rb_f, false)?;
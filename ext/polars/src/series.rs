@@ -1,15 +1,18 @@
 use magnus::exception::arg_error;
-use magnus::{Error, RArray, Value};
+use magnus::{class, Error, Module, RArray, RClass, RModule, RString, Value};
 use polars::prelude::*;
 use polars::series::IsSorted;
+use polars_core::utils::get_supertype;
 use std::cell::RefCell;
 
 use crate::apply::series::{call_lambda_and_extract, ApplyLambda};
 use crate::apply_method_all_arrow_series2;
 use crate::conversion::*;
+use crate::lazy::dsl::RbExpr;
 use crate::list_construction::rb_seq_to_list;
 use crate::set::set_at_idx;
-use crate::{RbDataFrame, RbPolarsErr, RbResult, RbValueError};
+use crate::utils;
+use crate::{RbDataFrame, RbPolarsErr, RbResult, RbTypeError, RbValueError};
 
 #[magnus::wrap(class = "Polars::RbSeries")]
 pub struct RbSeries {
@@ -37,6 +40,11 @@ impl RbSeries {
         matches!(self.series.borrow().is_sorted(), IsSorted::Descending)
     }
 
+    pub fn is_sorted(&self, reverse: bool, nulls_last: bool) -> RbResult<bool> {
+        let s = self.series.borrow();
+        utils::is_sorted(&s, reverse, nulls_last).map_err(RbPolarsErr::from)
+    }
+
     pub fn new_opt_bool(name: String, obj: RArray, strict: bool) -> RbResult<RbSeries> {
         let len = obj.len();
         let mut builder = BooleanChunkedBuilder::new(&name, len);
@@ -284,24 +292,114 @@ impl RbSeries {
         self.series.borrow().n_chunks()
     }
 
-    pub fn append(&self, other: &RbSeries) -> RbResult<()> {
+    pub fn append(&self, other: &RbSeries, rechunk: bool) -> RbResult<()> {
         let mut binding = self.series.borrow_mut();
-        let res = binding.append(&other.series.borrow());
+        let other = coerce_numeric(&mut binding, &other.series.borrow())?;
+        let res = binding.append(&other);
         if let Err(e) = res {
-            Err(Error::runtime_error(e.to_string()))
-        } else {
-            Ok(())
+            return Err(Error::runtime_error(e.to_string()));
         }
+        if rechunk {
+            *binding = binding.rechunk();
+        }
+        Ok(())
     }
 
     pub fn extend(&self, other: &RbSeries) -> RbResult<()> {
-        self.series
-            .borrow_mut()
-            .extend(&other.series.borrow())
-            .map_err(RbPolarsErr::from)?;
+        let mut binding = self.series.borrow_mut();
+        let other = coerce_numeric(&mut binding, &other.series.borrow())?;
+        binding.extend(&other).map_err(RbPolarsErr::from)?;
         Ok(())
     }
 
+    pub fn gather_every(&self, n: usize, offset: usize) -> RbResult<Self> {
+        if n == 0 {
+            return Err(RbValueError::new_err("step must be greater than 0".into()));
+        }
+        let s = self.series.borrow();
+        let len = s.len();
+        Ok(s.slice(offset as i64, len.saturating_sub(offset))
+            .take_every(n)
+            .into())
+    }
+
+    pub fn replace(
+        &self,
+        old: &RbSeries,
+        new: &RbSeries,
+        default: Option<&RbSeries>,
+        return_dtype: Option<Wrap<DataType>>,
+    ) -> RbResult<Self> {
+        let old = old.series.borrow();
+        let new = new.series.borrow();
+        let default = default.map(|d| d.series.borrow());
+        utils::check_replace_args(&old, &new, default.as_deref()).map_err(RbValueError::new_err)?;
+        let s = utils::replace(
+            &self.series.borrow(),
+            &old,
+            &new,
+            default.as_deref(),
+            return_dtype.map(|dt| dt.0).as_ref(),
+        )
+        .map_err(RbPolarsErr::from)?;
+        Ok(s.into())
+    }
+
+    pub fn rle(&self) -> RbResult<Self> {
+        let s = utils::rle(&self.series.borrow()).map_err(RbPolarsErr::from)?;
+        Ok(s.into())
+    }
+
+    pub fn hist(&self, bins: Option<Vec<f64>>, bin_count: Option<usize>) -> RbResult<RbDataFrame> {
+        utils::check_hist_args(bins.as_deref(), bin_count).map_err(RbValueError::new_err)?;
+        let out = utils::hist(
+            &self.series.borrow(),
+            bins.as_deref(),
+            bin_count,
+            true,
+            true,
+        )
+        .map_err(RbPolarsErr::from)?;
+        let fields = out.struct_().map_err(RbPolarsErr::from)?.fields().to_vec();
+        let df = DataFrame::new(fields).map_err(RbPolarsErr::from)?;
+        Ok(df.into())
+    }
+
+    pub fn rle_id(&self) -> RbResult<Self> {
+        let s = utils::rle_id(&self.series.borrow()).map_err(RbPolarsErr::from)?;
+        Ok(s.into())
+    }
+
+    pub fn to_struct(&self, fields: Vec<String>) -> RbResult<Self> {
+        let s = self.series.borrow();
+        let width = fields.len();
+        if width == 0 {
+            return Err(RbValueError::new_err(
+                "fields must contain at least one name".into(),
+            ));
+        }
+        if s.len() % width != 0 {
+            return Err(RbValueError::new_err(format!(
+                "length {} is not divisible by the number of fields ({})",
+                s.len(),
+                width
+            )));
+        }
+        let columns = fields
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let mut col = s
+                    .slice(i as i64, s.len().saturating_sub(i))
+                    .take_every(width);
+                col.rename(name);
+                col
+            })
+            .collect::<Vec<_>>();
+        let out = StructChunked::new(s.name(), &columns).map_err(RbPolarsErr::from)?;
+        Ok(out.into_series().into())
+    }
+
     pub fn new_from_index(&self, index: usize, length: usize) -> RbResult<Self> {
         if index >= self.series.borrow().len() {
             Err(Error::new(arg_error(), "index is out of bounds"))
@@ -406,6 +504,10 @@ impl RbSeries {
         Ok(s.into())
     }
 
+    pub fn shuffle(&self, seed: Option<u64>) -> Self {
+        self.series.borrow().shuffle(seed).into()
+    }
+
     pub fn series_equal(&self, other: &RbSeries, null_equal: bool, strict: bool) -> bool {
         if strict {
             self.series.borrow().eq(&other.series.borrow())
@@ -523,6 +625,12 @@ impl RbSeries {
         }
     }
 
+    pub fn to_numo(&self) -> RbResult<Value> {
+        let s = self.series.borrow();
+        let (class_name, data) = numo_binary(&s)?;
+        numo_class(class_name)?.funcall("from_binary", (data,))
+    }
+
     pub fn median(&self) -> Option<f64> {
         match self.series.borrow().dtype() {
             DataType::Boolean => {
@@ -756,11 +864,13 @@ impl RbSeries {
         Ok(RbSeries::new(s))
     }
 
-    pub fn to_dummies(&self) -> RbResult<RbDataFrame> {
-        let df = self
-            .series
-            .borrow()
-            .to_dummies()
+    pub fn to_dummies(
+        &self,
+        separator: String,
+        drop_first: bool,
+        include_null: bool,
+    ) -> RbResult<RbDataFrame> {
+        let df = utils::to_dummies(&self.series.borrow(), &separator, drop_first, include_null)
             .map_err(RbPolarsErr::from)?;
         Ok(df.into())
     }
@@ -831,6 +941,77 @@ impl RbSeries {
         }
     }
 
+    // runs an expression on a single-column frame so
+    // namespace methods share the expression implementation,
+    // under a fixed name since col() treats wildcards and regexes specially
+    fn eval_expr(&self, f: impl FnOnce(RbExpr) -> RbExpr) -> RbResult<Self> {
+        let mut s = self.series.borrow().clone();
+        let name = s.name().to_string();
+        s.rename("series");
+        let expr = f(col("series").into()).inner;
+        let df = DataFrame::new(vec![s])
+            .and_then(|df| df.lazy().select([expr]).collect())
+            .map_err(RbPolarsErr::from)?;
+        let mut out = df.get_columns()[0].clone();
+        out.rename(&name);
+        Ok(out.into())
+    }
+
+    fn expect_utf8(&self) -> RbResult<()> {
+        let dtype = self.series.borrow().dtype().clone();
+        if dtype != DataType::Utf8 {
+            return Err(RbTypeError::new_err(format!(
+                "invalid series dtype: expected `Utf8`, got `{}`",
+                dtype
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn str_lengths(&self) -> RbResult<Self> {
+        self.expect_utf8()?;
+        self.eval_expr(|e| e.str_lengths())
+    }
+
+    pub fn str_to_uppercase(&self) -> RbResult<Self> {
+        self.expect_utf8()?;
+        self.eval_expr(|e| e.str_to_uppercase())
+    }
+
+    pub fn str_to_lowercase(&self) -> RbResult<Self> {
+        self.expect_utf8()?;
+        self.eval_expr(|e| e.str_to_lowercase())
+    }
+
+    pub fn str_contains(
+        &self,
+        pat: String,
+        literal: Option<bool>,
+        case_insensitive: Option<bool>,
+    ) -> RbResult<Self> {
+        self.expect_utf8()?;
+        self.eval_expr(|e| e.str_contains(pat, literal, case_insensitive))
+    }
+
+    pub fn str_slice(&self, start: i64, length: Option<u64>) -> RbResult<Self> {
+        self.expect_utf8()?;
+        self.eval_expr(|e| e.str_slice(start, length))
+    }
+
+    fn expect_temporal(&self) -> RbResult<()> {
+        let dtype = self.series.borrow().dtype().clone();
+        if !matches!(
+            dtype,
+            DataType::Date | DataType::Datetime(_, _) | DataType::Time
+        ) {
+            return Err(RbTypeError::new_err(format!(
+                "invalid series dtype: expected `Date`, `Datetime`, or `Time`, got `{}`",
+                dtype
+            )));
+        }
+        Ok(())
+    }
+
     pub fn set_at_idx(&self, idx: &RbSeries, values: &RbSeries) -> RbResult<()> {
         let mut s = self.series.borrow_mut();
         match set_at_idx(s.clone(), &idx.series.borrow(), &values.series.borrow()) {
@@ -843,6 +1024,32 @@ impl RbSeries {
     }
 }
 
+macro_rules! impl_temporal_accessor {
+    ($name:ident) => {
+        impl RbSeries {
+            pub fn $name(&self) -> RbResult<Self> {
+                self.expect_temporal()?;
+                self.eval_expr(|e| e.$name())
+            }
+        }
+    };
+}
+
+impl_temporal_accessor!(year);
+impl_temporal_accessor!(iso_year);
+impl_temporal_accessor!(quarter);
+impl_temporal_accessor!(month);
+impl_temporal_accessor!(week);
+impl_temporal_accessor!(weekday);
+impl_temporal_accessor!(day);
+impl_temporal_accessor!(ordinal_day);
+impl_temporal_accessor!(hour);
+impl_temporal_accessor!(minute);
+impl_temporal_accessor!(second);
+impl_temporal_accessor!(millisecond);
+impl_temporal_accessor!(microsecond);
+impl_temporal_accessor!(nanosecond);
+
 macro_rules! impl_set_with_mask {
     ($name:ident, $native:ty, $cast:ident, $variant:ident) => {
         fn $name(
@@ -1087,6 +1294,65 @@ impl_lt_eq_num!(lt_eq_f32, f32);
 impl_lt_eq_num!(lt_eq_f64, f64);
 // impl_lt_eq_num!(lt_eq_str, &str);
 
+pub(crate) fn numo_class(name: &str) -> RbResult<RClass> {
+    let numo: RModule = class::object().const_get("Numo")?;
+    numo.const_get(name)
+}
+
+// copy the contiguous values buffer of a numeric series into a binary string
+pub(crate) fn numo_binary(s: &Series) -> RbResult<(&'static str, RString)> {
+    if s.null_count() > 0 {
+        return Err(RbValueError::new_err(
+            "cannot convert series with nulls to Numo".into(),
+        ));
+    }
+    let s = s.rechunk();
+
+    macro_rules! binary {
+        ($ca:expr, $class_name:expr) => {{
+            let values = $ca.cont_slice().map_err(RbPolarsErr::from)?;
+            let bytes = unsafe {
+                std::slice::from_raw_parts(
+                    values.as_ptr() as *const u8,
+                    std::mem::size_of_val(values),
+                )
+            };
+            Ok(($class_name, RString::from_slice(bytes)))
+        }};
+    }
+
+    match s.dtype() {
+        DataType::Int8 => binary!(s.i8().unwrap(), "Int8"),
+        DataType::Int16 => binary!(s.i16().unwrap(), "Int16"),
+        DataType::Int32 => binary!(s.i32().unwrap(), "Int32"),
+        DataType::Int64 => binary!(s.i64().unwrap(), "Int64"),
+        DataType::UInt8 => binary!(s.u8().unwrap(), "UInt8"),
+        DataType::UInt16 => binary!(s.u16().unwrap(), "UInt16"),
+        DataType::UInt32 => binary!(s.u32().unwrap(), "UInt32"),
+        DataType::UInt64 => binary!(s.u64().unwrap(), "UInt64"),
+        DataType::Float32 => binary!(s.f32().unwrap(), "SFloat"),
+        DataType::Float64 => binary!(s.f64().unwrap(), "DFloat"),
+        dt => Err(RbTypeError::new_err(format!(
+            "cannot convert series of dtype {} to Numo",
+            dt
+        ))),
+    }
+}
+
+// cast both series to their supertype when appending numeric data of different dtypes
+fn coerce_numeric(s: &mut Series, other: &Series) -> RbResult<Series> {
+    let left = s.dtype().clone();
+    let right = other.dtype();
+    if &left == right || !(left.is_numeric() && right.is_numeric()) {
+        return Ok(other.clone());
+    }
+    let supertype = get_supertype(&left, right).map_err(RbPolarsErr::from)?;
+    if supertype != left {
+        *s = s.cast(&supertype).map_err(RbPolarsErr::from)?;
+    }
+    other.cast(&supertype).map_err(RbPolarsErr::from)
+}
+
 pub fn to_series_collection(rs: RArray) -> RbResult<Vec<Series>> {
     let mut series = Vec::new();
     for item in rs.each() {
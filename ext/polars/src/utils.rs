@@ -1,3 +1,6 @@
+use chrono_tz::{OffsetComponents, Tz};
+use polars::export::arrow::compute::cast::{cast, CastOptions};
+use polars::export::chrono::{self, Datelike, NaiveDate, NaiveDateTime, TimeZone as _};
 use polars::prelude::*;
 
 pub fn reinterpret(s: &Series, signed: bool) -> polars::prelude::PolarsResult<Series> {
@@ -12,12 +15,57 @@ pub fn reinterpret(s: &Series, signed: bool) -> polars::prelude::PolarsResult<Se
             Ok(ca.reinterpret_unsigned().into_series())
         }
         (DataType::Int64, true) => Ok(s.clone()),
+        (DataType::UInt32, true) => {
+            let ca = s.u32().unwrap();
+            let mut out: Int32Chunked = ca.into_iter().map(|v| v.map(|v| v as i32)).collect();
+            out.rename(s.name());
+            Ok(out.into_series())
+        }
+        (DataType::UInt32, false) => Ok(s.clone()),
+        (DataType::Int32, false) => {
+            let ca = s.i32().unwrap();
+            let mut out: UInt32Chunked = ca.into_iter().map(|v| v.map(|v| v as u32)).collect();
+            out.rename(s.name());
+            Ok(out.into_series())
+        }
+        (DataType::Int32, true) => Ok(s.clone()),
         _ => Err(PolarsError::ComputeError(
-            "reinterpret is only allowed for 64bit integers dtype, use cast otherwise".into(),
+            "reinterpret is only allowed for 32bit and 64bit integers dtype, use cast otherwise"
+                .into(),
         )),
     }
 }
 
+pub fn is_integer(dtype: &DataType) -> bool {
+    matches!(
+        dtype,
+        DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+    )
+}
+
+pub fn wrapping_cast(s: &Series, dtype: &DataType) -> PolarsResult<Series> {
+    if !(is_integer(s.dtype()) && is_integer(dtype)) {
+        return s.cast(dtype);
+    }
+    let options = CastOptions {
+        wrapped: true,
+        partial: false,
+    };
+    let chunks = s
+        .chunks()
+        .iter()
+        .map(|arr| cast(arr.as_ref(), &dtype.to_arrow(), options))
+        .collect::<Result<Vec<_>, _>>()?;
+    Series::try_from((s.name(), chunks))
+}
+
 #[macro_export]
 macro_rules! apply_method_all_arrow_series2 {
     ($self:expr, $method:ident, $($args:expr),*) => {
@@ -42,3 +90,1147 @@ macro_rules! apply_method_all_arrow_series2 {
         }
     }
 }
+
+pub fn is_sorted(s: &Series, reverse: bool, nulls_last: bool) -> PolarsResult<bool> {
+    let len = s.len();
+    let null_count = s.null_count();
+    let values = if nulls_last {
+        s.slice(0, len - null_count)
+    } else {
+        s.slice(null_count as i64, len - null_count)
+    };
+    // all nulls must be at one end
+    if values.null_count() > 0 {
+        return Ok(false);
+    }
+    if values.len() < 2 {
+        return Ok(true);
+    }
+    let n = values.len() - 1;
+    let lhs = values.slice(0, n);
+    let rhs = values.slice(1, n);
+    let ordered = if reverse {
+        lhs.gt_eq(&rhs)
+    } else {
+        lhs.lt_eq(&rhs)
+    }?;
+    Ok(ordered.all())
+}
+
+pub fn cut_output_type(include_breaks: bool) -> DataType {
+    if include_breaks {
+        DataType::Struct(vec![
+            Field::new("break_point", DataType::Float64),
+            Field::new("category", DataType::Categorical(None)),
+        ])
+    } else {
+        DataType::Categorical(None)
+    }
+}
+
+pub fn cut(
+    s: &Series,
+    breaks: &[f64],
+    labels: Option<&[String]>,
+    left_closed: bool,
+    include_breaks: bool,
+) -> PolarsResult<Series> {
+    let labels = match labels {
+        Some(labels) => {
+            if labels.len() != breaks.len() + 1 {
+                return Err(PolarsError::ComputeError(
+                    format!("expected {} labels, got {}", breaks.len() + 1, labels.len()).into(),
+                ));
+            }
+            labels.to_vec()
+        }
+        None => {
+            let mut edges = vec![f64::NEG_INFINITY];
+            edges.extend(breaks);
+            edges.push(f64::INFINITY);
+            edges
+                .windows(2)
+                .map(|w| {
+                    if left_closed {
+                        format!("[{:?}, {:?})", w[0], w[1])
+                    } else {
+                        format!("({:?}, {:?}]", w[0], w[1])
+                    }
+                })
+                .collect()
+        }
+    };
+
+    let values = s.cast(&DataType::Float64)?;
+    let bins: Vec<Option<usize>> = values
+        .f64()?
+        .into_iter()
+        .map(|v| {
+            v.map(|v| {
+                if left_closed {
+                    breaks.partition_point(|b| *b <= v)
+                } else {
+                    breaks.partition_point(|b| *b < v)
+                }
+            })
+        })
+        .collect();
+
+    let categories: Utf8Chunked = bins.iter().map(|i| i.map(|i| labels[i].as_str())).collect();
+    let mut categories = categories
+        .into_series()
+        .cast(&DataType::Categorical(None))?;
+    if !include_breaks {
+        categories.rename(s.name());
+        return Ok(categories);
+    }
+
+    categories.rename("category");
+    let break_points: Float64Chunked = bins
+        .iter()
+        .map(|i| i.map(|i| breaks.get(i).copied().unwrap_or(f64::INFINITY)))
+        .collect();
+    let mut break_points = break_points.into_series();
+    break_points.rename("break_point");
+    StructChunked::new(s.name(), &[break_points, categories]).map(|ca| ca.into_series())
+}
+
+pub fn check_hist_args(bins: Option<&[f64]>, bin_count: Option<usize>) -> Result<(), String> {
+    if let Some(bins) = bins {
+        if bin_count.is_some() {
+            return Err("can only provide one of bins or bin_count".into());
+        }
+        if bins.len() < 2 {
+            return Err("bins must contain at least 2 edges".into());
+        }
+        if !bins.windows(2).all(|w| w[0] < w[1]) {
+            return Err("bins must be strictly increasing".into());
+        }
+    }
+    if bin_count == Some(0) {
+        return Err("bin_count must be greater than 0".into());
+    }
+    Ok(())
+}
+
+pub fn hist_output_type(include_category: bool, include_breakpoint: bool) -> DataType {
+    if !include_category && !include_breakpoint {
+        return DataType::UInt32;
+    }
+    let mut fields = Vec::new();
+    if include_breakpoint {
+        fields.push(Field::new("break_point", DataType::Float64));
+    }
+    if include_category {
+        fields.push(Field::new("category", DataType::Categorical(None)));
+    }
+    fields.push(Field::new("count", DataType::UInt32));
+    DataType::Struct(fields)
+}
+
+pub fn hist(
+    s: &Series,
+    bins: Option<&[f64]>,
+    bin_count: Option<usize>,
+    include_category: bool,
+    include_breakpoint: bool,
+) -> PolarsResult<Series> {
+    let values = s.cast(&DataType::Float64)?;
+    let values: Vec<f64> = values
+        .f64()?
+        .into_iter()
+        .flatten()
+        .filter(|v| !v.is_nan())
+        .collect();
+
+    let edges = match bins {
+        Some(bins) => bins.to_vec(),
+        None => {
+            let bin_count = bin_count.unwrap_or(10);
+            let (mut min, mut max) = values
+                .iter()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+                    (lo.min(*v), hi.max(*v))
+                });
+            if values.is_empty() {
+                (min, max) = (0.0, 1.0);
+            } else if min == max {
+                (min, max) = (min - 0.5, max + 0.5);
+            }
+            let width = (max - min) / bin_count as f64;
+            let mut edges: Vec<f64> = (0..bin_count).map(|i| min + width * i as f64).collect();
+            edges.push(max);
+            edges
+        }
+    };
+
+    // bins are (lo, hi] except the first, which also includes its lower edge
+    let mut counts = vec![0u32; edges.len() - 1];
+    for v in values {
+        match edges.partition_point(|e| *e < v) {
+            0 if v == edges[0] => counts[0] += 1,
+            i if i > 0 && i < edges.len() => counts[i - 1] += 1,
+            _ => {}
+        }
+    }
+
+    let mut count = UInt32Chunked::from_vec("count", counts).into_series();
+    if !include_category && !include_breakpoint {
+        count.rename(s.name());
+        return Ok(count);
+    }
+
+    let mut fields = Vec::new();
+    if include_breakpoint {
+        let break_points = Float64Chunked::from_vec("break_point", edges[1..].to_vec());
+        fields.push(break_points.into_series());
+    }
+    if include_category {
+        let categories: Utf8Chunked = edges
+            .windows(2)
+            .enumerate()
+            .map(|(i, w)| {
+                // the first bin also includes its lower edge
+                let open = if i == 0 { '[' } else { '(' };
+                Some(format!("{}{:?}, {:?}]", open, w[0], w[1]))
+            })
+            .collect();
+        let mut categories = categories
+            .into_series()
+            .cast(&DataType::Categorical(None))?;
+        categories.rename("category");
+        fields.push(categories);
+    }
+    fields.push(count);
+    StructChunked::new(s.name(), &fields).map(|ca| ca.into_series())
+}
+
+pub fn to_dummies(
+    s: &Series,
+    separator: &str,
+    drop_first: bool,
+    include_null: bool,
+) -> PolarsResult<DataFrame> {
+    let mut uniques = s.drop_nulls().unique()?.sort(false);
+    if drop_first && !uniques.is_empty() {
+        uniques = uniques.slice(1, uniques.len() - 1);
+    }
+    let names = uniques.cast(&DataType::Utf8)?;
+
+    let mut columns = Vec::with_capacity(uniques.len() + 1);
+    for (i, name) in names.utf8()?.into_iter().enumerate() {
+        let mut column = s
+            .equal(&uniques.slice(i as i64, 1))?
+            .fill_null_with_values(false)?
+            .into_series()
+            .cast(&DataType::UInt8)?;
+        column.rename(&format!(
+            "{}{}{}",
+            s.name(),
+            separator,
+            name.unwrap_or("null")
+        ));
+        columns.push(column);
+    }
+    if include_null {
+        let mut column = s.is_null().into_series().cast(&DataType::UInt8)?;
+        column.rename(&format!("{}{}null", s.name(), separator));
+        columns.push(column);
+    }
+    DataFrame::new(columns)
+}
+
+pub fn rolling_rank_output_type(method: &RankMethod) -> DataType {
+    match method {
+        RankMethod::Average => DataType::Float32,
+        _ => DataType::UInt32,
+    }
+}
+
+// rank of each value within the window ending at it
+pub fn rolling_rank(
+    s: &Series,
+    window_size: usize,
+    min_periods: usize,
+    method: &RankMethod,
+) -> PolarsResult<Series> {
+    let values = s.cast(&DataType::Float64)?;
+    let values: Vec<Option<f64>> = values.f64()?.into_iter().collect();
+    let ranks: Vec<Option<f64>> = (0..values.len())
+        .map(|i| {
+            let current = values[i]?;
+            let window: Vec<f64> = values[(i + 1).saturating_sub(window_size)..=i]
+                .iter()
+                .flatten()
+                .copied()
+                .collect();
+            if window.len() < min_periods {
+                return None;
+            }
+            let less = window.iter().filter(|v| **v < current).count() as f64;
+            let equal = window.iter().filter(|v| **v == current).count() as f64;
+            let rank = match method {
+                RankMethod::Min => less + 1.0,
+                RankMethod::Max => less + equal,
+                RankMethod::Average => less + (equal + 1.0) / 2.0,
+                RankMethod::Dense => {
+                    let mut smaller: Vec<f64> =
+                        window.iter().copied().filter(|v| *v < current).collect();
+                    smaller.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                    smaller.dedup();
+                    smaller.len() as f64 + 1.0
+                }
+                // ordinal ranks ties by position, so the current value comes last
+                _ => less + equal,
+            };
+            Some(rank)
+        })
+        .collect();
+    let out = Float64Chunked::from_iter_options(s.name(), ranks.into_iter()).into_series();
+    out.cast(&rolling_rank_output_type(method))
+}
+
+// difference with the value `n` rows earlier, where `n` may vary per row
+pub fn diff_by(s: &Series, n: &Series) -> PolarsResult<Series> {
+    let len = s.len();
+    if n.len() != 1 && n.len() != len {
+        return Err(PolarsError::ComputeError(
+            format!("n must have length 1 or {}, got {}", len, n.len()).into(),
+        ));
+    }
+    let n = n.cast(&DataType::Int64)?;
+    let n = if n.len() == 1 {
+        n.new_from_index(0, len)
+    } else {
+        n
+    };
+    let idx: IdxCa = n
+        .i64()?
+        .into_iter()
+        .enumerate()
+        .map(|(i, lag)| {
+            let j = i as i64 - lag?;
+            (0..len as i64).contains(&j).then_some(j as IdxSize)
+        })
+        .collect();
+    let previous = s.take(&idx)?;
+    let mut out = s - &previous;
+    out.rename(s.name());
+    Ok(out)
+}
+
+// an alternation of literals, which the regex crate matches with Aho-Corasick
+pub fn literal_alternation(
+    patterns: &[String],
+    ascii_case_insensitive: bool,
+) -> Result<regex::Regex, regex::Error> {
+    let alternation = patterns
+        .iter()
+        .map(|pat| {
+            if ascii_case_insensitive {
+                regex::escape(&pat.to_ascii_lowercase())
+            } else {
+                regex::escape(pat)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("|");
+    regex::Regex::new(&alternation)
+}
+
+// named groups keep their name, unnamed groups are named by their index
+pub fn capture_group_names(re: &regex::Regex) -> Vec<String> {
+    re.capture_names()
+        .enumerate()
+        .skip(1)
+        .map(|(i, name)| name.map(|n| n.to_string()).unwrap_or_else(|| i.to_string()))
+        .collect()
+}
+
+pub fn extract_groups_output_type(re: &regex::Regex) -> DataType {
+    DataType::Struct(
+        capture_group_names(re)
+            .into_iter()
+            .map(|name| Field::new(&name, DataType::Utf8))
+            .collect(),
+    )
+}
+
+pub fn extract_groups(s: &Series, re: &regex::Regex) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    let names = capture_group_names(re);
+    let mut builders: Vec<Utf8ChunkedBuilder> = names
+        .iter()
+        .map(|name| Utf8ChunkedBuilder::new(name, ca.len(), ca.len() * 5))
+        .collect();
+    for opt_v in ca.into_iter() {
+        let caps = opt_v.and_then(|v| re.captures(v));
+        for (i, builder) in builders.iter_mut().enumerate() {
+            match caps.as_ref().and_then(|c| c.get(i + 1)) {
+                Some(m) => builder.append_value(m.as_str()),
+                None => builder.append_null(),
+            }
+        }
+    }
+    let fields: Vec<Series> = builders
+        .into_iter()
+        .map(|b| b.finish().into_series())
+        .collect();
+    StructChunked::new(s.name(), &fields).map(|ca| ca.into_series())
+}
+
+// days since the epoch of the first or last day of the month
+fn month_boundary_days(days: i64, end: bool) -> i64 {
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    let date = epoch + chrono::Duration::days(days);
+    let start = date.with_day(1).unwrap();
+    let target = if end {
+        let (year, month) = if start.month() == 12 {
+            (start.year() + 1, 1)
+        } else {
+            (start.year(), start.month() + 1)
+        };
+        NaiveDate::from_ymd_opt(year, month, 1)
+            .unwrap()
+            .pred_opt()
+            .unwrap()
+    } else {
+        start
+    };
+    (target - epoch).num_days()
+}
+
+fn units_to_naive(v: i64, units_per_sec: i64) -> Option<NaiveDateTime> {
+    let secs = v.div_euclid(units_per_sec);
+    let nanos = v.rem_euclid(units_per_sec) * (1_000_000_000 / units_per_sec);
+    NaiveDateTime::from_timestamp_opt(secs, nanos as u32)
+}
+
+fn naive_to_units(ndt: &NaiveDateTime, units_per_sec: i64) -> i64 {
+    ndt.timestamp() * units_per_sec
+        + ndt.timestamp_subsec_nanos() as i64 / (1_000_000_000 / units_per_sec)
+}
+
+// rolls dates to the start or end of their month, keeping the time of day
+pub fn month_boundary(s: &Series, end: bool) -> PolarsResult<Series> {
+    let physical = s.to_physical_repr();
+    let out = match s.dtype() {
+        DataType::Date => physical
+            .i32()?
+            .apply(|v| month_boundary_days(v as i64, end) as i32)
+            .into_date()
+            .into_series(),
+        DataType::Datetime(tu, tz) => {
+            let units_per_sec = match tu {
+                TimeUnit::Nanoseconds => 1_000_000_000,
+                TimeUnit::Microseconds => 1_000_000,
+                TimeUnit::Milliseconds => 1_000,
+            };
+            let per_day = 86_400 * units_per_sec;
+            let snap = |v: i64| {
+                let days = month_boundary_days(v.div_euclid(per_day), end);
+                days * per_day + v.rem_euclid(per_day)
+            };
+            let ca = physical.i64()?;
+            let mut out = match tz {
+                None => ca.apply(snap),
+                // snap in local time so the month is the one seen in the time zone
+                Some(tz) => {
+                    let parsed: Tz = tz.parse().map_err(|_| {
+                        PolarsError::ComputeError(
+                            format!("unable to parse time zone: '{}'", tz).into(),
+                        )
+                    })?;
+                    ca.into_iter()
+                        .map(|opt_v| {
+                            let v = match opt_v {
+                                Some(v) => v,
+                                None => return Ok(None),
+                            };
+                            let invalid = || {
+                                PolarsError::ComputeError(
+                                    format!("datetime {} is out of range", v).into(),
+                                )
+                            };
+                            let utc = units_to_naive(v, units_per_sec).ok_or_else(invalid)?;
+                            let local = parsed.from_utc_datetime(&utc).naive_local();
+                            let snapped = snap(naive_to_units(&local, units_per_sec));
+                            let snapped =
+                                units_to_naive(snapped, units_per_sec).ok_or_else(invalid)?;
+                            let out = parsed.from_local_datetime(&snapped).earliest().ok_or_else(
+                                || {
+                                    PolarsError::ComputeError(
+                                        format!(
+                                            "datetime {} does not exist in time zone {}",
+                                            snapped, tz
+                                        )
+                                        .into(),
+                                    )
+                                },
+                            )?;
+                            Ok(Some(naive_to_units(&out.naive_utc(), units_per_sec)))
+                        })
+                        .collect::<PolarsResult<Int64Chunked>>()?
+                }
+            };
+            out.rename(s.name());
+            out.into_datetime(*tu, tz.clone()).into_series()
+        }
+        dt => {
+            return Err(PolarsError::ComputeError(
+                format!("expected a date or datetime, got {:?}", dt).into(),
+            ))
+        }
+    };
+    Ok(out)
+}
+
+fn units_per_sec(tu: TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => 1_000_000_000,
+        TimeUnit::Microseconds => 1_000_000,
+        TimeUnit::Milliseconds => 1_000,
+    }
+}
+
+pub fn combine_output_type(dtype: &DataType, tu: TimeUnit) -> DataType {
+    match dtype {
+        DataType::Datetime(_, tz) => DataType::Datetime(tu, tz.clone()),
+        _ => DataType::Datetime(tu, None),
+    }
+}
+
+// sets the time of day, in local time for time zone aware datetimes
+pub fn combine(s: &Series, time: &Series, tu: TimeUnit) -> PolarsResult<Series> {
+    let len = s.len();
+    if !matches!(s.dtype(), DataType::Date | DataType::Datetime(_, _)) {
+        return Err(PolarsError::ComputeError(
+            format!("expected a date or datetime, got {:?}", s.dtype()).into(),
+        ));
+    }
+    if time.len() != 1 && time.len() != len {
+        return Err(PolarsError::ComputeError(
+            format!("time must have length 1 or {}, got {}", len, time.len()).into(),
+        ));
+    }
+    // times are stored as nanoseconds since midnight
+    let ns_per_day = 86_400_000_000_000;
+    let time = match time.dtype() {
+        DataType::Time => time.to_physical_repr().into_owned(),
+        dt if is_integer(dt) => time.cast(&DataType::Int64)?,
+        dt => {
+            return Err(PolarsError::ComputeError(
+                format!("expected a time, got {:?}", dt).into(),
+            ))
+        }
+    };
+    if let Some(t) = time
+        .i64()?
+        .into_iter()
+        .flatten()
+        .find(|t| !(0..ns_per_day).contains(t))
+    {
+        return Err(PolarsError::ComputeError(
+            format!("time must be within a day, got {} nanoseconds", t).into(),
+        ));
+    }
+    let time = if time.len() == 1 {
+        time.new_from_index(0, len)
+    } else {
+        time
+    };
+    let ns_per_unit = 1_000_000_000 / units_per_sec(tu);
+    let per_day = ns_per_day / ns_per_unit;
+    let times = time.i64()?;
+    let mut out: Int64Chunked = match s.dtype() {
+        DataType::Datetime(src_tu, Some(tz)) => {
+            let parsed: Tz = tz.parse().map_err(|_| {
+                PolarsError::ComputeError(format!("unable to parse time zone: '{}'", tz).into())
+            })?;
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+            let physical = s.to_physical_repr();
+            physical
+                .i64()?
+                .into_iter()
+                .zip(times.into_iter())
+                .map(|(v, t)| {
+                    let (v, t) = match (v, t) {
+                        (Some(v), Some(t)) => (v, t),
+                        _ => return Ok(None),
+                    };
+                    let invalid = || {
+                        PolarsError::ComputeError(format!("datetime {} is out of range", v).into())
+                    };
+                    let utc = units_to_naive(v, units_per_sec(*src_tu)).ok_or_else(invalid)?;
+                    // the date is the one seen in the time zone
+                    let date = parsed.from_utc_datetime(&utc).naive_local().date();
+                    let local = (date - epoch).num_days() * per_day + t / ns_per_unit;
+                    let local = units_to_naive(local, units_per_sec(tu)).ok_or_else(invalid)?;
+                    let out = parsed
+                        .from_local_datetime(&local)
+                        .earliest()
+                        .ok_or_else(|| {
+                            PolarsError::ComputeError(
+                                format!("datetime {} does not exist in time zone {}", local, tz)
+                                    .into(),
+                            )
+                        })?;
+                    Ok(Some(naive_to_units(&out.naive_utc(), units_per_sec(tu))))
+                })
+                .collect::<PolarsResult<_>>()?
+        }
+        _ => {
+            let dates = s.cast(&DataType::Date)?.to_physical_repr().into_owned();
+            dates
+                .i32()?
+                .into_iter()
+                .zip(times.into_iter())
+                .map(|(d, t)| Some(d? as i64 * per_day + t? / ns_per_unit))
+                .collect()
+        }
+    };
+    out.rename(s.name());
+    let tz = match s.dtype() {
+        DataType::Datetime(_, tz) => tz.clone(),
+        _ => None,
+    };
+    Ok(out.into_datetime(tu, tz).into_series())
+}
+
+pub fn tz_offset_output_type(dtype: &DataType) -> DataType {
+    match dtype {
+        DataType::Datetime(tu, _) => DataType::Duration(*tu),
+        _ => DataType::Duration(TimeUnit::Microseconds),
+    }
+}
+
+// the base utc offset or the dst offset of each datetime in its time zone
+pub fn tz_offset(s: &Series, dst: bool) -> PolarsResult<Series> {
+    let name = if dst { "dst_offset" } else { "base_utc_offset" };
+    let (tu, tz) = match s.dtype() {
+        DataType::Datetime(tu, Some(tz)) => (*tu, tz),
+        dt => {
+            return Err(PolarsError::ComputeError(
+                format!("{} requires a time zone aware datetime, got {:?}", name, dt).into(),
+            ))
+        }
+    };
+    let tz: Tz = tz.parse().map_err(|_| {
+        PolarsError::ComputeError(format!("unable to parse time zone: '{}'", tz).into())
+    })?;
+    let units_per_sec = match tu {
+        TimeUnit::Nanoseconds => 1_000_000_000,
+        TimeUnit::Microseconds => 1_000_000,
+        TimeUnit::Milliseconds => 1_000,
+    };
+    let physical = s.to_physical_repr();
+    let mut out: Int64Chunked = physical
+        .i64()?
+        .into_iter()
+        .map(|opt_v| {
+            let v = opt_v?;
+            let secs = v.div_euclid(units_per_sec);
+            let nanos = v.rem_euclid(units_per_sec) * (1_000_000_000 / units_per_sec);
+            let ndt = NaiveDateTime::from_timestamp_opt(secs, nanos as u32)?;
+            let offset = tz.offset_from_utc_datetime(&ndt);
+            let offset = if dst {
+                offset.dst_offset()
+            } else {
+                offset.base_utc_offset()
+            };
+            Some(offset.num_seconds() * units_per_sec)
+        })
+        .collect();
+    out.rename(s.name());
+    Ok(out.into_duration(tu).into_series())
+}
+
+// positions where a new run of equal values starts
+fn run_starts(s: &Series) -> PolarsResult<Vec<IdxSize>> {
+    let n = s.len();
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+    let lhs = s.slice(1, n - 1);
+    let rhs = s.slice(0, n - 1);
+    // nulls are equal to each other but not to values
+    let changed =
+        lhs.not_equal(&rhs)?.fill_null_with_values(false)? | (lhs.is_null() ^ rhs.is_null());
+    let mut starts = vec![0];
+    starts.extend(
+        changed
+            .into_no_null_iter()
+            .enumerate()
+            .filter(|(_, changed)| *changed)
+            .map(|(i, _)| (i + 1) as IdxSize),
+    );
+    Ok(starts)
+}
+
+pub fn rle(s: &Series) -> PolarsResult<Series> {
+    let starts = run_starts(s)?;
+    let mut lengths: Vec<IdxSize> = starts.windows(2).map(|w| w[1] - w[0]).collect();
+    if let Some(last) = starts.last() {
+        lengths.push(s.len() as IdxSize - last);
+    }
+    let lengths = IdxCa::from_vec("lengths", lengths).into_series();
+    let mut values = s.take(&IdxCa::from_vec("", starts))?;
+    values.rename("values");
+    StructChunked::new(s.name(), &[lengths, values]).map(|ca| ca.into_series())
+}
+
+pub fn rle_id(s: &Series) -> PolarsResult<Series> {
+    let starts = run_starts(s)?;
+    let mut ids = Vec::with_capacity(s.len());
+    for (id, w) in starts.windows(2).enumerate() {
+        ids.extend(std::iter::repeat(id as IdxSize).take((w[1] - w[0]) as usize));
+    }
+    if let Some(last) = starts.last() {
+        let id = (starts.len() - 1) as IdxSize;
+        ids.extend(std::iter::repeat(id).take(s.len() - *last as usize));
+    }
+    Ok(IdxCa::from_vec(s.name(), ids).into_series())
+}
+
+pub fn list_to_struct_output_type(dtype: &DataType, names: &[String]) -> DataType {
+    match dtype {
+        DataType::List(inner) => DataType::Struct(
+            names
+                .iter()
+                .map(|name| Field::new(name, *inner.clone()))
+                .collect(),
+        ),
+        dt => dt.clone(),
+    }
+}
+
+// one field per name, padding shorter lists with nulls
+pub fn list_to_struct(s: &Series, names: &[String], truncate: bool) -> PolarsResult<Series> {
+    let ca = s.list()?;
+    let width = names.len();
+    if !truncate {
+        let max_len = ca.lst_lengths().max().unwrap_or(0) as usize;
+        if max_len > width {
+            return Err(PolarsError::ComputeError(
+                format!("list of length {max_len} exceeds the struct width of {width}").into(),
+            ));
+        }
+    }
+    let fields = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let mut field = ca.lst_get(i as i64)?;
+            field.rename(name);
+            Ok(field)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    StructChunked::new(s.name(), &fields).map(|ca| ca.into_series())
+}
+
+// a field named exactly `name` takes precedence over a dotted path
+pub fn struct_path(dtype: &DataType, name: &str) -> Vec<String> {
+    match dtype {
+        DataType::Struct(fields) if fields.iter().any(|f| f.name().as_str() == name) => {
+            vec![name.to_string()]
+        }
+        _ => name.split('.').map(|v| v.to_string()).collect(),
+    }
+}
+
+pub fn struct_field_path_output_type(dtype: &DataType, path: &[String]) -> DataType {
+    let mut dtype = dtype.clone();
+    for name in path {
+        dtype = match &dtype {
+            DataType::Struct(fields) => match fields.iter().find(|f| f.name().as_str() == name) {
+                Some(field) => field.data_type().clone(),
+                None => return DataType::Unknown,
+            },
+            _ => return DataType::Unknown,
+        };
+    }
+    dtype
+}
+
+pub fn struct_field_path(s: &Series, path: &[String]) -> PolarsResult<Series> {
+    let mut out = s.clone();
+    for name in path {
+        let ca = out.struct_().map_err(|_| {
+            PolarsError::ComputeError(
+                format!(
+                    "cannot get field '{name}' from non-struct type {}",
+                    out.dtype()
+                )
+                .into(),
+            )
+        })?;
+        out = ca.field_by_name(name).map_err(|_| {
+            PolarsError::ComputeError(format!("struct field '{name}' not found").into())
+        })?;
+    }
+    Ok(out)
+}
+
+pub fn repeat_by(s: &Series, by: &Series, negative_as_empty: bool) -> PolarsResult<Series> {
+    let by = by.cast(&DataType::Int64)?;
+    let by = by.i64()?;
+    let by = if by.len() == 1 && s.len() != 1 {
+        by.new_from_index(0, s.len())
+    } else {
+        by.clone()
+    };
+    if by.len() != s.len() {
+        return Err(PolarsError::ComputeError(
+            format!("repeat_by expected {} counts, got {}", s.len(), by.len()).into(),
+        ));
+    }
+    if !negative_as_empty {
+        if let Some(count) = by.into_iter().flatten().find(|v| *v < 0) {
+            return Err(PolarsError::ComputeError(
+                format!("repeat_by count must not be negative, got {}", count).into(),
+            ));
+        }
+    }
+    let counts: IdxCa = by
+        .into_iter()
+        .map(|v| Some(v.unwrap_or(0).max(0) as IdxSize))
+        .collect();
+    let out = s.repeat_by(&counts).into_series();
+    if by.null_count() == 0 {
+        return Ok(out);
+    }
+    // a null count gives a null row instead of an empty list
+    let nulls = Series::full_null(s.name(), s.len(), out.dtype());
+    out.zip_with(&by.is_not_null(), &nulls)
+}
+
+// directory name used for null partition values, as in Hive
+pub const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+// percent-encode the characters that would break up a key=value directory name
+pub fn escape_partition_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '%' | '/' | '=' => out.push_str(&format!("%{:02X}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+pub fn unescape_partition_value(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let byte = std::str::from_utf8(&bytes[i + 1..i + 3])
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+            if let Some(byte) = byte {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+// validate a duration string like "1h30m" before it reaches Duration::parse, which panics
+pub fn check_duration(duration: &str) -> Result<(), String> {
+    let err = || format!("invalid duration string: '{}'", duration);
+    let mut rest = duration.strip_prefix('-').unwrap_or(duration);
+    if rest.is_empty() {
+        return Err(err());
+    }
+    while !rest.is_empty() {
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 {
+            return Err(err());
+        }
+        rest = &rest[digits..];
+        let unit = ["ns", "us", "ms", "mo", "s", "m", "h", "d", "w", "y", "i"]
+            .iter()
+            .find(|unit| rest.starts_with(*unit))
+            .ok_or_else(err)?;
+        rest = &rest[unit.len()..];
+    }
+    Ok(())
+}
+
+pub fn check_replace_args(
+    old: &Series,
+    new: &Series,
+    default: Option<&Series>,
+) -> Result<(), String> {
+    if old.len() != new.len() {
+        return Err(format!(
+            "old and new must have the same length, got {} and {}",
+            old.len(),
+            new.len()
+        ));
+    }
+    if matches!(default, Some(default) if default.len() != 1) {
+        return Err("default must be a single value".into());
+    }
+    Ok(())
+}
+
+pub fn replace_output_type(
+    dtype: &DataType,
+    new: &DataType,
+    default: Option<&DataType>,
+) -> PolarsResult<DataType> {
+    // categoricals are matched and replaced as strings
+    if let DataType::Categorical(_) = dtype {
+        let out = replace_output_type(&DataType::Utf8, new, default)?;
+        return Ok(if out == DataType::Utf8 {
+            DataType::Categorical(None)
+        } else {
+            out
+        });
+    }
+    polars_core::utils::get_supertype(new, default.unwrap_or(dtype))
+}
+
+pub fn replace(
+    s: &Series,
+    old: &Series,
+    new: &Series,
+    default: Option<&Series>,
+    return_dtype: Option<&DataType>,
+) -> PolarsResult<Series> {
+    if let DataType::Categorical(_) = s.dtype() {
+        let out = replace(&s.cast(&DataType::Utf8)?, old, new, default, return_dtype)?;
+        return match return_dtype {
+            None if out.dtype() == &DataType::Utf8 => out.cast(&DataType::Categorical(None)),
+            _ => Ok(out),
+        };
+    }
+
+    let dtype = match return_dtype {
+        Some(dtype) => dtype.clone(),
+        None => replace_output_type(s.dtype(), new.dtype(), default.map(|d| d.dtype()))?,
+    };
+
+    let mut key = old.cast(s.dtype())?;
+    if key.n_unique()? != key.len() {
+        return Err(PolarsError::ComputeError(
+            "old values must be unique".into(),
+        ));
+    }
+    key.rename("key");
+    let mut value = new.cast(&dtype)?;
+    value.rename("value");
+    let found = BooleanChunked::full("found", true, key.len()).into_series();
+    let mapping = DataFrame::new(vec![key, value, found])?;
+
+    let mut left = s.clone();
+    left.rename("key");
+    let joined = DataFrame::new(vec![left])?.left_join(&mapping, ["key"], ["key"])?;
+    let found = joined
+        .column("found")?
+        .bool()?
+        .fill_null_with_values(false)?;
+
+    let fallback = match default {
+        Some(default) if default.len() == 1 => default.cast(&dtype)?.new_from_index(0, s.len()),
+        Some(default) if default.len() == s.len() => default.cast(&dtype)?,
+        Some(default) => {
+            return Err(PolarsError::ComputeError(
+                format!(
+                    "default must have length 1 or {}, got {}",
+                    s.len(),
+                    default.len()
+                )
+                .into(),
+            ))
+        }
+        None => s.cast(&dtype)?,
+    };
+    let mut out = joined.column("value")?.zip_with(&found, &fallback)?;
+    if s.null_count() > 0 {
+        let nulls = Series::full_null(s.name(), s.len(), &dtype);
+        out = out.zip_with(&s.is_not_null(), &nulls)?;
+    }
+    out.rename(s.name());
+    Ok(out)
+}
+
+// validates the step and dtype shared by int_range and int_ranges
+pub fn check_int_range_args(step: i64, dtype: &DataType) -> Result<(), String> {
+    if step == 0 {
+        return Err("step must not be 0".into());
+    }
+    if !is_integer(dtype) {
+        return Err(format!("dtype must be an integer type, got {}", dtype));
+    }
+    Ok(())
+}
+
+fn range_bound(s: &Series, name: &str) -> PolarsResult<i64> {
+    let s = s.cast(&DataType::Int64)?;
+    if s.len() != 1 {
+        return Err(PolarsError::ComputeError(
+            format!(
+                "int_range expects a single value for {name}, got {}",
+                s.len()
+            )
+            .into(),
+        ));
+    }
+    s.i64()?.get(0).ok_or_else(|| {
+        PolarsError::ComputeError(format!("int_range {name} must not be null").into())
+    })
+}
+
+pub fn int_range(
+    start: &Series,
+    end: &Series,
+    step: i64,
+    dtype: &DataType,
+) -> PolarsResult<Series> {
+    let start = range_bound(start, "start")?;
+    let end = range_bound(end, "end")?;
+    Int64Chunked::from_vec("int_range", int_range_values(start, end, step))
+        .into_series()
+        .strict_cast(dtype)
+}
+
+pub fn int_range_values(start: i64, end: i64, step: i64) -> Vec<i64> {
+    if step > 0 {
+        (start..end).step_by(step as usize).collect()
+    } else {
+        (end..=start)
+            .rev()
+            .step_by(step.unsigned_abs() as usize)
+            .take_while(|&v| v > end)
+            .collect()
+    }
+}
+
+pub fn int_ranges(start: &Series, end: &Series, step: i64) -> PolarsResult<Series> {
+    let start = start.cast(&DataType::Int64)?;
+    let end = end.cast(&DataType::Int64)?;
+    let len = start.len().max(end.len());
+    let start = if start.len() == 1 {
+        start.new_from_index(0, len)
+    } else {
+        start
+    };
+    let end = if end.len() == 1 {
+        end.new_from_index(0, len)
+    } else {
+        end
+    };
+    if start.len() != end.len() {
+        return Err(PolarsError::ComputeError(
+            format!(
+                "int_ranges expected start and end of the same length, got {} and {}",
+                start.len(),
+                end.len()
+            )
+            .into(),
+        ));
+    }
+
+    let mut builder =
+        ListPrimitiveChunkedBuilder::<Int64Type>::new(start.name(), len, len * 5, DataType::Int64);
+    for (start, end) in start.i64()?.into_iter().zip(end.i64()?) {
+        match (start, end) {
+            (Some(start), Some(end)) => builder.append_slice(&int_range_values(start, end, step)),
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish().into_series())
+}
+
+// insertion indices for the elements in a sorted series, before (left) or after (right) equal values
+pub fn search_sorted_side(s: &Series, element: &Series, right: bool) -> PolarsResult<Series> {
+    // nulls are sorted first by default
+    let offset = if s.null_count() > 0 && s.is_null().get(0) == Some(true) {
+        s.null_count()
+    } else {
+        0
+    };
+    let values = s.drop_nulls();
+
+    fn positions<T: PartialOrd>(
+        values: &[T],
+        elements: impl Iterator<Item = Option<T>>,
+        offset: usize,
+        right: bool,
+    ) -> IdxCa {
+        elements
+            .map(|e| {
+                e.map(|e| {
+                    let pos = if right {
+                        values.partition_point(|v| *v <= e)
+                    } else {
+                        values.partition_point(|v| *v < e)
+                    };
+                    (offset + pos) as IdxSize
+                })
+            })
+            .collect()
+    }
+
+    // compare integers as i128 so every i64 and u64 value is exact
+    fn int_values(s: &Series) -> PolarsResult<Vec<Option<i128>>> {
+        let s = s.to_physical_repr();
+        let out = match s.dtype() {
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => s
+                .cast(&DataType::UInt64)?
+                .u64()?
+                .into_iter()
+                .map(|v| v.map(|v| v as i128))
+                .collect(),
+            _ => s
+                .cast(&DataType::Int64)?
+                .i64()?
+                .into_iter()
+                .map(|v| v.map(|v| v as i128))
+                .collect(),
+        };
+        Ok(out)
+    }
+
+    fn is_float(dtype: &DataType) -> bool {
+        matches!(dtype, DataType::Float32 | DataType::Float64)
+    }
+
+    let out = match values.dtype() {
+        DataType::Utf8 => {
+            let values: Vec<&str> = values.utf8()?.into_no_null_iter().collect();
+            let element = element.cast(&DataType::Utf8)?;
+            positions(&values, element.utf8()?.into_iter(), offset, right)
+        }
+        DataType::Date | DataType::Datetime(_, _) | DataType::Duration(_) | DataType::Time => {
+            let element = element.cast(s.dtype())?;
+            let values: Vec<i128> = int_values(&values)?.into_iter().flatten().collect();
+            positions(&values, int_values(&element)?.into_iter(), offset, right)
+        }
+        dt if dt.is_numeric() && (is_float(dt) || is_float(element.dtype())) => {
+            let values = values.cast(&DataType::Float64)?;
+            let values: Vec<f64> = values.f64()?.into_no_null_iter().collect();
+            let element = element.cast(&DataType::Float64)?;
+            positions(&values, element.f64()?.into_iter(), offset, right)
+        }
+        dt if dt.is_numeric() => {
+            let values: Vec<i128> = int_values(&values)?.into_iter().flatten().collect();
+            positions(&values, int_values(element)?.into_iter(), offset, right)
+        }
+        dt => {
+            return Err(PolarsError::ComputeError(
+                format!("search_sorted side is not supported for dtype {}", dt).into(),
+            ))
+        }
+    };
+    let mut out = out.into_series();
+    out.rename(s.name());
+    Ok(out)
+}
@@ -31,15 +31,33 @@ module Polars
       DataFrame._from_hash(data, columns: columns)
     end
 
+    # Construct a DataFrame from Arrow record batches.
+    #
+    # The batches are imported through the Arrow C data interface, so no data
+    # is serialized. Each batch must have the same schema. Schema and field
+    # metadata are not supported and are dropped.
+    #
+    # @param record_batches [Object]
+    #   An `Arrow::RecordBatch` or an array of them.
+    #
+    # @return [DataFrame]
+    def from_arrow(record_batches)
+      DataFrame._from_arrow(record_batches)
+    end
+
     # Construct a DataFrame from a sequence of dictionaries. This operation clones data.
     #
+    # Keys missing from a hash become null for that column.
+    #
     # @param hashes [Array]
     #   Array with hashes mapping column name to value.
     # @param infer_schema_length [Integer]
     #   How many hashes/rows to scan to determine the data types
     #   if set to `nil` all rows are scanned. This will be slow.
-    # @param schema [Object]
-    #   Schema that (partially) overwrites the inferred schema.
+    # @param schema [Hash]
+    #   Data types that overwrite the inferred ones, by column name. Columns
+    #   not present in any hash are added as nulls. Use `:unk` to keep
+    #   the inferred data type.
     #
     # @return [DataFrame]
     #
@@ -60,12 +78,12 @@ module Polars
     #   # │ 3   ┆ 6   │
     #   # └─────┴─────┘
     #
-    # @example Overwrite first column name and dtype
-    #   Polars.from_hashes(data, schema: {"c" => :i32})
+    # @example Overwrite the dtype of a column
+    #   Polars.from_hashes(data, schema: {"a" => :i32})
     #   # =>
     #   # shape: (3, 2)
     #   # ┌─────┬─────┐
-    #   # │ c   ┆ b   │
+    #   # │ a   ┆ b   │
     #   # │ --- ┆ --- │
     #   # │ i32 ┆ i64 │
     #   # ╞═════╪═════╡
@@ -77,7 +95,8 @@ module Polars
     #   # └─────┴─────┘
     #
     # @example Let polars infer the dtypes but inform about a 3rd column
-    #   Polars.from_hashes(data, schema: {"a" => :unknown, "b" => :unknown, "c" => :i32})
+    #   Polars.from_hashes(data, schema: {"a" => :unk, "b" => :unk, "c" => :i32})
+    #   # =>
     #   # shape: (3, 3)
     #   # ┌─────┬─────┬──────┐
     #   # │ a   ┆ b   ┆ c    │
@@ -90,9 +109,9 @@ module Polars
     #   # ├╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌╌┤
     #   # │ 3   ┆ 6   ┆ null │
     #   # └─────┴─────┴──────┘
-    # def from_hashes(hashes, infer_schema_length: 50, schema: nil)
-    #   DataFrame._from_hashes(hashes, infer_schema_length: infer_schema_length, schema: schema)
-    # end
+    def from_hashes(hashes, infer_schema_length: 50, schema: nil)
+      DataFrame._from_hashes(hashes, infer_schema_length: infer_schema_length, schema: schema)
+    end
 
     # def from_records
     # end
@@ -46,8 +46,28 @@ module Polars
       df
     end
 
+    # @private
+    def self._from_arrow(record_batches)
+      require "fiddle"
+
+      record_batches = [record_batches] unless record_batches.is_a?(Array)
+      exported = record_batches.map(&:export)
+      array_ptrs, schema_ptrs = exported.transpose
+      _from_rbdf(RbDataFrame.from_arrow(array_ptrs || [], schema_ptrs || []))
+    ensure
+      # the structs are allocated by GLib, and their contents are moved out
+      # by from_arrow, so only the structs themselves are left to free
+      if exported
+        g_free = Fiddle::Function.new(Fiddle::Handle::DEFAULT["g_free"], [Fiddle::TYPE_VOIDP], Fiddle::TYPE_VOID)
+        exported.flatten.each { |ptr| g_free.call(ptr) }
+      end
+    end
+
     # @private
     def self._from_hashes(data, infer_schema_length: 100, schema: nil)
+      if schema
+        schema = schema.to_h { |k, v| [k.to_s, Utils.rb_type_to_dtype(v)] }
+      end
       rbdf = RbDataFrame.read_hashes(data, infer_schema_length, schema)
       _from_rbdf(rbdf)
     end
@@ -243,6 +263,15 @@ module Polars
       _from_rbdf(RbDataFrame.read_json(file))
     end
 
+    # @private
+    def self._read_excel(file, sheet_id: nil, sheet_name: nil, has_header: true, infer_schema_length: 100)
+      if file.is_a?(String) || (defined?(Pathname) && file.is_a?(Pathname))
+        file = Utils.format_path(file)
+      end
+
+      _from_rbdf(RbDataFrame.read_excel(file, sheet_name, sheet_id, has_header, infer_schema_length))
+    end
+
     # @private
     def self._read_ndjson(file)
       if file.is_a?(String) || (defined?(Pathname) && file.is_a?(Pathname))
@@ -601,27 +630,79 @@ module Polars
       end
     end
 
-    # Convert every row to a dictionary.
+    # Convert every row to a hash.
     #
-    # Note that this is slow.
+    # Struct and list columns are converted to nested hashes and arrays.
     #
     # @return [Array]
     #
     # @example
     #   df = Polars::DataFrame.new({"foo" => [1, 2, 3], "bar" => [4, 5, 6]})
     #   df.to_hashes
-    #   [{'foo': 1, 'bar': 4}, {'foo': 2, 'bar': 5}, {'foo': 3, 'bar': 6}]
+    #   # => [{"foo"=>1, "bar"=>4}, {"foo"=>2, "bar"=>5}, {"foo"=>3, "bar"=>6}]
     def to_hashes
-      rbdf = _df
-      names = columns
+      _df.to_hashes
+    end
 
-      height.times.map do |i|
-        names.zip(rbdf.row_tuple(i)).to_h
+    # Convert DataFrame to Arrow record batches.
+    #
+    # Each chunk is exported as a record batch through the Arrow C data
+    # interface, so no data is serialized. Requires the `red-arrow` gem.
+    # The exported schemas have no metadata.
+    #
+    # @return [Array]
+    def to_arrow
+      require "arrow"
+      require "fiddle"
+
+      df = _df.should_rechunk ? rechunk : self
+      n = df.n_chunks
+      # sizes of the ArrowArray and ArrowSchema C structs on 64-bit platforms,
+      # which are also large enough on 32-bit ones since pointers are smaller
+      arrays = n.times.map { Fiddle::Pointer.malloc(80, Fiddle::RUBY_FREE) }
+      schemas = n.times.map { Fiddle::Pointer.malloc(72, Fiddle::RUBY_FREE) }
+      df._df.to_arrow(arrays.map(&:to_i), schemas.map(&:to_i))
+      arrays.zip(schemas).map do |array, schema|
+        Arrow::RecordBatch.import(array.to_i, Arrow::Schema.import(schema.to_i))
       end
     end
 
-    # def to_numo
-    # end
+    # Convert DataFrame to a 2D Numo array.
+    #
+    # All columns must be numeric and are cast to their common supertype.
+    #
+    # @param order ["c", "fortran"]
+    #   Memory layout of the result. "c" returns a contiguous row-major array.
+    #   "fortran" returns a view where the values of each column are contiguous,
+    #   which avoids a copy.
+    #
+    # @return [Numo::NArray]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"foo" => [1, 2, 3], "bar" => [6.5, 7.0, 8.5]})
+    #   df.to_numo
+    #   # =>
+    #   # Numo::DFloat#shape=[3,2]
+    #   # [[1, 6.5],
+    #   #  [2, 7],
+    #   #  [3, 8.5]]
+    def to_numo(order: "c")
+      require "numo/narray"
+
+      if null_count.row(0).any? { |v| v > 0 }
+        raise ArgumentError, "DataFrame contains nulls"
+      end
+
+      out = _df.to_numo.transpose
+      case order
+      when "c"
+        out.dup
+      when "fortran"
+        out
+      else
+        raise ArgumentError, "order must be c or fortran"
+      end
+    end
 
     # no to_pandas
 
@@ -816,18 +897,45 @@ module Polars
       _df.write_avro(file, compression)
     end
 
-    # Write to Arrow IPC binary stream or Feather file.
+    # Write to an Excel file.
+    #
+    # Temporal columns are formatted as Excel dates and nulls are left as blank cells.
     #
     # @param file [String]
     #   File path to which the file should be written.
+    # @param sheet_name [String]
+    #   Name of the worksheet. Defaults to "Sheet1".
+    #
+    # @return [nil]
+    def write_excel(file, sheet_name: nil)
+      if file.is_a?(String) || (defined?(Pathname) && file.is_a?(Pathname))
+        file = Utils.format_path(file)
+      end
+
+      _df.write_excel(file, sheet_name)
+    end
+
+    # Write to Arrow IPC binary stream or Feather file.
+    #
+    # @param file [Object]
+    #   File path or writable IO object to which the file should be written.
+    #   If `nil`, the IPC bytes are returned as a binary String.
     # @param compression ["uncompressed", "lz4", "zstd"]
     #   Compression method. Defaults to "uncompressed".
     #
-    # @return [nil]
-    def write_ipc(file, compression: "uncompressed")
+    # @return [nil, String]
+    def write_ipc(file = nil, compression: "uncompressed")
       if compression.nil?
         compression = "uncompressed"
       end
+
+      if file.nil?
+        buffer = StringIO.new
+        buffer.set_encoding(Encoding::BINARY)
+        _df.write_ipc(buffer, compression)
+        return buffer.string
+      end
+
       if file.is_a?(String) || (defined?(Pathname) && file.is_a?(Pathname))
         file = Utils.format_path(file)
       end
@@ -837,8 +945,9 @@ module Polars
 
     # Write to Apache Parquet file.
     #
-    # @param file [String]
-    #   File path to which the file should be written.
+    # @param file [Object]
+    #   File path or writable IO object to which the file should be written.
+    #   If `nil`, the Parquet bytes are returned as a binary String.
     # @param compression ["lz4", "uncompressed", "snappy", "gzip", "lzo", "brotli", "zstd"]
     #   Choose "zstd" for good compression performance.
     #   Choose "lz4" for fast compression/decompression.
@@ -858,14 +967,21 @@ module Polars
     #   If `nil` (default), the chunks of the DataFrame are
     #   used. Writing in smaller chunks may reduce memory pressure and improve
     #   writing speeds.
+    # @param partition_by [Object]
+    #   Column name(s) to partition by. When given, `file` is a directory and
+    #   one file is written per partition in a `col=value/` directory tree.
+    # @param include_partition_columns [Boolean]
+    #   Also write the partition columns to each file.
     #
-    # @return [nil]
+    # @return [nil, String]
     def write_parquet(
-      file,
+      file = nil,
       compression: "zstd",
       compression_level: nil,
       statistics: false,
-      row_group_size: nil
+      row_group_size: nil,
+      partition_by: nil,
+      include_partition_columns: false
     )
       if compression.nil?
         compression = "uncompressed"
@@ -874,6 +990,32 @@ module Polars
         file = Utils.format_path(file)
       end
 
+      if file.nil?
+        if !partition_by.nil?
+          raise ArgumentError, "partition_by requires a directory path"
+        end
+
+        buffer = StringIO.new
+        buffer.set_encoding(Encoding::BINARY)
+        _df.write_parquet(
+          buffer, compression, compression_level, statistics, row_group_size
+        )
+        return buffer.string
+      end
+
+      if !partition_by.nil?
+        partition_by = [partition_by] if partition_by.is_a?(String)
+        return _df.write_parquet_partitioned(
+          file,
+          partition_by,
+          compression,
+          compression_level,
+          statistics,
+          row_group_size,
+          include_partition_columns
+        )
+      end
+
       _df.write_parquet(
         file, compression, compression_level, statistics, row_group_size
       )
@@ -1638,6 +1780,9 @@ module Polars
     #   Name of the column to add.
     # @param offset [Integer]
     #   Start the row count at this offset.
+    # @param dtype [Symbol]
+    #   Data type of the row count, either `:u32` or `:u64`. Raises an error if
+    #   the row count does not fit.
     #
     # @return [DataFrame]
     #
@@ -1662,8 +1807,8 @@ module Polars
     #   # ├╌╌╌╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌┤
     #   # │ 2      ┆ 5   ┆ 6   │
     #   # └────────┴─────┴─────┘
-    def with_row_count(name: "row_nr", offset: 0)
-      _from_rbdf(_df.with_row_count(name, offset))
+    def with_row_count(name: "row_nr", offset: 0, dtype: :u32)
+      _from_rbdf(_df.with_row_count(name, offset, Utils.rb_type_to_dtype(dtype)))
     end
 
     # Start a groupby operation.
@@ -1714,6 +1859,37 @@ module Polars
       )
     end
 
+    # Split the DataFrame by the given columns, call the block with each group,
+    # and stack the returned frames.
+    #
+    # Groups are passed in order of first appearance. All frames returned by
+    # the block must have the same schema.
+    #
+    # @param by [Object]
+    #   Column(s) to group by.
+    #
+    # @return [DataFrame]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"g" => ["a", "b", "a"], "x" => [3, 1, 2]})
+    #   df.group_by_map("g") { |gdf| gdf.with_column(Polars.col("x").rank.alias("rank")) }
+    #   # =>
+    #   # shape: (3, 3)
+    #   # ┌─────┬─────┬──────┐
+    #   # │ g   ┆ x   ┆ rank │
+    #   # │ --- ┆ --- ┆ ---  │
+    #   # │ str ┆ i64 ┆ f32  │
+    #   # ╞═════╪═════╪══════╡
+    #   # │ a   ┆ 3   ┆ 2.0  │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌╌┤
+    #   # │ a   ┆ 2   ┆ 1.0  │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌╌┤
+    #   # │ b   ┆ 1   ┆ 1.0  │
+    #   # └─────┴─────┴──────┘
+    def group_by_map(by, &block)
+      groupby(by, maintain_order: true).apply(&block)
+    end
+
     # Create rolling groups based on a time column.
     #
     # Also works for index values of type `:i32` or `:i64`.
@@ -2337,6 +2513,9 @@ module Polars
     #   Join strategy.
     # @param suffix [String]
     #   Suffix to append to columns with a duplicate name.
+    # @param coalesce [Boolean]
+    #   Merge the left and right key columns into one. If `false`, the right
+    #   key columns are kept with the suffix appended.
     #
     # @return [DataFrame]
     #
@@ -2426,7 +2605,7 @@ module Polars
     #   # ╞═════╪═════╪═════╡
     #   # │ 3   ┆ 8.0 ┆ c   │
     #   # └─────┴─────┴─────┘
-    def join(other, left_on: nil, right_on: nil, on: nil, how: "inner", suffix: "_right")
+    def join(other, left_on: nil, right_on: nil, on: nil, how: "inner", suffix: "_right", coalesce: true)
       lazy
         .join(
           other.lazy,
@@ -2435,6 +2614,7 @@ module Polars
           on: on,
           how: how,
           suffix: suffix,
+          coalesce: coalesce
         )
         .collect(no_optimization: true)
     end
@@ -2568,6 +2748,9 @@ module Polars
     #   Series to stack.
     # @param in_place [Boolean]
     #   Modify in place.
+    # @param suffix [String]
+    #   Suffix to add to stacked columns whose names already exist. If nil,
+    #   duplicate names raise an error.
     #
     # @return [DataFrame]
     #
@@ -2594,15 +2777,15 @@ module Polars
     #   # ├╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌╌╌┤
     #   # │ 3   ┆ 8   ┆ c   ┆ 30    │
     #   # └─────┴─────┴─────┴───────┘
-    def hstack(columns, in_place: false)
+    def hstack(columns, in_place: false, suffix: nil)
       if !columns.is_a?(Array)
         columns = columns.get_columns
       end
       if in_place
-        _df.hstack_mut(columns.map(&:_s))
+        _df.hstack_mut(columns.map(&:_s), suffix)
         self
       else
-        _from_rbdf(_df.hstack(columns.map(&:_s)))
+        _from_rbdf(_df.hstack(columns.map(&:_s), suffix))
       end
     end
 
@@ -2612,6 +2795,8 @@ module Polars
     #   DataFrame to stack.
     # @param in_place [Boolean]
     #   Modify in place
+    # @param coerce [Boolean]
+    #   Cast numeric columns with different dtypes to their common supertype.
     #
     # @return [DataFrame]
     #
@@ -2646,12 +2831,12 @@ module Polars
     #   # ├╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌┤
     #   # │ 4   ┆ 9   ┆ d   │
     #   # └─────┴─────┴─────┘
-    def vstack(df, in_place: false)
+    def vstack(df, in_place: false, coerce: false)
       if in_place
-        _df.vstack_mut(df._df)
+        _df.vstack_mut(df._df, coerce)
         self
       else
-        _from_rbdf(_df.vstack(df._df))
+        _from_rbdf(_df.vstack(df._df, coerce))
       end
     end
 
@@ -2810,6 +2995,26 @@ module Polars
       _df.get_columns.map { |s| Utils.wrap_s(s) }
     end
 
+    # Iterate over the columns as Series.
+    #
+    # Columns are yielded one at a time instead of building an array of
+    # all columns first.
+    #
+    # @return [Object]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [1, 2], "b" => [3, 4]})
+    #   df.iter_columns.map(&:sum)
+    #   # => [3, 7]
+    def iter_columns
+      return to_enum(:iter_columns) unless block_given?
+
+      _df.iter_columns do |s|
+        yield Utils.wrap_s(s)
+      end
+      self
+    end
+
     # Get a single column as Series by name.
     #
     # @param name [String]
@@ -2974,6 +3179,9 @@ module Polars
     #
     # @param columns [Object]
     #   Column of LargeList type.
+    # @param empty_as_null [Boolean]
+    #   Give a null row for an empty list. If `false`, rows with empty lists are
+    #   dropped.
     #
     # @return [DataFrame]
     #
@@ -3008,8 +3216,8 @@ module Polars
     #   # ├╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
     #   # │ c       ┆ 8       │
     #   # └─────────┴─────────┘
-    def explode(columns)
-      lazy.explode(columns).collect(no_optimization: true)
+    def explode(columns, empty_as_null: true)
+      lazy.explode(columns, empty_as_null: empty_as_null).collect(no_optimization: true)
     end
 
     # Create a spreadsheet-style pivot table as a DataFrame.
@@ -3023,6 +3231,10 @@ module Polars
     #   Columns whose values will be used as the header of the output DataFrame
     # @param aggregate_fn ["first", "sum", "max", "min", "mean", "median", "last", "count"]
     #   A predefined aggregate function str or an expression.
+    #   With "count", missing combinations are 0 instead of null.
+    #   Can also be an array of function names or a hash of names to functions,
+    #   in which case the output columns are suffixed with the function name or
+    #   hash key (`A_sum`), even if there is only one entry.
     # @param maintain_order [Object]
     #   Sort the grouped keys so that the output order is predictable.
     # @param sort_columns [Object]
@@ -3050,6 +3262,27 @@ module Polars
     #   # ├╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌┤
     #   # │ two ┆ 4   ┆ 5   ┆ 6   │
     #   # └─────┴─────┴─────┴─────┘
+    #
+    # @example Cross-tab
+    #   df = Polars::DataFrame.new(
+    #     {
+    #       "foo" => ["one", "one", "two"],
+    #       "bar" => ["B", "A", "B"],
+    #       "baz" => [1, 2, 3]
+    #     }
+    #   )
+    #   df.pivot(values: "baz", index: "foo", columns: "bar", aggregate_fn: "count", sort_columns: true)
+    #   # =>
+    #   # shape: (2, 3)
+    #   # ┌─────┬─────┬─────┐
+    #   # │ foo ┆ A   ┆ B   │
+    #   # │ --- ┆ --- ┆ --- │
+    #   # │ str ┆ u32 ┆ u32 │
+    #   # ╞═════╪═════╪═════╡
+    #   # │ one ┆ 1   ┆ 1   │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌┤
+    #   # │ two ┆ 0   ┆ 1   │
+    #   # └─────┴─────┴─────┘
     def pivot(
       values:,
       index:,
@@ -3068,35 +3301,28 @@ module Polars
         columns = [columns]
       end
 
-      if aggregate_fn.is_a?(String)
-        case aggregate_fn
-        when "first"
-          aggregate_fn = Polars.element.first
-        when "sum"
-          aggregate_fn = Polars.element.sum
-        when "max"
-          aggregate_fn = Polars.element.max
-        when "min"
-          aggregate_fn = Polars.element.min
-        when "mean"
-          aggregate_fn = Polars.element.mean
-        when "median"
-          aggregate_fn = Polars.element.median
-        when "last"
-          aggregate_fn = Polars.element.last
-        when "count"
-          aggregate_fn = Polars.count
+      aggregate_fns =
+        if aggregate_fn.is_a?(Hash)
+          aggregate_fn
+        elsif aggregate_fn.is_a?(Array)
+          aggregate_fn.to_h do |name|
+            if !name.is_a?(String)
+              raise ArgumentError, "aggregate_fn array must contain function names, use a hash to name expressions"
+            end
+            [name, name]
+          end
         else
-          raise ArgumentError, "Argument aggregate fn: '#{aggregate_fn}' was not expected."
+          {"" => aggregate_fn}
         end
-      end
+      aggregate_exprs = aggregate_fns.values.map { |fn| _pivot_aggregate_expr(fn)._rbexpr }
 
       _from_rbdf(
         _df.pivot_expr(
           values,
           index,
           columns,
-          aggregate_fn._rbexpr,
+          aggregate_exprs,
+          aggregate_fns.keys.map(&:to_s),
           maintain_order,
           sort_columns
         )
@@ -3117,6 +3343,9 @@ module Polars
     # @param value_vars [Object]
     #   Values to use as identifier variables.
     #   If `value_vars` is empty all columns that are not in `id_vars` will be used.
+    #   Accepts regular expressions starting with `^` and ending with `$`, and
+    #   dtypes like `:f64`. When these are used and `id_vars` is not given, the
+    #   remaining columns are used as identifier variables.
     # @param variable_name [String]
     #   Name to give to the `value` column. Defaults to "variable"
     # @param value_name [String]
@@ -3152,8 +3381,33 @@ module Polars
     #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
     #   # │ z   ┆ c        ┆ 6     │
     #   # └─────┴──────────┴───────┘
+    #
+    # @example Select value columns with a regular expression
+    #   df = Polars::DataFrame.new(
+    #     {
+    #       "sensor" => ["x", "y"],
+    #       "measure_a" => [1, 3],
+    #       "measure_b" => [2, 4]
+    #     }
+    #   )
+    #   df.melt(value_vars: "^measure_.*$")
+    #   # =>
+    #   # shape: (4, 3)
+    #   # ┌────────┬───────────┬───────┐
+    #   # │ sensor ┆ variable  ┆ value │
+    #   # │ ---    ┆ ---       ┆ ---   │
+    #   # │ str    ┆ str       ┆ i64   │
+    #   # ╞════════╪═══════════╪═══════╡
+    #   # │ x      ┆ measure_a ┆ 1     │
+    #   # ├╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    #   # │ y      ┆ measure_a ┆ 3     │
+    #   # ├╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    #   # │ x      ┆ measure_b ┆ 2     │
+    #   # ├╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    #   # │ y      ┆ measure_b ┆ 4     │
+    #   # └────────┴───────────┴───────┘
     def melt(id_vars: nil, value_vars: nil, variable_name: nil, value_name: nil)
-      if value_vars.is_a?(String)
+      if value_vars.is_a?(String) || value_vars.is_a?(Symbol)
         value_vars = [value_vars]
       end
       if id_vars.is_a?(String)
@@ -3165,11 +3419,65 @@ module Polars
       if id_vars.nil?
         id_vars = []
       end
+      value_dtypes, value_vars = value_vars.partition { |v| v.is_a?(Symbol) }
+      value_dtypes = value_dtypes.map { |v| Utils.rb_type_to_dtype(v) }
       _from_rbdf(
-        _df.melt(id_vars, value_vars, value_name, variable_name)
+        _df.melt(id_vars, value_vars, value_dtypes, value_name, variable_name)
       )
     end
 
+    # Unpivot a DataFrame from wide to long format.
+    #
+    # This is the same as {#melt}, where `index` corresponds to `id_vars` and
+    # `on` corresponds to `value_vars`.
+    #
+    # @param on [Object]
+    #   Columns or selector expressions to unpivot. Defaults to all columns
+    #   that are not in `index`.
+    # @param index [Object]
+    #   Columns to use as identifier variables.
+    # @param variable_name [String]
+    #   Name to give to the `variable` column. Defaults to "variable"
+    # @param value_name [String]
+    #   Name to give to the `value` column. Defaults to "value"
+    #
+    # @return [DataFrame]
+    #
+    # @example
+    #   df = Polars::DataFrame.new(
+    #     {
+    #       "a" => ["x", "y", "z"],
+    #       "b" => [1, 3, 5],
+    #       "c" => [2, 4, 6]
+    #     }
+    #   )
+    #   df.unpivot(Polars.col(["b", "c"]), index: "a")
+    #   # =>
+    #   # shape: (6, 3)
+    #   # ┌─────┬──────────┬───────┐
+    #   # │ a   ┆ variable ┆ value │
+    #   # │ --- ┆ ---      ┆ ---   │
+    #   # │ str ┆ str      ┆ i64   │
+    #   # ╞═════╪══════════╪═══════╡
+    #   # │ x   ┆ b        ┆ 1     │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    #   # │ y   ┆ b        ┆ 3     │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    #   # │ z   ┆ b        ┆ 5     │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    #   # │ x   ┆ c        ┆ 2     │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    #   # │ y   ┆ c        ┆ 4     │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    #   # │ z   ┆ c        ┆ 6     │
+    #   # └─────┴──────────┴───────┘
+    def unpivot(on = nil, index: nil, variable_name: nil, value_name: nil)
+      on = on.nil? ? [] : Utils.selection_to_rbexpr_list(on)
+      index = [index] if index.is_a?(String)
+      index = [] if index.nil?
+      _from_rbdf(_df.unpivot(on, index, variable_name, value_name))
+    end
+
     # Unstack a long table to a wide form without doing an aggregation.
     #
     # This can be much faster than a pivot, because it can skip the grouping phase.
@@ -3415,6 +3723,10 @@ module Polars
     #
     # @param periods [Integer]
     #   Number of places to shift (may be negative).
+    # @param fill_value [Object]
+    #   Fill the resulting null values with this value or expression. Can also
+    #   be a Hash of column names to fill values, in which case other columns
+    #   are not filled.
     #
     # @return [DataFrame]
     #
@@ -3456,8 +3768,14 @@ module Polars
     #   # ├╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌┤
     #   # │ null ┆ null ┆ null │
     #   # └──────┴──────┴──────┘
-    def shift(periods)
-      _from_rbdf(_df.shift(periods))
+    def shift(periods = 1, fill_value: nil)
+      if fill_value.nil?
+        return _from_rbdf(_df.shift(periods))
+      end
+
+      lazy
+        .shift(periods, fill_value: fill_value)
+        .collect(no_optimization: true, string_cache: false)
     end
 
     # Shift the values by a given period and fill the resulting null values.
@@ -4236,6 +4554,9 @@ module Polars
     # @param seed [Integer]
     #   Seed for the random number generator. If set to nil (default), a random
     #   seed is used.
+    # @param by [String]
+    #   Sample `n` or `frac` rows from each group of this column. Without
+    #   replacement, groups smaller than `n` are taken in full.
     #
     # @return [DataFrame]
     #
@@ -4264,12 +4585,18 @@ module Polars
       frac: nil,
       with_replacement: false,
       shuffle: false,
-      seed: nil
+      seed: nil,
+      by: nil
     )
       if !n.nil? && !frac.nil?
         raise ArgumentError, "cannot specify both `n` and `frac`"
       end
 
+      if !by.nil?
+        n = 1 if n.nil? && frac.nil?
+        return _from_rbdf(_df.sample_stratified(by, n, frac, with_replacement, shuffle, seed))
+      end
+
       if n.nil? && !frac.nil?
         _from_rbdf(
           _df.sample_frac(frac, with_replacement, shuffle, seed)
@@ -4282,6 +4609,21 @@ module Polars
       _from_rbdf(_df.sample_n(n, with_replacement, shuffle, seed))
     end
 
+    # Shuffle the rows of this DataFrame.
+    #
+    # @param seed [Integer]
+    #   Seed for the random number generator. If set to nil (default), a random
+    #   seed is used. The same seed always gives the same order.
+    #
+    # @return [DataFrame]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"foo" => [1, 2, 3], "bar" => ["a", "b", "c"]})
+    #   df.shuffle(seed: 1)
+    def shuffle(seed: nil)
+      _from_rbdf(_df.shuffle(seed))
+    end
+
     # Apply a horizontal reduction on a DataFrame.
     #
     # This can be used to effectively determine aggregations on a row level, and can
@@ -4455,6 +4797,56 @@ module Polars
       end
     end
 
+    # Shrink numeric columns to the minimal required datatype.
+    #
+    # Columns that cannot be shrunk are left unchanged.
+    #
+    # @param categorical_threshold [Integer]
+    #   Convert Utf8 columns with fewer distinct values than this to
+    #   Categorical.
+    # @param report [Boolean]
+    #   Also return the estimated size in bytes before and after.
+    #
+    # @return [DataFrame, Array]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => ["x", "y", "x"]})
+    #   df.shrink_dtype(categorical_threshold: 10).dtypes
+    #   # => [:i8, :cat]
+    def shrink_dtype(categorical_threshold: nil, report: false)
+      df = _from_rbdf(_df.shrink_dtype(categorical_threshold))
+      if report
+        [df, {"before" => estimated_size, "after" => df.estimated_size}]
+      else
+        df
+      end
+    end
+
+    # Flag a column as sorted.
+    #
+    # This can speed up future operations.
+    #
+    # @param column [String]
+    #   Column that is sorted.
+    # @param reverse [Boolean]
+    #   Whether the column is sorted in descending order.
+    # @param verify [Boolean]
+    #   Check the data and raise if the column is not sorted.
+    #
+    # @return [DataFrame]
+    #
+    # @note
+    #   This can lead to incorrect results if the data is NOT sorted!!
+    #   Use with care!
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => [3, 1, 2]})
+    #   df.set_sorted("a")["a"].flags
+    #   # => {"SORTED_ASC"=>true, "SORTED_DESC"=>false}
+    def set_sorted(column, reverse: false, verify: false)
+      _from_rbdf(_df.set_sorted(column, reverse, verify))
+    end
+
     # Take every nth row in the DataFrame and return as a new DataFrame.
     #
     # @return [DataFrame]
@@ -4635,6 +5027,31 @@ module Polars
 
     private
 
+    def _pivot_aggregate_expr(aggregate_fn)
+      return aggregate_fn unless aggregate_fn.is_a?(String)
+
+      case aggregate_fn
+      when "first"
+        Polars.element.first
+      when "sum"
+        Polars.element.sum
+      when "max"
+        Polars.element.max
+      when "min"
+        Polars.element.min
+      when "mean"
+        Polars.element.mean
+      when "median"
+        Polars.element.median
+      when "last"
+        Polars.element.last
+      when "count"
+        Polars.count
+      else
+        raise ArgumentError, "Argument aggregate fn: '#{aggregate_fn}' was not expected."
+      end
+    end
+
     def initialize_copy(other)
       super
       self._df = _df._clone
@@ -4726,6 +5143,10 @@ module Polars
         data.each do |s|
           data_series << s._s
         end
+      elsif data[0].is_a?(Hash)
+        rbdf = RbDataFrame.read_hashes(data, 100, nil)
+        rbdf.set_column_names(columns) if columns
+        return rbdf
       elsif data[0].is_a?(Array)
         if orient.nil? && !columns.nil?
           orient = columns.length == data.length ? "col" : "row"
@@ -312,6 +312,36 @@ module Polars
       Utils.wrap_expr(_rbexpr.year)
     end
 
+    # Determine whether the year of the underlying date is a leap year.
+    #
+    # Applies to Date and Datetime columns.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new(
+    #     {"date" => [Date.new(1900, 1, 1), Date.new(2000, 1, 1), Date.new(2020, 1, 1), Date.new(2021, 1, 1)]}
+    #   )
+    #   df.select(Polars.col("date").dt.is_leap_year)
+    #   # =>
+    #   # shape: (4, 1)
+    #   # ┌───────┐
+    #   # │ date  │
+    #   # │ ---   │
+    #   # │ bool  │
+    #   # ╞═══════╡
+    #   # │ false │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ true  │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ true  │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ false │
+    #   # └───────┘
+    def is_leap_year
+      Utils.wrap_expr(_rbexpr.dt_is_leap_year)
+    end
+
     # Extract ISO year from underlying Date representation.
     #
     # Applies to Date and Datetime columns.
@@ -1414,5 +1444,153 @@ module Polars
     def offset_by(by)
       Utils.wrap_expr(_rbexpr.dt_offset_by(by))
     end
+
+    # Roll dates to the first day of their month.
+    #
+    # The time of day is kept, along with the time unit and time zone. For time
+    # zone aware datetimes, the month and time of day are those in the time zone.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new(
+    #     {
+    #       "dates" => Polars.date_range(
+    #         DateTime.new(2024, 1, 20), DateTime.new(2024, 3, 2), "2w"
+    #       )
+    #     }
+    #   )
+    #   df.select(
+    #     [
+    #       Polars.col("dates"),
+    #       Polars.col("dates").dt.month_start.alias("month_start"),
+    #       Polars.col("dates").dt.month_end.alias("month_end")
+    #     ]
+    #   )
+    #   # =>
+    #   # shape: (4, 3)
+    #   # ┌─────────────────────┬─────────────────────┬─────────────────────┐
+    #   # │ dates               ┆ month_start         ┆ month_end           │
+    #   # │ ---                 ┆ ---                 ┆ ---                 │
+    #   # │ datetime[μs]        ┆ datetime[μs]        ┆ datetime[μs]        │
+    #   # ╞═════════════════════╪═════════════════════╪═════════════════════╡
+    #   # │ 2024-01-20 00:00:00 ┆ 2024-01-01 00:00:00 ┆ 2024-01-31 00:00:00 │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ 2024-02-03 00:00:00 ┆ 2024-02-01 00:00:00 ┆ 2024-02-29 00:00:00 │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ 2024-02-17 00:00:00 ┆ 2024-02-01 00:00:00 ┆ 2024-02-29 00:00:00 │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ 2024-03-02 00:00:00 ┆ 2024-03-01 00:00:00 ┆ 2024-03-31 00:00:00 │
+    #   # └─────────────────────┴─────────────────────┴─────────────────────┘
+    def month_start
+      Utils.wrap_expr(_rbexpr.dt_month_start)
+    end
+
+    # Create a Datetime from a Date or Datetime and a time of day.
+    #
+    # For Datetime input, the existing time of day is replaced. Time zone aware
+    # Datetimes keep their time zone, and the time of day is set in local time.
+    #
+    # @param time [Object]
+    #   A `Time` whose time of day is used, or an expression of dtype `:time`.
+    #   Integer expressions are read as nanoseconds since midnight.
+    # @param time_unit ["ns", "us", "ms"]
+    #   Time unit of the resulting Datetime.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"date" => [Date.new(2024, 1, 1), Date.new(2024, 2, 29)]})
+    #   df.select(Polars.col("date").dt.combine(Time.utc(2000, 1, 1, 9, 30)))
+    #   # =>
+    #   # shape: (2, 1)
+    #   # ┌─────────────────────┐
+    #   # │ date                │
+    #   # │ ---                 │
+    #   # │ datetime[μs]        │
+    #   # ╞═════════════════════╡
+    #   # │ 2024-01-01 09:30:00 │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ 2024-02-29 09:30:00 │
+    #   # └─────────────────────┘
+    def combine(time, time_unit = "us")
+      if time.is_a?(Time)
+        nanoseconds = ((time.hour * 60 + time.min) * 60 + time.sec) * 1_000_000_000 + time.nsec
+        time = Polars.lit(nanoseconds).cast(:i64)
+      else
+        time = Utils.expr_to_lit_or_expr(time)
+      end
+      Utils.wrap_expr(_rbexpr.dt_combine(time._rbexpr, time_unit))
+    end
+
+    # Base offset from UTC, excluding any daylight saving time adjustment.
+    #
+    # Requires a time zone aware Datetime and returns a Duration.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new(
+    #     {
+    #       "ts" => Polars.date_range(DateTime.new(2024, 3, 9, 12), DateTime.new(2024, 3, 11, 12), "1d")
+    #     }
+    #   ).with_column(Polars.col("ts").dt.with_time_zone("US/Eastern"))
+    #   df.select(Polars.col("ts").dt.base_utc_offset.dt.seconds)
+    #   # =>
+    #   # shape: (3, 1)
+    #   # ┌────────┐
+    #   # │ ts     │
+    #   # │ ---    │
+    #   # │ i64    │
+    #   # ╞════════╡
+    #   # │ -18000 │
+    #   # ├╌╌╌╌╌╌╌╌┤
+    #   # │ -18000 │
+    #   # ├╌╌╌╌╌╌╌╌┤
+    #   # │ -18000 │
+    #   # └────────┘
+    def base_utc_offset
+      Utils.wrap_expr(_rbexpr.dt_base_utc_offset)
+    end
+
+    # Additional offset currently in effect for daylight saving time.
+    #
+    # Requires a time zone aware Datetime and returns a Duration.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new(
+    #     {
+    #       "ts" => Polars.date_range(DateTime.new(2024, 3, 9, 12), DateTime.new(2024, 3, 11, 12), "1d")
+    #     }
+    #   ).with_column(Polars.col("ts").dt.with_time_zone("US/Eastern"))
+    #   df.select(Polars.col("ts").dt.dst_offset.dt.seconds)
+    #   # =>
+    #   # shape: (3, 1)
+    #   # ┌──────┐
+    #   # │ ts   │
+    #   # │ ---  │
+    #   # │ i64  │
+    #   # ╞══════╡
+    #   # │ 0    │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ 3600 │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ 3600 │
+    #   # └──────┘
+    def dst_offset
+      Utils.wrap_expr(_rbexpr.dt_dst_offset)
+    end
+
+    # Roll dates to the last day of their month.
+    #
+    # The time of day is kept, along with the time unit and time zone. For time
+    # zone aware datetimes, the month and time of day are those in the time zone.
+    #
+    # @return [Expr]
+    def month_end
+      Utils.wrap_expr(_rbexpr.dt_month_end)
+    end
   end
 end
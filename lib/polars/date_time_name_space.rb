@@ -175,6 +175,26 @@ module Polars
     #   #         2002
     #   # ]
     def year
+      Utils.wrap_s(_s.year)
+    end
+
+    # Determine whether the year of the underlying date is a leap year.
+    #
+    # Applies to Date and Datetime columns.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new([Date.new(2000, 1, 1), Date.new(2001, 1, 1)])
+    #   s.dt.is_leap_year
+    #   # =>
+    #   # shape: (2,)
+    #   # Series: '' [bool]
+    #   # [
+    #   #         true
+    #   #         false
+    #   # ]
+    def is_leap_year
       super
     end
 
@@ -197,7 +217,7 @@ module Polars
     #   #         2021
     #   # ]
     def iso_year
-      super
+      Utils.wrap_s(_s.iso_year)
     end
 
     # Extract quarter from underlying Date representation.
@@ -234,7 +254,7 @@ module Polars
     #   #         2
     #   # ]
     def quarter
-      super
+      Utils.wrap_s(_s.quarter)
     end
 
     # Extract the month from the underlying date representation.
@@ -272,7 +292,7 @@ module Polars
     #   #         4
     #   # ]
     def month
-      super
+      Utils.wrap_s(_s.month)
     end
 
     # Extract the week from the underlying date representation.
@@ -310,7 +330,7 @@ module Polars
     #   #         13
     #   # ]
     def week
-      super
+      Utils.wrap_s(_s.week)
     end
 
     # Extract the week day from the underlying date representation.
@@ -353,7 +373,7 @@ module Polars
     #   #         7
     #   # ]
     def weekday
-      super
+      Utils.wrap_s(_s.weekday)
     end
 
     # Extract the day from the underlying date representation.
@@ -393,7 +413,7 @@ module Polars
     #   #         9
     #   # ]
     def day
-      super
+      Utils.wrap_s(_s.day)
     end
 
     # Extract ordinal day from underlying date representation.
@@ -429,7 +449,7 @@ module Polars
     #   #         60
     #   # ]
     def ordinal_day
-      super
+      Utils.wrap_s(_s.ordinal_day)
     end
 
     # Extract the hour from the underlying DateTime representation.
@@ -466,7 +486,7 @@ module Polars
     #   #         3
     #   # ]
     def hour
-      super
+      Utils.wrap_s(_s.hour)
     end
 
     # Extract the minutes from the underlying DateTime representation.
@@ -501,7 +521,7 @@ module Polars
     #   #         4
     #   # ]
     def minute
-      super
+      Utils.wrap_s(_s.minute)
     end
 
     # Extract seconds from underlying DateTime representation.
@@ -567,7 +587,11 @@ module Polars
     #   #         4.0
     #   # ]
     def second(fractional: false)
-      super
+      if fractional
+        super
+      else
+        Utils.wrap_s(_s.second)
+      end
     end
 
     # Extract the milliseconds from the underlying DateTime representation.
@@ -612,7 +636,7 @@ module Polars
     #   #         0
     #   # ]
     def millisecond
-      super
+      Utils.wrap_s(_s.millisecond)
     end
 
     # Extract the microseconds from the underlying DateTime representation.
@@ -657,7 +681,7 @@ module Polars
     #   #         0
     #   # ]
     def microsecond
-      super
+      Utils.wrap_s(_s.microsecond)
     end
 
     # Extract the nanoseconds from the underlying DateTime representation.
@@ -702,7 +726,7 @@ module Polars
     #   #         0
     #   # ]
     def nanosecond
-      super
+      Utils.wrap_s(_s.nanosecond)
     end
 
     # Return a timestamp in the given time unit.
@@ -1292,6 +1316,119 @@ module Polars
       super
     end
 
+    # Roll dates to the first day of their month.
+    #
+    # The time of day is kept, along with the time unit and time zone. For time
+    # zone aware datetimes, the month and time of day are those in the time zone.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars.date_range(DateTime.new(2024, 1, 20), DateTime.new(2024, 3, 2), "2w")
+    #   s.dt.month_start
+    #   # =>
+    #   # shape: (4,)
+    #   # Series: '' [datetime[μs]]
+    #   # [
+    #   #         2024-01-01 00:00:00
+    #   #         2024-02-01 00:00:00
+    #   #         2024-02-01 00:00:00
+    #   #         2024-03-01 00:00:00
+    #   # ]
+    def month_start
+      super
+    end
+
+    # Create a naive Datetime from a Date or Datetime and a time of day.
+    #
+    # For Datetime input, the existing time of day is replaced.
+    #
+    # @param time [Object]
+    #   A `Time` whose time of day is used, or an expression of dtype `:time`.
+    # @param time_unit ["ns", "us", "ms"]
+    #   Time unit of the resulting Datetime.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new("date", [Date.new(2024, 1, 1), Date.new(2024, 2, 29)])
+    #   s.dt.combine(Time.utc(2000, 1, 1, 9, 30))
+    #   # =>
+    #   # shape: (2,)
+    #   # Series: 'date' [datetime[μs]]
+    #   # [
+    #   #         2024-01-01 09:30:00
+    #   #         2024-02-29 09:30:00
+    #   # ]
+    def combine(time, time_unit = "us")
+      super
+    end
+
+    # Base offset from UTC, excluding any daylight saving time adjustment.
+    #
+    # Requires a time zone aware Datetime and returns a Duration.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars.date_range(DateTime.new(2024, 3, 9, 12), DateTime.new(2024, 3, 11, 12), "1d")
+    #   s.dt.with_time_zone("US/Eastern").dt.base_utc_offset.dt.seconds
+    #   # =>
+    #   # shape: (3,)
+    #   # Series: '' [i64]
+    #   # [
+    #   #         -18000
+    #   #         -18000
+    #   #         -18000
+    #   # ]
+    def base_utc_offset
+      super
+    end
+
+    # Additional offset currently in effect for daylight saving time.
+    #
+    # Requires a time zone aware Datetime and returns a Duration.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars.date_range(DateTime.new(2024, 3, 9, 12), DateTime.new(2024, 3, 11, 12), "1d")
+    #   s.dt.with_time_zone("US/Eastern").dt.dst_offset.dt.seconds
+    #   # =>
+    #   # shape: (3,)
+    #   # Series: '' [i64]
+    #   # [
+    #   #         0
+    #   #         3600
+    #   #         3600
+    #   # ]
+    def dst_offset
+      super
+    end
+
+    # Roll dates to the last day of their month.
+    #
+    # The time of day is kept, along with the time unit and time zone. For time
+    # zone aware datetimes, the month and time of day are those in the time zone.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars.date_range(DateTime.new(2024, 1, 20), DateTime.new(2024, 3, 2), "2w")
+    #   s.dt.month_end
+    #   # =>
+    #   # shape: (4,)
+    #   # Series: '' [datetime[μs]]
+    #   # [
+    #   #         2024-01-31 00:00:00
+    #   #         2024-02-29 00:00:00
+    #   #         2024-02-29 00:00:00
+    #   #         2024-03-31 00:00:00
+    #   # ]
+    def month_end
+      super
+    end
+
     # Divide the date/ datetime range into buckets.
     #
     # Each date/datetime is mapped to the start of its bucket.
@@ -180,6 +180,10 @@ module Polars
 
     # Check if any boolean value in a Boolean column is `true`.
     #
+    # @param ignore_nulls [Boolean]
+    #   Ignore null values. If false, Kleene logic is used and the result
+    #   is null when there are no `true` values and at least one null.
+    #
     # @return [Boolean]
     #
     # @example
@@ -194,8 +198,8 @@ module Polars
     #   # ╞══════╪═══════╡
     #   # │ true ┆ false │
     #   # └──────┴───────┘
-    def any
-      wrap_expr(_rbexpr.any)
+    def any(ignore_nulls: true)
+      wrap_expr(_rbexpr.any(ignore_nulls))
     end
 
     # Check if all boolean values in a Boolean column are `true`.
@@ -203,6 +207,10 @@ module Polars
     # This method is an expression - not to be confused with
     # `Polars.all` which is a function to select all columns.
     #
+    # @param ignore_nulls [Boolean]
+    #   Ignore null values. If false, Kleene logic is used and the result
+    #   is null when there are no `false` values and at least one null.
+    #
     # @return [Boolean]
     #
     # @example
@@ -219,8 +227,8 @@ module Polars
     #   # ╞══════╪═══════╪═══════╡
     #   # │ true ┆ false ┆ false │
     #   # └──────┴───────┴───────┘
-    def all
-      wrap_expr(_rbexpr.all)
+    def all(ignore_nulls: true)
+      wrap_expr(_rbexpr.all(ignore_nulls))
     end
 
     # Compute the square root of the elements.
@@ -505,6 +513,28 @@ module Polars
       wrap_expr(_rbexpr.is_not)
     end
 
+    # Negate a boolean expression.
+    #
+    # Unlike `is_not`, this raises an error for non-boolean columns.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [true, false, nil]})
+    #   df.filter(Polars.col("a").not_)
+    #   # =>
+    #   # shape: (1, 1)
+    #   # ┌───────┐
+    #   # │ a     │
+    #   # │ ---   │
+    #   # │ bool  │
+    #   # ╞═══════╡
+    #   # │ false │
+    #   # └───────┘
+    def not_
+      wrap_expr(_rbexpr.not_)
+    end
+
     # Returns a boolean Series indicating which values are null.
     #
     # @return [Expr]
@@ -779,9 +809,12 @@ module Polars
 
     # Get a slice of this expression.
     #
-    # @param offset [Integer]
+    # The offset and length can be expressions. In a groupby context they are
+    # evaluated per group, so the slice can depend on the group size.
+    #
+    # @param offset [Object]
     #   Start index. Negative indexing is supported.
-    # @param length [Integer]
+    # @param length [Object]
     #   Length of the slice. If set to `nil`, all rows starting at the offset
     #   will be selected.
     #
@@ -806,6 +839,23 @@ module Polars
     #   # ├╌╌╌╌╌┼╌╌╌╌╌┤
     #   # │ 10  ┆ 4   │
     #   # └─────┴─────┘
+    #
+    # @example Drop the first half of each group
+    #   df = Polars::DataFrame.new({"g" => ["a", "a", "a", "a", "b", "b"], "v" => [1, 2, 3, 4, 5, 6]})
+    #   df.groupby("g", maintain_order: true).agg(
+    #     Polars.col("v").slice((Polars.count * 0.5).cast(:i64))
+    #   )
+    #   # =>
+    #   # shape: (2, 2)
+    #   # ┌─────┬───────────┐
+    #   # │ g   ┆ v         │
+    #   # │ --- ┆ ---       │
+    #   # │ str ┆ list[i64] │
+    #   # ╞═════╪═══════════╡
+    #   # │ a   ┆ [3, 4]    │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ b   ┆ [6]       │
+    #   # └─────┴───────────┘
     def slice(offset, length = nil)
       if !offset.is_a?(Expr)
         offset = Polars.lit(offset)
@@ -1119,6 +1169,42 @@ module Polars
       wrap_expr(_rbexpr.cumcount(reverse))
     end
 
+    # Get the running count of non-null values.
+    #
+    # Unlike {#cumcount}, nulls are not counted.
+    #
+    # @param reverse [Boolean]
+    #   Reverse the operation.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [1, nil, 3, 4]})
+    #   df.select(
+    #     [
+    #       Polars.col("a").cum_count,
+    #       Polars.col("a").cum_count(reverse: true).alias("a_reverse")
+    #     ]
+    #   )
+    #   # =>
+    #   # shape: (4, 2)
+    #   # ┌─────┬───────────┐
+    #   # │ a   ┆ a_reverse │
+    #   # │ --- ┆ ---       │
+    #   # │ u32 ┆ u32       │
+    #   # ╞═════╪═══════════╡
+    #   # │ 1   ┆ 3         │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ 1   ┆ 2         │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ 2   ┆ 2         │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ 3   ┆ 1         │
+    #   # └─────┴───────────┘
+    def cum_count(reverse: false)
+      wrap_expr(_rbexpr.cum_count(reverse))
+    end
+
     # Rounds down to the nearest integer value.
     #
     # Only works on floating point Series.
@@ -1269,6 +1355,9 @@ module Polars
     # @param strict [Boolean]
     #   Throw an error if a cast could not be done.
     #   For instance, due to an overflow.
+    # @param wrap_numerical [Boolean]
+    #   Wrap integers that overflow the target type instead of raising
+    #   an error or setting them to null.
     #
     # @return [Expr]
     #
@@ -1298,9 +1387,9 @@ module Polars
     #   # ├╌╌╌╌╌┼╌╌╌╌╌┤
     #   # │ 3.0 ┆ 6   │
     #   # └─────┴─────┘
-    def cast(dtype, strict: true)
+    def cast(dtype, strict: true, wrap_numerical: false)
       dtype = Utils.rb_type_to_dtype(dtype)
-      wrap_expr(_rbexpr.cast(dtype, strict))
+      wrap_expr(_rbexpr.cast(dtype, strict, wrap_numerical))
     end
 
     # Sort this column. In projection/ selection context the whole column is sorted.
@@ -1518,6 +1607,10 @@ module Polars
     #
     # @param element [Object]
     #   Expression or scalar value.
+    # @param side ["any", "left", "right"]
+    #   If "any", the index of the first suitable location found is given.
+    #   If "left", the index of the leftmost suitable location found is given.
+    #   If "right", return the rightmost suitable location found is given.
     #
     # @return [Expr]
     #
@@ -1543,9 +1636,9 @@ module Polars
     #   # ╞══════╪═══════╪═════╡
     #   # │ 0    ┆ 2     ┆ 4   │
     #   # └──────┴───────┴─────┘
-    def search_sorted(element)
+    def search_sorted(element, side: "any")
       element = Utils.expr_to_lit_or_expr(element, str_to_lit: false)
-      wrap_expr(_rbexpr.search_sorted(element._rbexpr))
+      wrap_expr(_rbexpr.search_sorted(element._rbexpr, side))
     end
 
     # Sort this column by the ordering of another column, or multiple other columns.
@@ -1649,10 +1742,49 @@ module Polars
       wrap_expr(_rbexpr.take(indices_lit._rbexpr))
     end
 
+    # Take values by index, with control over out of bounds indices.
+    #
+    # In a groupby context, the indices are relative to each group.
+    #
+    # @param indices [Object]
+    #   An array of indices or an expression that leads to integer indices.
+    # @param null_on_oob [Boolean]
+    #   Return null for indices that are out of bounds or negative instead of
+    #   raising an error.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [1, 2, 3]})
+    #   df.select(Polars.col("a").gather([0, 5], null_on_oob: true))
+    #   # =>
+    #   # shape: (2, 1)
+    #   # ┌──────┐
+    #   # │ a    │
+    #   # │ ---  │
+    #   # │ i64  │
+    #   # ╞══════╡
+    #   # │ 1    │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ null │
+    #   # └──────┘
+    def gather(indices, null_on_oob: false)
+      if indices.is_a?(Array)
+        # negative indices are only allowed when they become null
+        indices_lit = Polars.lit(Series.new("", indices, dtype: null_on_oob ? :i64 : :u32))
+      else
+        indices_lit = Utils.expr_to_lit_or_expr(indices, str_to_lit: false)
+      end
+      wrap_expr(_rbexpr.gather(indices_lit._rbexpr, null_on_oob))
+    end
+
     # Shift the values by a given period.
     #
     # @param periods [Integer]
     #   Number of places to shift (may be negative).
+    # @param fill_value [Object]
+    #   Fill the resulting null values with this value or expression. With a
+    #   negative period, the nulls at the end are filled.
     #
     # @return [Expr]
     #
@@ -1674,8 +1806,27 @@ module Polars
     #   # ├╌╌╌╌╌╌┤
     #   # │ 3    │
     #   # └──────┘
-    def shift(periods = 1)
-      wrap_expr(_rbexpr.shift(periods))
+    #
+    # @example
+    #   df.select(Polars.col("foo").shift(-1, fill_value: 100))
+    #   # =>
+    #   # shape: (4, 1)
+    #   # ┌─────┐
+    #   # │ foo │
+    #   # │ --- │
+    #   # │ i64 │
+    #   # ╞═════╡
+    #   # │ 2   │
+    #   # ├╌╌╌╌╌┤
+    #   # │ 3   │
+    #   # ├╌╌╌╌╌┤
+    #   # │ 4   │
+    #   # ├╌╌╌╌╌┤
+    #   # │ 100 │
+    #   # └─────┘
+    def shift(periods = 1, fill_value: nil)
+      fill_value = Utils.expr_to_lit_or_expr(fill_value, str_to_lit: true)._rbexpr unless fill_value.nil?
+      wrap_expr(_rbexpr.shift(periods, fill_value))
     end
 
     # Shift the values by a given period and fill the resulting null values.
@@ -1826,8 +1977,9 @@ module Polars
 
     # Fill missing values with the latest seen values.
     #
-    # @param limit [Integer]
-    #   The number of consecutive null values to forward fill.
+    # @param limit [Integer, nil]
+    #   The number of consecutive null values to forward fill. Nulls beyond
+    #   the limit are left as null. If `nil`, there is no limit.
     #
     # @return [Expr]
     #
@@ -1858,8 +2010,9 @@ module Polars
 
     # Fill missing values with the next to be seen values.
     #
-    # @param limit [Integer]
-    #   The number of consecutive null values to backward fill.
+    # @param limit [Integer, nil]
+    #   The number of consecutive null values to backward fill. Nulls beyond
+    #   the limit are left as null. If `nil`, there is no limit.
     #
     # @return [Expr]
     #
@@ -2769,6 +2922,11 @@ module Polars
 
     # Take every nth value in the Series and return as a new Series.
     #
+    # @param n [Integer]
+    #   Gather every `n`-th row.
+    # @param offset [Integer]
+    #   Starting index.
+    #
     # @return [Expr]
     #
     # @example
@@ -2787,9 +2945,26 @@ module Polars
     #   # ├╌╌╌╌╌┤
     #   # │ 7   │
     #   # └─────┘
-    def take_every(n)
-      wrap_expr(_rbexpr.take_every(n))
+    #
+    # @example Start at an offset
+    #   df.select(Polars.col("foo").gather_every(3, 1))
+    #   # =>
+    #   # shape: (3, 1)
+    #   # ┌─────┐
+    #   # │ foo │
+    #   # │ --- │
+    #   # │ i64 │
+    #   # ╞═════╡
+    #   # │ 2   │
+    #   # ├╌╌╌╌╌┤
+    #   # │ 5   │
+    #   # ├╌╌╌╌╌┤
+    #   # │ 8   │
+    #   # └─────┘
+    def take_every(n, offset = 0)
+      wrap_expr(_rbexpr.take_every(n, offset))
     end
+    alias_method :gather_every, :take_every
 
     # Get the first `n` rows.
     #
@@ -2928,8 +3103,9 @@ module Polars
     #
     # @param by [Object]
     #   Numeric column that determines how often the values will be repeated.
-    #   The column will be coerced to UInt32. Give this dtype to make the coercion a
-    #   no-op.
+    #   A null count gives a null row.
+    # @param negative_as_empty [Boolean]
+    #   Give an empty list for negative counts instead of raising an error.
     #
     # @return [Expr]
     #
@@ -2954,9 +3130,99 @@ module Polars
     #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
     #   # │ ["z", "z", "z"] │
     #   # └─────────────────┘
-    def repeat_by(by)
+    def repeat_by(by, negative_as_empty: false)
       by = Utils.expr_to_lit_or_expr(by, str_to_lit: false)
-      wrap_expr(_rbexpr.repeat_by(by._rbexpr))
+      wrap_expr(_rbexpr.repeat_by(by._rbexpr, negative_as_empty))
+    end
+
+    # Replace values with other values.
+    #
+    # @param old [Object]
+    #   Values to replace. Can also be a Hash mapping old values to new values,
+    #   or a DataFrame whose first two columns are the old and new values.
+    # @param new [Object]
+    #   Values to replace by. Must have the same length as `old`.
+    # @param default [Object]
+    #   Value or expression for entries that do not match any of `old`. Unmatched
+    #   entries keep their original value if not given. Nulls are always kept.
+    # @param return_dtype [Symbol]
+    #   Data type of the resulting expression.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [1, 2, 3, nil]})
+    #   df.select(Polars.col("a").replace({1 => "a", 2 => "b"}, default: "other"))
+    #   # =>
+    #   # shape: (4, 1)
+    #   # ┌───────┐
+    #   # │ a     │
+    #   # │ ---   │
+    #   # │ str   │
+    #   # ╞═══════╡
+    #   # │ a     │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ b     │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ other │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ null  │
+    #   # └───────┘
+    def replace(old, new = nil, default: nil, return_dtype: nil)
+      if old.is_a?(Hash) && new.nil?
+        new = old.values
+        old = old.keys
+      elsif old.is_a?(DataFrame) && new.nil?
+        new = old.to_series(1)
+        old = old.to_series(0)
+      end
+      old = Series.new("", old) unless old.is_a?(Series)
+      new = Series.new("", new) unless new.is_a?(Series)
+      default = Utils.expr_to_lit_or_expr(default, str_to_lit: true)._rbexpr unless default.nil?
+      return_dtype = Utils.rb_type_to_dtype(return_dtype) unless return_dtype.nil?
+      wrap_expr(_rbexpr.replace(old._s, new._s, default, return_dtype))
+    end
+
+    # Look up values in a mapping.
+    #
+    # @param remapping [Object]
+    #   Mapping from keys to values. Can be a Hash, or a Struct expression or
+    #   Series whose first two fields are the keys and values.
+    # @param default [Object]
+    #   Value or expression for entries whose key is not in the mapping.
+    #   Missing keys are null if not given.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"code" => ["nl", "de", "fr", nil]})
+    #   df.select(Polars.col("code").map_dict({"nl" => "Netherlands", "de" => "Germany"}, default: "unknown"))
+    #   # =>
+    #   # shape: (4, 1)
+    #   # ┌─────────────┐
+    #   # │ code        │
+    #   # │ ---         │
+    #   # │ str         │
+    #   # ╞═════════════╡
+    #   # │ Netherlands │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ Germany     │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ unknown     │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ null        │
+    #   # └─────────────┘
+    def map_dict(remapping, default: nil)
+      if remapping.is_a?(Hash)
+        keys = Polars.lit(Series.new("", remapping.keys))
+        values = Polars.lit(Series.new("", remapping.values))
+      else
+        remapping = Polars.lit(remapping) if remapping.is_a?(Series)
+        keys = remapping.struct[0]
+        values = remapping.struct[1]
+      end
+      default = Utils.expr_to_lit_or_expr(default, str_to_lit: true)._rbexpr unless default.nil?
+      wrap_expr(_rbexpr.map_dict(keys._rbexpr, values._rbexpr, default))
     end
 
     # Check if this expression is between start and end.
@@ -3055,11 +3321,12 @@ module Polars
 
     # Reinterpret the underlying bits as a signed/unsigned integer.
     #
-    # This operation is only allowed for 64bit integers. For lower bits integers,
-    # you can safely use that cast operation.
+    # This operation is only allowed for 32bit and 64bit integers. For lower bits
+    # integers, you can safely use that cast operation.
     #
     # @param signed [Boolean]
-    #   If true, reinterpret as `:i64`. Otherwise, reinterpret as `:u64`.
+    #   If true, reinterpret as `:i64` (or `:i32`). Otherwise, reinterpret as
+    #   `:u64` (or `:u32`).
     #
     # @return [Expr]
     #
@@ -4121,12 +4388,53 @@ module Polars
       wrap_expr(_rbexpr.rank(method, reverse))
     end
 
+    # Rank each value within the window of `window_size` values ending at it.
+    #
+    # @param window_size [Integer]
+    #   The length of the window.
+    # @param min_periods [Integer]
+    #   The number of non-null values in the window that should be present
+    #   before computing a result. If nil, it will be set equal to window size.
+    # @param method ["average", "min", "max", "dense", "ordinal"]
+    #   The method used to assign ranks to tied elements. See {#rank}. With
+    #   "ordinal", the current value is ranked after earlier equal values.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [3, 6, 1, 1, 6]})
+    #   df.select(Polars.col("a").rolling_rank(3))
+    #   # =>
+    #   # shape: (5, 1)
+    #   # ┌──────┐
+    #   # │ a    │
+    #   # │ ---  │
+    #   # │ f32  │
+    #   # ╞══════╡
+    #   # │ null │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ null │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ 1.0  │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ 1.5  │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ 3.0  │
+    #   # └──────┘
+    def rolling_rank(window_size, min_periods: nil, method: "average")
+      min_periods = window_size if min_periods.nil?
+      wrap_expr(_rbexpr.rolling_rank(window_size, min_periods, method))
+    end
+
     # Calculate the n-th discrete difference.
     #
-    # @param n [Integer]
-    #   Number of slots to shift.
+    # @param n [Integer, Expr]
+    #   Number of slots to shift. Can be an expression to vary the lag per row,
+    #   in which case a negative lag gives the difference with a later row and
+    #   lags that fall outside of the data give null.
     # @param null_behavior ["ignore", "drop"]
-    #   How to handle null values.
+    #   How to handle null values. Only "ignore" is supported when `n` is an
+    #   expression.
     #
     # @return [Expr]
     #
@@ -4151,6 +4459,12 @@ module Polars
     #   # │ 20   │
     #   # └──────┘
     def diff(n: 1, null_behavior: "ignore")
+      if n.is_a?(Expr)
+        if null_behavior != "ignore"
+          raise ArgumentError, "null_behavior must be 'ignore' when n is an expression"
+        end
+        return wrap_expr(_rbexpr.diff_by(n._rbexpr))
+      end
       wrap_expr(_rbexpr.diff(n, null_behavior))
     end
 
@@ -4163,6 +4477,9 @@ module Polars
     #
     # @param n [Integer]
     #   Periods to shift for forming percent change.
+    # @param fill_method ["forward", "backward", nil]
+    #   How to fill nulls before computing the change. If `nil`, nulls are
+    #   not filled and propagate to the result.
     #
     # @return [Expr]
     #
@@ -4190,8 +4507,8 @@ module Polars
     #   # ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┤
     #   # │ 12   ┆ 0.0        │
     #   # └──────┴────────────┘
-    def pct_change(n: 1)
-      wrap_expr(_rbexpr.pct_change(n))
+    def pct_change(n: 1, fill_method: "forward")
+      wrap_expr(_rbexpr.pct_change(n, fill_method))
     end
 
     # Compute the sample skewness of a data set.
@@ -4887,9 +5204,11 @@ module Polars
 
     # Extend the Series with given number of values.
     #
+    # In a groupby context, each group is extended.
+    #
     # @param value [Object]
     #   The value to extend the Series with. This value may be nil to fill with
-    #   nulls.
+    #   nulls. An error is raised if the value does not match the dtype.
     # @param n [Integer]
     #   The number of values to extend.
     #
@@ -4919,6 +5238,275 @@ module Polars
       wrap_expr(_rbexpr.extend_constant(value, n))
     end
 
+    # Bin continuous values into discrete categories.
+    #
+    # @param breaks [Array]
+    #   Unique cut points, in increasing order.
+    # @param labels [Array]
+    #   Names of the categories. The number of labels must be one more than the
+    #   number of cut points.
+    # @param left_closed [Boolean]
+    #   Set the intervals to be left-closed instead of right-closed.
+    # @param include_breaks [Boolean]
+    #   Include a column with the right endpoint of the bin each observation falls
+    #   in. This will change the data type of the output from a `Categorical` to a
+    #   `Struct`.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [1.0, 2.5, 4.0]})
+    #   df.select(Polars.col("a").cut([2, 3]))
+    #   # =>
+    #   # shape: (3, 1)
+    #   # ┌─────────────┐
+    #   # │ a           │
+    #   # │ ---         │
+    #   # │ cat         │
+    #   # ╞═════════════╡
+    #   # │ (-inf, 2.0] │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ (2.0, 3.0]  │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ (3.0, inf]  │
+    #   # └─────────────┘
+    def cut(breaks, labels: nil, left_closed: false, include_breaks: false)
+      wrap_expr(_rbexpr.cut(breaks.map(&:to_f), labels, left_closed, include_breaks))
+    end
+
+    # Get one hot encoded dummy variables as boolean expressions.
+    #
+    # The categories must be known when the query is built. For a lazy frame,
+    # they can be derived in a first pass, for instance with
+    # `lf.select(Polars.col("a").unique.sort).collect["a"]`.
+    #
+    # @param categories [Array]
+    #   Categories to create an indicator column for.
+    # @param separator [String]
+    #   Separator between the column name and the category in column names.
+    # @param drop_first [Boolean]
+    #   Remove the first category from the output.
+    #
+    # @return [Array]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => ["x", "y", nil, "x"]})
+    #   df.lazy.with_columns(Polars.col("a").to_dummies(["x", "y"])).collect
+    #   # =>
+    #   # shape: (4, 3)
+    #   # ┌──────┬───────┬───────┐
+    #   # │ a    ┆ a_x   ┆ a_y   │
+    #   # │ ---  ┆ ---   ┆ ---   │
+    #   # │ str  ┆ bool  ┆ bool  │
+    #   # ╞══════╪═══════╪═══════╡
+    #   # │ x    ┆ true  ┆ false │
+    #   # ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    #   # │ y    ┆ false ┆ true  │
+    #   # ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    #   # │ null ┆ false ┆ false │
+    #   # ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    #   # │ x    ┆ true  ┆ false │
+    #   # └──────┴───────┴───────┘
+    def to_dummies(categories, separator: "_", drop_first: false)
+      categories = categories.to_a.map(&:to_s)
+      _rbexpr.to_dummies(categories, separator, drop_first).map { |e| wrap_expr(e) }
+    end
+
+    # Bin values into buckets and count their occurrences.
+    #
+    # Bins are right-closed, except the first bin, which also includes its
+    # lower edge. Nulls and values outside of the bins are not counted.
+    #
+    # @param bins [Array]
+    #   Bin edges. Must be strictly increasing.
+    # @param bin_count [Integer]
+    #   Number of equally sized bins between the minimum and maximum value.
+    #   Defaults to 10 if `bins` is not given either.
+    # @param include_category [Boolean]
+    #   Include a column with the category of each bin.
+    # @param include_breakpoint [Boolean]
+    #   Include a column with the right edge of each bin.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [1, 3, 8, 8, 2, 1, 3]})
+    #   df.select(Polars.col("a").hist(bins: [1, 2, 3])).unnest("a")
+    #   # =>
+    #   # shape: (2, 3)
+    #   # ┌─────────────┬────────────┬───────┐
+    #   # │ break_point ┆ category   ┆ count │
+    #   # │ ---         ┆ ---        ┆ ---   │
+    #   # │ f64         ┆ cat        ┆ u32   │
+    #   # ╞═════════════╪════════════╪═══════╡
+    #   # │ 2.0         ┆ [1.0, 2.0] ┆ 3     │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    #   # │ 3.0         ┆ (2.0, 3.0] ┆ 2     │
+    #   # └─────────────┴────────────┴───────┘
+    def hist(bins: nil, bin_count: nil, include_category: true, include_breakpoint: true)
+      bins = bins.map(&:to_f) unless bins.nil?
+      wrap_expr(_rbexpr.hist(bins, bin_count, include_category, include_breakpoint))
+    end
+
+    # Bin continuous values into discrete categories based on their quantiles.
+    #
+    # @param quantiles [Object]
+    #   Either an array of quantile probabilities between 0 and 1 or a positive
+    #   integer determining the number of bins with uniform probability.
+    # @param labels [Array]
+    #   Names of the categories. The number of labels must be one more than the
+    #   number of quantiles.
+    # @param left_closed [Boolean]
+    #   Set the intervals to be left-closed instead of right-closed.
+    # @param allow_duplicates [Boolean]
+    #   If set to `true`, duplicates in the resulting quantiles are dropped,
+    #   rather than raising an error. This can happen even with unique
+    #   probabilities, depending on the data.
+    # @param include_breaks [Boolean]
+    #   Include a column with the right endpoint of the bin each observation falls
+    #   in. This will change the data type of the output from a `Categorical` to a
+    #   `Struct`.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [1, 2, 3, 4]})
+    #   df.select(Polars.col("a").qcut(2, labels: ["low", "high"]))
+    #   # =>
+    #   # shape: (4, 1)
+    #   # ┌──────┐
+    #   # │ a    │
+    #   # │ ---  │
+    #   # │ cat  │
+    #   # ╞══════╡
+    #   # │ low  │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ low  │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ high │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ high │
+    #   # └──────┘
+    def qcut(quantiles, labels: nil, left_closed: false, allow_duplicates: false, include_breaks: false)
+      if quantiles.is_a?(Integer)
+        if quantiles < 1
+          raise ArgumentError, "quantiles must be positive"
+        end
+        quantiles = (1...quantiles).map { |i| i / quantiles.to_f }
+      end
+      wrap_expr(_rbexpr.qcut(quantiles.map(&:to_f), labels, left_closed, allow_duplicates, include_breaks))
+    end
+
+    # Get the lengths and values of runs of identical values.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [1, 1, 2, 2, 2, 1]})
+    #   df.select(Polars.col("a").rle).unnest("a")
+    #   # =>
+    #   # shape: (3, 2)
+    #   # ┌─────────┬────────┐
+    #   # │ lengths ┆ values │
+    #   # │ ---     ┆ ---    │
+    #   # │ u32     ┆ i64    │
+    #   # ╞═════════╪════════╡
+    #   # │ 2       ┆ 1      │
+    #   # ├╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+    #   # │ 3       ┆ 2      │
+    #   # ├╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+    #   # │ 1       ┆ 1      │
+    #   # └─────────┴────────┘
+    def rle
+      wrap_expr(_rbexpr.rle)
+    end
+
+    # Map values to run IDs.
+    #
+    # Similar to RLE, but it maps each value to an ID corresponding to the run into
+    # which it falls. This is especially useful when you want to define groups by
+    # runs of identical values rather than the values themselves.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [1, 1, 2, 1]})
+    #   df.with_column(Polars.col("a").rle_id.alias("id"))
+    #   # =>
+    #   # shape: (4, 2)
+    #   # ┌─────┬─────┐
+    #   # │ a   ┆ id  │
+    #   # │ --- ┆ --- │
+    #   # │ i64 ┆ u32 │
+    #   # ╞═════╪═════╡
+    #   # │ 1   ┆ 0   │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌┤
+    #   # │ 1   ┆ 0   │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌┤
+    #   # │ 2   ┆ 1   │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌┤
+    #   # │ 1   ┆ 2   │
+    #   # └─────┴─────┘
+    def rle_id
+      wrap_expr(_rbexpr.rle_id)
+    end
+
+    # Get a boolean mask of the local maximum peaks.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [1, 3, 2, 4, 1]})
+    #   df.select(Polars.col("a").peak_max)
+    #   # =>
+    #   # shape: (5, 1)
+    #   # ┌───────┐
+    #   # │ a     │
+    #   # │ ---   │
+    #   # │ bool  │
+    #   # ╞═══════╡
+    #   # │ false │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ true  │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ false │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ true  │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ false │
+    #   # └───────┘
+    def peak_max
+      wrap_expr(_rbexpr.peak_max)
+    end
+
+    # Get a boolean mask of the local minimum peaks.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [4, 1, 3, 2, 5]})
+    #   df.select(Polars.col("a").peak_min)
+    #   # =>
+    #   # shape: (5, 1)
+    #   # ┌───────┐
+    #   # │ a     │
+    #   # │ ---   │
+    #   # │ bool  │
+    #   # ╞═══════╡
+    #   # │ false │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ true  │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ false │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ true  │
+    #   # ├╌╌╌╌╌╌╌┤
+    #   # │ false │
+    #   # └───────┘
+    def peak_min
+      wrap_expr(_rbexpr.peak_min)
+    end
+
     # Count all unique values and create a struct mapping value to count.
     #
     # @param multithreaded [Boolean]
@@ -5117,6 +5705,8 @@ module Polars
     #
     # @param reverse [Boolean]
     #   If the `Series` order is reversed, e.g. descending.
+    # @param verify [Boolean]
+    #   Check the data when the expression is evaluated and raise if it is not sorted.
     #
     # @return [Expr]
     #
@@ -5136,9 +5726,9 @@ module Polars
     #   # ╞════════╡
     #   # │ 3      │
     #   # └────────┘
-    # def set_sorted(reverse: false)
-    #   map { |s| s.set_sorted(reverse) }
-    # end
+    def set_sorted(reverse: false, verify: false)
+      wrap_expr(_rbexpr.set_sorted(reverse, verify))
+    end
 
     # Aggregate to list.
     #
@@ -5170,6 +5760,12 @@ module Polars
     # Shrink to the dtype needed to fit the extrema of this `Series`.
     # This can be used to reduce memory pressure.
     #
+    # @param categorical_threshold [Integer]
+    #   Convert Utf8 columns with fewer distinct values than this to
+    #   Categorical. Utf8 columns are left unchanged when not given. The
+    #   resulting dtypes depend on the data, so they are unknown to the query
+    #   planner until the expression is computed.
+    #
     # @return [Expr]
     #
     # @example
@@ -5198,8 +5794,8 @@ module Polars
     #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
     #   # │ 3   ┆ 8589934592 ┆ 1073741824 ┆ 112  ┆ 129  ┆ c   ┆ 0.12 ┆ false │
     #   # └─────┴────────────┴────────────┴──────┴──────┴─────┴──────┴───────┘
-    def shrink_dtype
-      wrap_expr(_rbexpr.shrink_dtype)
+    def shrink_dtype(categorical_threshold: nil)
+      wrap_expr(_rbexpr.shrink_dtype(categorical_threshold))
     end
 
     # Create an object namespace of all list related methods.
@@ -17,7 +17,7 @@ module Polars
     # Implementing logic using a Ruby function is almost always _significantly_
     # slower and more memory intensive than implementing the same logic using
     # the native expression API because:
-
+    #
     # - The native expression engine runs in Rust; UDFs run in Ruby.
     # - Use of Ruby UDFs forces the DataFrame to be materialized in memory.
     # - Polars-native expressions can be parallelised (UDFs cannot).
@@ -26,6 +26,8 @@ module Polars
     # Wherever possible you should strongly prefer the native expression API
     # to achieve the best performance.
     #
+    # All frames returned by the block must have the same schema.
+    #
     # @return [DataFrame]
     #
     # @example
@@ -36,25 +38,23 @@ module Polars
     #       "shape" => ["square", "triangle", "square", "triangle", "square"]
     #     }
     #   )
-    #   df.groupby("color").apply { |group_df| group_df.sample(2) }
+    #   df.groupby("color", maintain_order: true).apply { |group_df| group_df.head(1) }
     #   # =>
-    #   # shape: (4, 3)
+    #   # shape: (2, 3)
     #   # ┌─────┬───────┬──────────┐
     #   # │ id  ┆ color ┆ shape    │
     #   # │ --- ┆ ---   ┆ ---      │
     #   # │ i64 ┆ str   ┆ str      │
     #   # ╞═════╪═══════╪══════════╡
-    #   # │ 1   ┆ green ┆ triangle │
+    #   # │ 0   ┆ red   ┆ square   │
     #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌┤
-    #   # │ 2   ┆ green ┆ square   │
-    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌┤
-    #   # │ 4   ┆ red   ┆ square   │
-    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌┤
-    #   # │ 3   ┆ red   ┆ triangle │
+    #   # │ 1   ┆ green ┆ triangle │
     #   # └─────┴───────┴──────────┘
-    # def apply(&f)
-    #   _dataframe_class._from_rbdf(_df.groupby_apply(by, f))
-    # end
+    def apply(&f)
+      by = self.by.is_a?(Array) ? self.by : [self.by]
+      wrap_f = ->(rbdf) { f.call(_dataframe_class._from_rbdf(rbdf)) }
+      _dataframe_class._from_rbdf(_df.groupby_apply(by, wrap_f, maintain_order))
+    end
 
     # Use multiple aggregations on columns.
     #
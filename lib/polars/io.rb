@@ -371,8 +371,15 @@ module Polars
     #   Extra options that make sense for a particular storage connection.
     # @param low_memory [Boolean]
     #   Reduce memory pressure at the expense of performance.
+    # @param hive_partitioning [Boolean]
+    #   Add columns for `key=value` directories in the file paths (like
+    #   `year=2023/month=01/`). Values are parsed as integers or floats when possible.
     #
     # @return [LazyFrame]
+    #
+    # @note
+    #   When `file` is a glob pattern, all matching files must have the same schema.
+    #   The files are scanned in sorted order and concatenated.
     def scan_parquet(
       file,
       n_rows: nil,
@@ -382,7 +389,8 @@ module Polars
       row_count_name: nil,
       row_count_offset: 0,
       storage_options: nil,
-      low_memory: false
+      low_memory: false,
+      hive_partitioning: false
     )
       if file.is_a?(String) || (defined?(Pathname) && file.is_a?(Pathname))
         file = Utils.format_path(file)
@@ -397,7 +405,8 @@ module Polars
         row_count_name: row_count_name,
         row_count_offset: row_count_offset,
         storage_options: storage_options,
-        low_memory: low_memory
+        low_memory: low_memory,
+        hive_partitioning: hive_partitioning
       )
     end
 
@@ -524,7 +533,8 @@ module Polars
     #   Path to a file, or a file-like object.
     # @param columns [Object]
     #   Columns to select. Accepts a list of column indices (starting at zero) or a list
-    #   of column names.
+    #   of column names. Only the selected columns are read from the file. Raises an
+    #   error if a column is not in the file's schema.
     # @param n_rows [Integer]
     #   Stop reading from parquet file after reading `n_rows`.
     # @param storage_options [Hash]
@@ -593,8 +603,33 @@ module Polars
     # def read_sql
     # end
 
-    # def read_excel
-    # end
+    # Read an Excel worksheet into a DataFrame.
+    #
+    # @param file [String]
+    #   Path to an `.xlsx`, `.xls`, or `.ods` file.
+    # @param sheet_id [Integer]
+    #   Sheet number to read (starting at 1). Defaults to the first sheet.
+    # @param sheet_name [String]
+    #   Sheet name to read. Takes precedence over `sheet_id`.
+    # @param has_header [Boolean]
+    #   Indicate if the first row of the sheet is a header or not.
+    # @param infer_schema_length [Integer]
+    #   Maximum number of rows to read for schema inference.
+    #   If set to `nil`, the full data may be scanned *(this is slow)*.
+    #
+    # @return [DataFrame]
+    #
+    # @note
+    #   Dtypes are inferred the same way as `read_csv`. Empty cells are read as nulls.
+    def read_excel(file, sheet_id: nil, sheet_name: nil, has_header: true, infer_schema_length: 100)
+      DataFrame._read_excel(
+        file,
+        sheet_id: sheet_id,
+        sheet_name: sheet_name,
+        has_header: has_header,
+        infer_schema_length: infer_schema_length
+      )
+    end
 
     # Read a CSV file in batches.
     #
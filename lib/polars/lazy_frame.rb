@@ -80,7 +80,8 @@ module Polars
       row_count_name: nil,
       row_count_offset: 0,
       storage_options: nil,
-      low_memory: false
+      low_memory: false,
+      hive_partitioning: false
     )
       _from_rbldf(
         RbLazyFrame.new_from_parquet(
@@ -90,7 +91,8 @@ module Polars
           parallel,
           rechunk,
           Utils._prepare_row_count_args(row_count_name, row_count_offset),
-          low_memory
+          low_memory,
+          hive_partitioning
         )
       )
     end
@@ -270,6 +272,102 @@ module Polars
       nil
     end
 
+    # Persist a LazyFrame at the provided path.
+    #
+    # This allows streaming results that are larger than RAM to be written to disk.
+    #
+    # @param path [String]
+    #   File path to which the file should be written.
+    # @param compression ["lz4", "uncompressed", "snappy", "gzip", "lzo", "brotli", "zstd"]
+    #   Choose "zstd" for good compression performance.
+    #   Choose "lz4" for fast compression/decompression.
+    #   Choose "snappy" for more backwards compatibility guarantees
+    #   when you deal with older parquet readers.
+    # @param compression_level [Integer, nil]
+    #   The level of compression to use. Higher compression means smaller files on
+    #   disk.
+    #
+    #   - "gzip" : min-level: 0, max-level: 10.
+    #   - "brotli" : min-level: 0, max-level: 11.
+    #   - "zstd" : min-level: 1, max-level: 22.
+    # @param statistics [Boolean]
+    #   Write statistics to the parquet headers. This requires extra compute.
+    # @param row_group_size [Integer, nil]
+    #   Size of the row groups in number of rows.
+    #   If `nil` (default), the chunks of the DataFrame are
+    #   used. Writing in smaller chunks may reduce memory pressure and improve
+    #   writing speeds.
+    # @param data_pagesize_limit [Integer, nil]
+    #   Size limit of individual data pages.
+    #   If not set defaults to 1024 * 1024 bytes.
+    # @param maintain_order [Boolean]
+    #   Maintain the order in which data is processed.
+    #   Setting this to `false` will be slightly faster.
+    #
+    # @return [nil]
+    #
+    # @example
+    #   lf = Polars::DataFrame.new({"a" => [1, 2, 3]}).lazy
+    #   lf.sink_parquet("out.parquet")
+    def sink_parquet(
+      path,
+      compression: "zstd",
+      compression_level: nil,
+      statistics: false,
+      row_group_size: nil,
+      data_pagesize_limit: nil,
+      maintain_order: true
+    )
+      if compression.nil?
+        compression = "uncompressed"
+      end
+
+      _ldf.sink_parquet(
+        Utils.format_path(path),
+        compression,
+        compression_level,
+        statistics,
+        row_group_size,
+        data_pagesize_limit,
+        maintain_order
+      )
+      nil
+    end
+
+    # Persist a LazyFrame at the provided path in the Arrow IPC format.
+    #
+    # This allows streaming results that are larger than RAM to be written to disk.
+    #
+    # @param path [String]
+    #   File path to which the file should be written.
+    # @param compression ["uncompressed", "lz4", "zstd"]
+    #   Compression method. Defaults to "uncompressed".
+    # @param maintain_order [Boolean]
+    #   Maintain the order in which data is processed.
+    #   Setting this to `false` will be slightly faster.
+    #
+    # @return [nil]
+    #
+    # @example
+    #   lf = Polars::DataFrame.new({"a" => [1, 2, 3]}).lazy
+    #   lf.sink_ipc("out.arrow")
+    def sink_ipc(
+      path,
+      compression: "uncompressed",
+      maintain_order: true
+    )
+      if compression.nil?
+        compression = "uncompressed"
+      end
+
+      _ldf.sink_ipc(
+        Utils.format_path(path),
+        compression,
+        maintain_order
+      )
+      nil
+    end
+
     # Offers a structured way to apply a sequence of user-defined functions (UDFs).
     #
     # @param func [Object]
@@ -320,11 +418,11 @@ module Polars
     # @return [String]
     def describe_optimized_plan(
       type_coercion: true,
-      predicate_pushdown: true,
-      projection_pushdown: true,
+      predicate_pushdown: nil,
+      projection_pushdown: nil,
       simplify_expression: true,
-      slice_pushdown: true,
-      common_subplan_elimination: true,
+      slice_pushdown: nil,
+      common_subplan_elimination: nil,
       allow_streaming: false
     )
       ldf = _ldf.optimization_toggle(
@@ -340,6 +438,64 @@ module Polars
       ldf.describe_optimized_plan
     end
 
+    # Toggle predicate pushdown.
+    #
+    # When enabled, filters are applied as early as possible, ideally
+    # at scan level. It is enabled by default.
+    #
+    # @param toggle [Boolean]
+    #   Whether to enable the optimization.
+    #
+    # @return [LazyFrame]
+    def with_predicate_pushdown(toggle = true)
+      _from_rbldf(_ldf.with_predicate_pushdown(toggle))
+    end
+
+    # Toggle projection pushdown.
+    #
+    # When enabled, only the columns that are needed are read and
+    # carried through the query. It is enabled by default.
+    #
+    # @param toggle [Boolean]
+    #   Whether to enable the optimization.
+    #
+    # @return [LazyFrame]
+    def with_projection_pushdown(toggle = true)
+      _from_rbldf(_ldf.with_projection_pushdown(toggle))
+    end
+
+    # Toggle slice pushdown.
+    #
+    # When enabled, slices like `head` only materialize the required
+    # rows. It is enabled by default.
+    #
+    # @param toggle [Boolean]
+    #   Whether to enable the optimization.
+    #
+    # @return [LazyFrame]
+    def with_slice_pushdown(toggle = true)
+      _from_rbldf(_ldf.with_slice_pushdown(toggle))
+    end
+
+    # Toggle common subplan elimination.
+    #
+    # When enabled, subplans that occur more than once in the query are
+    # computed once and cached. This helps when a derived frame is joined
+    # with itself or concatenated with another frame derived from the same
+    # source. It is enabled by default.
+    #
+    # @param toggle [Boolean]
+    #   Whether to enable the optimization.
+    #
+    # @return [LazyFrame]
+    #
+    # @example
+    #   lf = Polars::DataFrame.new({"a" => [1, 2, 3]}).lazy.with_column(Polars.col("a") * 2)
+    #   lf.join(lf, on: "a").with_comm_subplan_elim(false).collect
+    def with_comm_subplan_elim(toggle = true)
+      _from_rbldf(_ldf.with_comm_subplan_elim(toggle))
+    end
+
     # def show_graph
     # end
 
@@ -405,9 +561,9 @@ module Polars
     # @param type_coercion [Boolean]
     #   Do type coercion optimization.
     # @param predicate_pushdown [Boolean]
-    #   Do predicate pushdown optimization.
+    #   Do predicate pushdown optimization. Defaults to the setting of the frame.
     # @param projection_pushdown [Boolean]
-    #   Do projection pushdown optimization.
+    #   Do projection pushdown optimization. Defaults to the setting of the frame.
     # @param simplify_expression [Boolean]
     #   Run simplify expressions optimization.
     # @param string_cache [Boolean]
@@ -416,13 +572,17 @@ module Polars
     # @param no_optimization [Boolean]
     #   Turn off (certain) optimizations.
     # @param slice_pushdown [Boolean]
-    #   Slice pushdown optimization.
+    #   Slice pushdown optimization. Defaults to the setting of the frame.
     # @param common_subplan_elimination [Boolean]
     #   Will try to cache branching subplans that occur on self-joins or unions.
+    #   Defaults to the setting of the frame.
     # @param allow_streaming [Boolean]
     #   Run parts of the query in a streaming fashion (this is in an alpha state)
+    # @param return_plan [Boolean]
+    #   Also return the optimized query plan as a string, as
+    #   `[DataFrame, String]`.
     #
-    # @return [DataFrame]
+    # @return [DataFrame, Array]
     #
     # @example
     #   df = Polars::DataFrame.new(
@@ -448,14 +608,15 @@ module Polars
     #   # └─────┴─────┴─────┘
     def collect(
       type_coercion: true,
-      predicate_pushdown: true,
-      projection_pushdown: true,
+      predicate_pushdown: nil,
+      projection_pushdown: nil,
       simplify_expression: true,
       string_cache: false,
       no_optimization: false,
-      slice_pushdown: true,
-      common_subplan_elimination: true,
-      allow_streaming: false
+      slice_pushdown: nil,
+      common_subplan_elimination: nil,
+      allow_streaming: false,
+      return_plan: false
     )
       if no_optimization
         predicate_pushdown = false
@@ -477,7 +638,38 @@ module Polars
         common_subplan_elimination,
         allow_streaming
       )
-      Utils.wrap_df(ldf.collect)
+      if return_plan
+        df, plan = ldf.collect_with_plan
+        [Utils.wrap_df(df), plan]
+      else
+        Utils.wrap_df(ldf.collect)
+      end
+    end
+
+    # Collect into a DataFrame using the streaming engine.
+    #
+    # The query is processed in batches, so it can run on data that does not
+    # fit in memory. Parts of the query that are not supported by the
+    # streaming engine fall back to the default engine.
+    #
+    # @return [DataFrame]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => ["x", "y", "x"], "b" => [1, 2, 3]}).lazy
+    #   df.groupby("a").agg(Polars.col("b").sum).sort("a").collect_streaming
+    #   # =>
+    #   # shape: (2, 2)
+    #   # ┌─────┬─────┐
+    #   # │ a   ┆ b   │
+    #   # │ --- ┆ --- │
+    #   # │ str ┆ i64 │
+    #   # ╞═════╪═════╡
+    #   # │ x   ┆ 4   │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌┤
+    #   # │ y   ┆ 2   │
+    #   # └─────┴─────┘
+    def collect_streaming
+      Utils.wrap_df(_ldf.collect_streaming)
     end
 
     # Collect a small number of rows for debugging purposes.
@@ -495,9 +687,9 @@ module Polars
     # @param type_coercion [Boolean]
     #   Run type coercion optimization.
     # @param predicate_pushdown [Boolean]
-    #   Run predicate pushdown optimization.
+    #   Run predicate pushdown optimization. Defaults to the setting of the frame.
     # @param projection_pushdown [Boolean]
-    #   Run projection pushdown optimization.
+    #   Run projection pushdown optimization. Defaults to the setting of the frame.
     # @param simplify_expression [Boolean]
     #   Run simplify expressions optimization.
     # @param string_cache [Boolean]
@@ -506,9 +698,10 @@ module Polars
     # @param no_optimization [Boolean]
     #   Turn off optimizations.
     # @param slice_pushdown [Boolean]
-    #   Slice pushdown optimization
+    #   Slice pushdown optimization. Defaults to the setting of the frame.
     # @param common_subplan_elimination [Boolean]
     #   Will try to cache branching subplans that occur on self-joins or unions.
+    #   Defaults to the setting of the frame.
     # @param allow_streaming [Boolean]
     #   Run parts of the query in a streaming fashion (this is in an alpha state)
     #
@@ -537,13 +730,13 @@ module Polars
     def fetch(
       n_rows = 500,
       type_coercion: true,
-      predicate_pushdown: true,
-      projection_pushdown: true,
+      predicate_pushdown: nil,
+      projection_pushdown: nil,
       simplify_expression: true,
       string_cache: false,
       no_optimization: false,
-      slice_pushdown: true,
-      common_subplan_elimination: true,
+      slice_pushdown: nil,
+      common_subplan_elimination: nil,
       allow_streaming: false
     )
       if no_optimization
@@ -1261,6 +1454,7 @@ module Polars
     #   Or combine them:
     #   "3d12h4m25s" # 3 days, 12 hours, 4 minutes, and 25 seconds
     #
+    #   Numeric tolerances are not allowed for temporal keys.
     # @param allow_parallel [Boolean]
     #   Allow the physical plan to optionally evaluate the computation of both
     #   DataFrames up to the join in parallel.
@@ -1356,6 +1550,9 @@ module Polars
     #   Join strategy.
     # @param suffix [String]
     #   Suffix to append to columns with a duplicate name.
+    # @param coalesce [Boolean]
+    #   Merge the left and right key columns into one. If `false`, the right
+    #   key columns are kept with the suffix appended.
     # @param allow_parallel [Boolean]
     #   Allow the physical plan to optionally evaluate the computation of both
     #   DataFrames up to the join in parallel.
@@ -1459,16 +1656,21 @@ module Polars
       how: "inner",
       suffix: "_right",
       allow_parallel: true,
-      force_parallel: false
+      force_parallel: false,
+      coalesce: true
     )
       if !other.is_a?(LazyFrame)
         raise ArgumentError, "Expected a `LazyFrame` as join table, got #{other.class.name}"
       end
 
       if how == "cross"
+        if !on.nil? || !left_on.nil? || !right_on.nil?
+          raise ArgumentError, "cross join does not take join keys"
+        end
+
         return _from_rbldf(
           _ldf.join(
-            other._ldf, [], [], allow_parallel, force_parallel, how, suffix
+            other._ldf, [], [], allow_parallel, force_parallel, how, suffix, true
           )
         )
       end
@@ -1493,6 +1695,7 @@ module Polars
           force_parallel,
           how,
           suffix,
+          coalesce
         )
       )
     end
@@ -1683,6 +1886,10 @@ module Polars
     #
     # @param periods [Integer]
     #   Number of places to shift (may be negative).
+    # @param fill_value [Object]
+    #   Fill the resulting null values with this value or expression. Can also
+    #   be a Hash of column names to fill values, in which case other columns
+    #   are not filled.
     #
     # @return [LazyFrame]
     #
@@ -1723,8 +1930,34 @@ module Polars
     #   # ├╌╌╌╌╌╌┼╌╌╌╌╌╌┤
     #   # │ null ┆ null │
     #   # └──────┴──────┘
-    def shift(periods)
-      _from_rbldf(_ldf.shift(periods))
+    #
+    # @example
+    #   df.shift(1, fill_value: {"a" => 0}).collect
+    #   # =>
+    #   # shape: (3, 2)
+    #   # ┌─────┬──────┐
+    #   # │ a   ┆ b    │
+    #   # │ --- ┆ ---  │
+    #   # │ i64 ┆ i64  │
+    #   # ╞═════╪══════╡
+    #   # │ 0   ┆ null │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌┤
+    #   # │ 1   ┆ 2    │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌┤
+    #   # │ 3   ┆ 4    │
+    #   # └─────┴──────┘
+    def shift(periods = 1, fill_value: nil)
+      fill_columns = []
+      fill_values = []
+      if fill_value.is_a?(Hash)
+        fill_value.each do |k, v|
+          fill_columns << k.to_s
+          fill_values << Utils.expr_to_lit_or_expr(v, str_to_lit: true)._rbexpr
+        end
+        fill_value = nil
+      end
+      fill_value = Utils.expr_to_lit_or_expr(fill_value, str_to_lit: true)._rbexpr unless fill_value.nil?
+      _from_rbldf(_ldf.shift(periods, fill_value, fill_columns, fill_values))
     end
 
     # Shift the values by a given period and fill the resulting null values.
@@ -2173,6 +2406,12 @@ module Polars
 
     # Explode lists to long format.
     #
+    # @param columns [Object]
+    #   Column names or expressions to explode.
+    # @param empty_as_null [Boolean]
+    #   Give a null row for an empty list. If `false`, rows with empty lists are
+    #   dropped.
+    #
     # @return [LazyFrame]
     #
     # @example
@@ -2206,9 +2445,9 @@ module Polars
     #   # ├╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
     #   # │ c       ┆ 8       │
     #   # └─────────┴─────────┘
-    def explode(columns)
+    def explode(columns, empty_as_null: true)
       columns = Utils.selection_to_rbexpr_list(columns)
-      _from_rbldf(_ldf.explode(columns))
+      _from_rbldf(_ldf.explode(columns, empty_as_null))
     end
 
     # Drop duplicate rows from this DataFrame.
@@ -651,6 +651,88 @@ module Polars
       end
     end
 
+    # Create a range of integers.
+    #
+    # @param start [Integer]
+    #   Start of the range (inclusive).
+    # @param stop [Integer]
+    #   End of the range (exclusive).
+    # @param step [Integer]
+    #   Step size of the range. Can be negative.
+    # @param eager [Boolean]
+    #   If eager evaluation is `true`, a Series is returned instead of an Expr.
+    # @param dtype [Symbol]
+    #   Integer data type of the range.
+    #
+    # @return [Expr, Series]
+    #
+    # @example
+    #   Polars.int_range(0, 6, step: 2, eager: true, dtype: :u32)
+    #   # =>
+    #   # shape: (3,)
+    #   # Series: 'int_range' [u32]
+    #   # [
+    #   #         0
+    #   #         2
+    #   #         4
+    #   # ]
+    def int_range(start, stop, step: 1, eager: false, dtype: :i64)
+      if eager
+        return Utils.wrap_s(_int_range(start, stop, step, Utils.rb_type_to_dtype(dtype)))
+      end
+
+      start = Utils.expr_to_lit_or_expr(start, str_to_lit: false)
+      stop = Utils.expr_to_lit_or_expr(stop, str_to_lit: false)
+      Utils.wrap_expr(
+        RbExpr.int_range(start._rbexpr, stop._rbexpr, step, Utils.rb_type_to_dtype(dtype))
+      ).alias("int_range")
+    end
+
+    # Create a column of integer ranges, one range per row.
+    #
+    # @param start [Object]
+    #   Start of each range (inclusive). Strings are parsed as column names.
+    # @param stop [Object]
+    #   End of each range (exclusive). Strings are parsed as column names.
+    # @param step [Integer]
+    #   Step size of the ranges. Can be negative.
+    # @param dtype [Symbol]
+    #   Integer data type of the range values.
+    # @param eager [Boolean]
+    #   If eager evaluation is `true`, a Series is returned instead of an Expr.
+    #
+    # @return [Expr, Series]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"start" => [1, -1, 3], "end" => [3, 2, 1]})
+    #   df.select(Polars.int_ranges("start", "end"))
+    #   # =>
+    #   # shape: (3, 1)
+    #   # ┌────────────┐
+    #   # │ start      │
+    #   # │ ---        │
+    #   # │ list[i64]  │
+    #   # ╞════════════╡
+    #   # │ [1, 2]     │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ [-1, 0, 1] │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ []         │
+    #   # └────────────┘
+    def int_ranges(start, stop, step: 1, dtype: :i64, eager: false)
+      start = Utils.expr_to_lit_or_expr(start, str_to_lit: false)
+      stop = Utils.expr_to_lit_or_expr(stop, str_to_lit: false)
+      range_expr = Utils.wrap_expr(
+        RbExpr.int_ranges(start._rbexpr, stop._rbexpr, step, Utils.rb_type_to_dtype(dtype))
+      )
+
+      if !eager
+        range_expr
+      else
+        DataFrame.new.select(range_expr).to_series
+      end
+    end
+
     # Find the indexes that would sort the columns.
     #
     # Argsort by multiple columns. The first column will be used for the ordering.
@@ -875,9 +957,9 @@ module Polars
     # @param type_coercion [Boolean]
     #   Do type coercion optimization.
     # @param predicate_pushdown [Boolean]
-    #   Do predicate pushdown optimization.
+    #   Do predicate pushdown optimization. Defaults to the setting of the frame.
     # @param projection_pushdown [Boolean]
-    #   Do projection pushdown optimization.
+    #   Do projection pushdown optimization. Defaults to the setting of the frame.
     # @param simplify_expression [Boolean]
     #   Run simplify expressions optimization.
     # @param string_cache [Boolean]
@@ -885,9 +967,10 @@ module Polars
     # @param no_optimization [Boolean]
     #   Turn off optimizations.
     # @param slice_pushdown [Boolean]
-    #   Slice pushdown optimization.
+    #   Slice pushdown optimization. Defaults to the setting of the frame.
     # @param common_subplan_elimination [Boolean]
     #   Will try to cache branching subplans that occur on self-joins or unions.
+    #   Defaults to the setting of the frame.
     # @param allow_streaming [Boolean]
     #   Run parts of the query in a streaming fashion (this is in an alpha state)
     #
@@ -895,13 +978,13 @@ module Polars
     def collect_all(
       lazy_frames,
       type_coercion: true,
-      predicate_pushdown: true,
-      projection_pushdown: true,
+      predicate_pushdown: nil,
+      projection_pushdown: nil,
       simplify_expression: true,
       string_cache: false,
       no_optimization: false,
-      slice_pushdown: true,
-      common_subplan_elimination: true,
+      slice_pushdown: nil,
+      common_subplan_elimination: nil,
       allow_streaming: false
     )
       if no_optimization
@@ -1009,15 +1092,30 @@ module Polars
     #   Run eagerly and collect into a `Series`.
     # @param name [String]
     #   Only used in `eager` mode. As expression, use `alias`.
+    # @param dtype [Symbol]
+    #   Only used in `eager` mode. Data type of the resulting `Series`.
     #
-    # @return [Expr]
-    def repeat(value, n, eager: false, name: nil)
+    # @return [Object]
+    #
+    # @example
+    #   Polars.repeat(1, 3, eager: true, name: "a", dtype: :i8)
+    #   # =>
+    #   # shape: (3,)
+    #   # Series: 'a' [i8]
+    #   # [
+    #   #         1
+    #   #         1
+    #   #         1
+    #   # ]
+    def repeat(value, n, eager: false, name: nil, dtype: nil)
       if eager
         if name.nil?
           name = ""
         end
-        dtype = py_type_to_dtype(type(value))
-        Series._repeat(name, value, n, dtype)
+        if !dtype.nil?
+          dtype = Utils.rb_type_to_dtype(dtype)
+        end
+        Utils.wrap_s(_repeat_series(Series.new(name, [value])._s, n, dtype))
       else
         if n.is_a?(Integer)
           n = lit(n)
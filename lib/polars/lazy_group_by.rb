@@ -87,7 +87,39 @@ module Polars
       @lazyframe_class._from_rbldf(@lgb.tail(n))
     end
 
-    # def apply
-    # end
+    # Apply a custom/user-defined function (UDF) over the groups as a sub-DataFrame.
+    #
+    # The block is called with each group when the query is collected. The
+    # query planner cannot look inside the block, so the schema of the frames
+    # it returns must be given up front.
+    #
+    # @param schema [Hash]
+    #   Schema of the frames returned by the block. An error is raised on
+    #   collect if a group returns a frame with a different schema.
+    #
+    # @return [LazyFrame]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"g" => ["a", "a", "b"], "x" => [1.0, 3.0, 5.0]}).lazy
+    #   df.groupby("g", maintain_order: true)
+    #     .apply(schema: {"g" => :str, "x" => :f64}) { |gdf| gdf.with_column(Polars.col("x") - Polars.col("x").mean) }
+    #     .collect
+    #   # =>
+    #   # shape: (3, 2)
+    #   # ┌─────┬──────┐
+    #   # │ g   ┆ x    │
+    #   # │ --- ┆ ---  │
+    #   # │ str ┆ f64  │
+    #   # ╞═════╪══════╡
+    #   # │ a   ┆ -1.0 │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌┤
+    #   # │ a   ┆ 1.0  │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌╌┤
+    #   # │ b   ┆ 0.0  │
+    #   # └─────┴──────┘
+    def apply(schema:, &f)
+      wrap_f = ->(rbdf) { f.call(DataFrame._from_rbdf(rbdf)) }
+      @lazyframe_class._from_rbldf(@lgb.apply(wrap_f, schema))
+    end
   end
 end
@@ -547,8 +547,15 @@ module Polars
     # @param n_field_strategy ["first_non_null", "max_width"]
     #   Strategy to determine the number of fields of the struct.
     # @param name_generator [Object]
-    #   A custom function that can be used to generate the field names.
+    #   A callable that receives the index of a field and returns its name.
+    #   Can also be given as a block. Requires `upper_bound`.
     #   Default field names are `field_0, field_1 .. field_n`
+    # @param upper_bound [Integer]
+    #   Declared number of fields of the struct. When set, the struct always has
+    #   this many fields, shorter lists are padded with nulls, and
+    #   `n_field_strategy` is ignored.
+    # @param truncate [Boolean]
+    #   Truncate lists longer than `upper_bound` instead of raising an error.
     #
     # @return [Expr]
     #
@@ -566,9 +573,24 @@ module Polars
     #   # ├╌╌╌╌╌╌╌╌╌╌╌╌┤
     #   # │ {1,2,null} │
     #   # └────────────┘
-    def to_struct(n_field_strategy: "first_non_null", name_generator: nil)
-      raise Todo if name_generator
-      Utils.wrap_expr(_rbexpr.lst_to_struct(n_field_strategy, name_generator, 0))
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => [[1, 2], [3, 4, 5]]})
+    #   df.select(Polars.col("a").arr.to_struct(upper_bound: 2) { |i| "x#{i}" }).unnest("a")
+    #   # =>
+    #   # shape: (2, 2)
+    #   # ┌─────┬─────┐
+    #   # │ x0  ┆ x1  │
+    #   # │ --- ┆ --- │
+    #   # │ i64 ┆ i64 │
+    #   # ╞═════╪═════╡
+    #   # │ 1   ┆ 2   │
+    #   # ├╌╌╌╌╌┼╌╌╌╌╌┤
+    #   # │ 3   ┆ 4   │
+    #   # └─────┴─────┘
+    def to_struct(n_field_strategy: "first_non_null", name_generator: nil, upper_bound: nil, truncate: true, &block)
+      name_generator ||= block
+      Utils.wrap_expr(_rbexpr.lst_to_struct(n_field_strategy, name_generator, upper_bound || 0, truncate))
     end
 
     # Run any polars expression against the lists' elements.
@@ -88,6 +88,9 @@ module Polars
 
     # Get flags that are set on the Series.
     #
+    # The sorted flags are hints that were set by a sort or {#set_sorted}.
+    # They are not verified against the data. Use {#is_sorted} for that.
+    #
     # @return [Hash]
     def flags
       {
@@ -96,6 +99,30 @@ module Polars
       }
     end
 
+    # Check if the Series is sorted.
+    #
+    # Unlike {#flags}, this checks the data, which takes O(n) time.
+    #
+    # @param reverse [Boolean]
+    #   Check for descending order.
+    # @param nulls_last [Boolean]
+    #   Expect nulls at the end instead of the start.
+    #
+    # @return [Boolean]
+    #
+    # @example
+    #   s = Polars::Series.new([1, 3, 2])
+    #   s.is_sorted
+    #   # => false
+    #
+    # @example
+    #   s = Polars::Series.new([3, 2, nil])
+    #   s.is_sorted(reverse: true, nulls_last: true)
+    #   # => true
+    def is_sorted(reverse: false, nulls_last: false)
+      _s.is_sorted(reverse, nulls_last)
+    end
+
     # Get the inner dtype in of a List typed Series.
     #
     # @return [Symbol]
@@ -350,16 +377,24 @@ module Polars
 
     # Check if any boolean value in the column is `true`.
     #
-    # @return [Boolean]
-    def any
-      to_frame.select(Polars.col(name).any).to_series[0]
+    # @param ignore_nulls [Boolean]
+    #   Ignore null values. If false, Kleene logic is used and the result
+    #   is `nil` when there are no `true` values and at least one null.
+    #
+    # @return [Boolean, nil]
+    def any(ignore_nulls: true)
+      to_frame.select(Polars.col(name).any(ignore_nulls: ignore_nulls)).to_series[0]
     end
 
     # Check if all boolean values in the column are `true`.
     #
-    # @return [Boolean]
-    def all
-      to_frame.select(Polars.col(name).all).to_series[0]
+    # @param ignore_nulls [Boolean]
+    #   Ignore null values. If false, Kleene logic is used and the result
+    #   is `nil` when there are no `false` values and at least one null.
+    #
+    # @return [Boolean, nil]
+    def all(ignore_nulls: true)
+      to_frame.select(Polars.col(name).all(ignore_nulls: ignore_nulls)).to_series[0]
     end
 
     # Compute the logarithm to a given base.
@@ -640,6 +675,13 @@ module Polars
 
     # Get dummy variables.
     #
+    # @param separator [String]
+    #   Separator between the series name and the value in column names.
+    # @param drop_first [Boolean]
+    #   Remove the first category from the output.
+    # @param include_null [Boolean]
+    #   Add an indicator column for null values.
+    #
     # @return [DataFrame]
     #
     # @example
@@ -658,8 +700,8 @@ module Polars
     #   # ├╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌┤
     #   # │ 0   ┆ 0   ┆ 1   │
     #   # └─────┴─────┴─────┘
-    def to_dummies
-      Utils.wrap_df(_s.to_dummies)
+    def to_dummies(separator: "_", drop_first: false, include_null: false)
+      Utils.wrap_df(_s.to_dummies(separator, drop_first, include_null))
     end
 
     # Count the unique values in a Series.
@@ -1015,9 +1057,15 @@ module Polars
     #   before doing a query. For instance, when you read in multiple files and when
     #   to store them in a single Series. In the latter case, finish the sequence
     #   of `append_chunks` operations with a `rechunk`.
+    # @param rechunk [Boolean]
+    #   Rechunk after appending the chunks. Only used when `append_chunks` is `true`.
     #
     # @return [Series]
     #
+    # @note
+    #   Numeric Series with different dtypes are cast to their supertype. Other
+    #   dtypes must match.
+    #
     # @example
     #   s = Polars::Series.new("a", [1, 2, 3])
     #   s2 = Polars::Series.new("b", [4, 5, 6])
@@ -1033,16 +1081,16 @@ module Polars
     #   #         5
     #   #         6
     #   # ]
-    def append(other, append_chunks: true)
+    def append(other, append_chunks: true, rechunk: false)
       begin
         if append_chunks
-          _s.append(other._s)
+          _s.append(other._s, rechunk)
         else
           _s.extend(other._s)
         end
       rescue => e
         if e.message == "Already mutably borrowed"
-          append(other.clone, append_chunks)
+          append(other.clone, append_chunks: append_chunks, rechunk: rechunk)
         else
           raise e
         end
@@ -1135,6 +1183,185 @@ module Polars
       super
     end
 
+    # Take every nth value in the Series, starting at an offset.
+    #
+    # @param n [Integer]
+    #   Gather every *n*-th element.
+    # @param offset [Integer]
+    #   Start the gathering at this index.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new("a", [1, 2, 3, 4])
+    #   s.gather_every(2, 1)
+    #   # =>
+    #   # shape: (2,)
+    #   # Series: 'a' [i64]
+    #   # [
+    #   #         2
+    #   #         4
+    #   # ]
+    def gather_every(n, offset = 0)
+      Utils.wrap_s(_s.gather_every(n, offset))
+    end
+
+    # Replace values with other values.
+    #
+    # @param old [Object]
+    #   Values to replace. Can also be a Hash mapping old values to new values,
+    #   or a DataFrame whose first two columns are the old and new values.
+    # @param new [Object]
+    #   Values to replace by. Must have the same length as `old`.
+    # @param default [Object]
+    #   Value for entries that do not match any of `old`. Unmatched entries keep
+    #   their original value if not given. Nulls are always kept.
+    # @param return_dtype [Symbol]
+    #   Data type of the resulting Series.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new("a", ["x", "y", "z"])
+    #   s.replace({"x" => "a", "y" => "b"})
+    #   # =>
+    #   # shape: (3,)
+    #   # Series: 'a' [str]
+    #   # [
+    #   #         "a"
+    #   #         "b"
+    #   #         "z"
+    #   # ]
+    def replace(old, new = nil, default: nil, return_dtype: nil)
+      if old.is_a?(Hash) && new.nil?
+        new = old.values
+        old = old.keys
+      elsif old.is_a?(DataFrame) && new.nil?
+        new = old.to_series(1)
+        old = old.to_series(0)
+      end
+      old = Series.new("", old) unless old.is_a?(Series)
+      new = Series.new("", new) unless new.is_a?(Series)
+      default = Series.new("", [default]) unless default.nil?
+      return_dtype = Utils.rb_type_to_dtype(return_dtype) unless return_dtype.nil?
+      Utils.wrap_s(_s.replace(old._s, new._s, default&._s, return_dtype))
+    end
+
+    # Get the lengths and values of runs of identical values.
+    #
+    # Consecutive nulls form a run of their own.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new("s", [1, 1, 2, 1, nil, 1, 3, 3])
+    #   s.rle.struct.to_frame
+    #   # =>
+    #   # shape: (6, 2)
+    #   # ┌─────────┬────────┐
+    #   # │ lengths ┆ values │
+    #   # │ ---     ┆ ---    │
+    #   # │ u32     ┆ i64    │
+    #   # ╞═════════╪════════╡
+    #   # │ 2       ┆ 1      │
+    #   # ├╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+    #   # │ 1       ┆ 2      │
+    #   # ├╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+    #   # │ 1       ┆ 1      │
+    #   # ├╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+    #   # │ 1       ┆ null   │
+    #   # ├╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+    #   # │ 1       ┆ 1      │
+    #   # ├╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌┤
+    #   # │ 2       ┆ 3      │
+    #   # └─────────┴────────┘
+    def rle
+      Utils.wrap_s(_s.rle)
+    end
+
+    # Map values to run IDs.
+    #
+    # Similar to RLE, but it maps each value to an ID corresponding to the run into
+    # which it falls. Consecutive nulls form a run of their own.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new("s", [1, 1, 2, 1, nil, 1, 3, 3])
+    #   s.rle_id
+    #   # =>
+    #   # shape: (8,)
+    #   # Series: 's' [u32]
+    #   # [
+    #   #         0
+    #   #         0
+    #   #         1
+    #   #         2
+    #   #         3
+    #   #         4
+    #   #         5
+    #   #         5
+    #   # ]
+    def rle_id
+      Utils.wrap_s(_s.rle_id)
+    end
+
+    # Group consecutive values into a struct with the given fields.
+    #
+    # Every run of `fields.length` values becomes one row, with the values
+    # assigned to the fields in order.
+    #
+    # @param fields [Array]
+    #   Names of the struct fields.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new("a", ["x", "1", "y", "2"])
+    #   s.to_struct(["name", "value"])
+    #   # =>
+    #   # shape: (2,)
+    #   # Series: 'a' [struct[2]]
+    #   # [
+    #   #         {"x","1"}
+    #   #         {"y","2"}
+    #   # ]
+    def to_struct(fields)
+      Utils.wrap_s(_s.to_struct(fields))
+    end
+
+    # Bin values into buckets and count their occurrences.
+    #
+    # Bins are right-closed, except the first bin, which also includes its
+    # lower edge. Nulls and values outside of the bins are not counted.
+    #
+    # @param bins [Array]
+    #   Bin edges. Must be strictly increasing.
+    # @param bin_count [Integer]
+    #   Number of equally sized bins between the minimum and maximum value.
+    #   Defaults to 10 if `bins` is not given either.
+    #
+    # @return [DataFrame]
+    #
+    # @example
+    #   s = Polars::Series.new("a", [1, 3, 8, 8, 2, 1, 3])
+    #   s.hist(bins: [1, 2, 3])
+    #   # =>
+    #   # shape: (2, 3)
+    #   # ┌─────────────┬────────────┬───────┐
+    #   # │ break_point ┆ category   ┆ count │
+    #   # │ ---         ┆ ---        ┆ ---   │
+    #   # │ f64         ┆ cat        ┆ u32   │
+    #   # ╞═════════════╪════════════╪═══════╡
+    #   # │ 2.0         ┆ [1.0, 2.0] ┆ 3     │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┤
+    #   # │ 3.0         ┆ (2.0, 3.0] ┆ 2     │
+    #   # └─────────────┴────────────┴───────┘
+    def hist(bins: nil, bin_count: nil)
+      bins = bins.map(&:to_f) unless bins.nil?
+      Utils.wrap_df(_s.hist(bins, bin_count))
+    end
+
     # Sort this Series.
     #
     # @param reverse [Boolean]
@@ -1276,10 +1503,19 @@ module Polars
     #
     # @param element [Object]
     #   Expression or scalar value.
+    # @param side ["any", "left", "right"]
+    #   If "any", the index of the first suitable location found is given.
+    #   If "left", the index of the leftmost suitable location found is given.
+    #   If "right", return the rightmost suitable location found is given.
     #
     # @return [Integer]
-    def search_sorted(element)
-      Polars.select(Polars.lit(self).search_sorted(element))[0, 0]
+    #
+    # @example
+    #   s = Polars::Series.new("a", [1, 2, 2, 2, 3])
+    #   s.search_sorted(2, side: "right")
+    #   # => 4
+    def search_sorted(element, side: "any")
+      Polars.select(Polars.lit(self).search_sorted(element, side: side))[0, 0]
     end
 
     # Get unique elements in series.
@@ -1655,6 +1891,9 @@ module Polars
     #   DataType to cast to
     # @param strict [Boolean]
     #   Throw an error if a cast could not be done for instance due to an overflow
+    # @param wrap_numerical [Boolean]
+    #   Wrap integers that overflow the target type instead of raising
+    #   an error or setting them to null
     #
     # @return [Series]
     #
@@ -1669,7 +1908,7 @@ module Polars
     #   #         0
     #   #         1
     #   # ]
-    def cast(dtype, strict: true)
+    def cast(dtype, strict: true, wrap_numerical: false)
       super
     end
 
@@ -1811,8 +2050,42 @@ module Polars
     # def view
     # end
 
-    # def to_numo
-    # end
+    # Convert this Series to a Numo array.
+    #
+    # Numeric data is copied from the underlying buffer without converting each
+    # element to a Ruby object.
+    #
+    # @param fill_null [Object]
+    #   Value to use for nulls in numeric and boolean Series. If not set,
+    #   an error is raised when the Series contains nulls.
+    #
+    # @return [Numo::NArray]
+    #
+    # @example
+    #   s = Polars::Series.new("a", [1, 2, 3])
+    #   s.to_numo
+    #   # =>
+    #   # Numo::Int64#shape=[3]
+    #   # [1, 2, 3]
+    def to_numo(fill_null: nil)
+      require "numo/narray"
+
+      s = self
+      if (is_numeric || is_boolean) && null_count > 0
+        if fill_null.nil?
+          raise ArgumentError, "Series contains nulls; set fill_null to convert it"
+        end
+        s = s.fill_null(fill_null)
+      end
+
+      if s.is_numeric
+        s._s.to_numo
+      elsif s.is_boolean
+        Numo::Bit.cast(s.to_a)
+      else
+        Numo::RObject.cast(s.to_a)
+      end
+    end
 
     # Set masked values.
     #
@@ -2377,10 +2650,44 @@ module Polars
       Utils.wrap_s(_s.apply_lambda(func, pl_return_dtype, skip_nulls))
     end
 
+    # Cumulatively reduce the values with a custom/user-defined function.
+    #
+    # The block is called with the accumulator and the current value and its
+    # result becomes the new accumulator. Nulls are skipped and stay null.
+    #
+    # This calls the block in Ruby for every element, so it is much slower than the
+    # built-in cumulative methods like `cumsum` and `cummax`.
+    #
+    # @param initial [Object]
+    #   Initial value of the accumulator.
+    # @param return_dtype [Symbol]
+    #   Output datatype.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new("a", [1, 2, 3, 4])
+    #   s.cum_reduce(1) { |acc, x| acc * x + 1 }
+    #   # =>
+    #   # shape: (4,)
+    #   # Series: 'a' [i64]
+    #   # [
+    #   #         2
+    #   #         5
+    #   #         16
+    #   #         65
+    #   # ]
+    def cum_reduce(initial, return_dtype: nil)
+      acc = initial
+      apply(return_dtype: return_dtype) { |x| acc = yield(acc, x) }
+    end
+
     # Shift the values by a given period.
     #
     # @param periods [Integer]
     #   Number of places to shift (may be negative).
+    # @param fill_value [Object]
+    #   Fill the resulting null values with this value or expression.
     #
     # @return [Series]
     #
@@ -2406,7 +2713,7 @@ module Polars
     #   #         3
     #   #         null
     #   # ]
-    def shift(periods = 1)
+    def shift(periods = 1, fill_value: nil)
       super
     end
 
@@ -2768,8 +3075,57 @@ module Polars
         .to_series
     end
 
-    # def rolling_apply
-    # end
+    # Apply a custom rolling window function.
+    #
+    # The block is called with each window as a Series and should return a scalar.
+    # Windows with fewer than `min_periods` non-null values give null.
+    #
+    # This calls the block in Ruby for every window, so prefer the specialized
+    # rolling methods like `rolling_sum` when possible.
+    #
+    # @param window_size [Integer]
+    #   The length of the window.
+    # @param min_periods [Integer]
+    #   The number of values in the window that should be non-null before computing
+    #   a result. If nil, it will be set equal to window size.
+    # @param center [Boolean]
+    #   Set the labels at the center of the window
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new("a", [1, 5, 2, 8, 3])
+    #   s.rolling_apply(3) { |w| w.max - w.min }
+    #   # =>
+    #   # shape: (5,)
+    #   # Series: 'a' [i64]
+    #   # [
+    #   #         null
+    #   #         null
+    #   #         4
+    #   #         6
+    #   #         6
+    #   # ]
+    def rolling_apply(window_size, min_periods: nil, center: false)
+      if min_periods.nil?
+        min_periods = window_size
+      end
+      offset = center ? window_size / 2 : 0
+
+      values =
+        len.times.map do |i|
+          start = i + offset + 1 - window_size
+          stop = [start + window_size, len].min
+          start = [start, 0].max
+          window = slice(start, stop - start)
+          if window.len - window.null_count < min_periods
+            nil
+          else
+            yield window
+          end
+        end
+      Series.new(name, values)
+    end
 
     # Compute a rolling median.
     #
@@ -3073,11 +3429,12 @@ module Polars
 
     # Reinterpret the underlying bits as a signed/unsigned integer.
     #
-    # This operation is only allowed for 64bit integers. For lower bits integers,
-    # you can safely use that cast operation.
+    # This operation is only allowed for 32bit and 64bit integers. For lower bits
+    # integers, you can safely use that cast operation.
     #
     # @param signed [Boolean]
-    #   If true, reinterpret as `:i64`. Otherwise, reinterpret as `:u64`.
+    #   If true, reinterpret as `:i64` (or `:i32`). Otherwise, reinterpret as
+    #   `:u64` (or `:u32`).
     #
     # @return [Series]
     def reinterpret(signed: true)
@@ -3189,6 +3546,9 @@ module Polars
     #
     # @param n [Integer]
     #   periods to shift for forming percent change.
+    # @param fill_method ["forward", "backward", nil]
+    #   How to fill nulls before computing the change. If `nil`, nulls are
+    #   not filled and propagate to the result.
     #
     # @return [Series]
     #
@@ -3227,7 +3587,7 @@ module Polars
     #   #         3.0
     #   #         3.0
     #   # ]
-    def pct_change(n: 1)
+    def pct_change(n: 1, fill_method: "forward")
       super
     end
 
@@ -3340,7 +3700,8 @@ module Polars
     # Shuffle the contents of this Series.
     #
     # @param seed [Integer, nil]
-    #   Seed for the random number generator.
+    #   Seed for the random number generator. The same seed always gives the
+    #   same order. Nulls are shuffled like any other value.
     #
     # @return [Series]
     #
@@ -3356,7 +3717,7 @@ module Polars
     #   #         3
     #   # ]
     def shuffle(seed: nil)
-      super
+      Utils.wrap_s(_s.shuffle(seed))
     end
 
     # Exponentially-weighted moving average.
@@ -3433,6 +3794,7 @@ module Polars
     # Flags the Series as sorted.
     #
     # Enables downstream code to user fast paths for sorted arrays.
+    # The flag is not verified. Use {#is_sorted} to check the data first.
     #
     # @param reverse [Boolean]
     #   If the Series order is reversed, e.g. descending.
@@ -3463,8 +3825,12 @@ module Polars
     # Shrink to the dtype needed to fit the extrema of this Series.
     # This can be used to reduce memory pressure.
     #
+    # @param categorical_threshold [Integer]
+    #   Convert a Utf8 Series with fewer distinct values than this to
+    #   Categorical.
+    #
     # @return [Series]
-    def shrink_dtype
+    def shrink_dtype(categorical_threshold: nil)
       super
     end
 
@@ -434,12 +434,109 @@ module Polars
       Utils.wrap_expr(_rbexpr.str_rjust(width, fillchar))
     end
 
+    # Reverse the characters of each string.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"text" => ["foo", "bar", nil, "añb"]})
+    #   df.select(Polars.col("text").str.reverse)
+    #   # =>
+    #   # shape: (4, 1)
+    #   # ┌──────┐
+    #   # │ text │
+    #   # │ ---  │
+    #   # │ str  │
+    #   # ╞══════╡
+    #   # │ oof  │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ rab  │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ null │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ bña  │
+    #   # └──────┘
+    def reverse
+      Utils.wrap_expr(_rbexpr.str_reverse)
+    end
+
+    # Pad the start of the string until it reaches the given length.
+    #
+    # Strings that are already at least `length` characters long are
+    # returned unchanged.
+    #
+    # @param length [Integer]
+    #   Pad the string until it reaches this length.
+    # @param fill_char [String]
+    #   The character to pad the string with.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => ["cow", "monkey", nil, "hippopotamus"]})
+    #   df.select(Polars.col("a").str.pad_start(8, "*"))
+    #   # =>
+    #   # shape: (4, 1)
+    #   # ┌──────────────┐
+    #   # │ a            │
+    #   # │ ---          │
+    #   # │ str          │
+    #   # ╞══════════════╡
+    #   # │ *****cow     │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ **monkey     │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ null         │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ hippopotamus │
+    #   # └──────────────┘
+    def pad_start(length, fill_char = " ")
+      Utils.wrap_expr(_rbexpr.str_pad_start(length, fill_char))
+    end
+
+    # Pad the end of the string until it reaches the given length.
+    #
+    # Strings that are already at least `length` characters long are
+    # returned unchanged.
+    #
+    # @param length [Integer]
+    #   Pad the string until it reaches this length.
+    # @param fill_char [String]
+    #   The character to pad the string with.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"a" => ["cow", "monkey", nil, "hippopotamus"]})
+    #   df.select(Polars.col("a").str.pad_end(8, "*"))
+    #   # =>
+    #   # shape: (4, 1)
+    #   # ┌──────────────┐
+    #   # │ a            │
+    #   # │ ---          │
+    #   # │ str          │
+    #   # ╞══════════════╡
+    #   # │ cow*****     │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ monkey**     │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ null         │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ hippopotamus │
+    #   # └──────────────┘
+    def pad_end(length, fill_char = " ")
+      Utils.wrap_expr(_rbexpr.str_pad_end(length, fill_char))
+    end
+
     # Check if string contains a substring that matches a regex.
     #
     # @param pattern [String]
     #   A valid regex pattern.
     # @param literal [Boolean]
     #   Treat pattern as a literal string.
+    # @param case_insensitive [Boolean]
+    #   Ignore case when matching. When combined with `literal`, only ASCII
+    #   characters are case-folded.
     #
     # @return [Expr]
     #
@@ -467,8 +564,83 @@ module Polars
     #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
     #   # │ null        ┆ null  ┆ null    │
     #   # └─────────────┴───────┴─────────┘
-    def contains(pattern, literal: false)
-      Utils.wrap_expr(_rbexpr.str_contains(pattern, literal))
+    def contains(pattern, literal: false, case_insensitive: false)
+      Utils.wrap_expr(_rbexpr.str_contains(pattern, literal, case_insensitive))
+    end
+
+    # Check if string values contain any of the given literal patterns.
+    #
+    # @param patterns [Array]
+    #   Literal substrings to look for.
+    # @param ascii_case_insensitive [Boolean]
+    #   Match ASCII characters regardless of their case.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"text" => ["Tiger was here", "Cat and dog", "no pets", nil]})
+    #   df.with_column(
+    #     Polars.col("text").str.contains_any(["cat", "dog"]).alias("has_pet")
+    #   )
+    #   # =>
+    #   # shape: (4, 2)
+    #   # ┌────────────────┬─────────┐
+    #   # │ text           ┆ has_pet │
+    #   # │ ---            ┆ ---     │
+    #   # │ str            ┆ bool    │
+    #   # ╞════════════════╪═════════╡
+    #   # │ Tiger was here ┆ false   │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+    #   # │ Cat and dog    ┆ true    │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+    #   # │ no pets        ┆ false   │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌┤
+    #   # │ null           ┆ null    │
+    #   # └────────────────┴─────────┘
+    def contains_any(patterns, ascii_case_insensitive: false)
+      Utils.wrap_expr(_rbexpr.str_contains_any(patterns, ascii_case_insensitive))
+    end
+
+    # Replace many literal patterns at once.
+    #
+    # The string is scanned a single time, so a replacement is never matched
+    # by another pattern.
+    #
+    # @param patterns [Object]
+    #   Literal substrings to replace. Can also be a Hash mapping patterns to
+    #   replacements.
+    # @param replacements [Object]
+    #   Replacements for each pattern, or a single string to use for all of them.
+    # @param ascii_case_insensitive [Boolean]
+    #   Match ASCII characters regardless of their case.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"text" => ["Tiger here", "Cat and dog", nil]})
+    #   df.with_column(
+    #     Polars.col("text").str.replace_many({"tiger" => "Lion", "cat" => "Lion", "dog" => "cat"}, ascii_case_insensitive: true).alias("replaced")
+    #   )
+    #   # =>
+    #   # shape: (3, 2)
+    #   # ┌─────────────┬──────────────┐
+    #   # │ text        ┆ replaced     │
+    #   # │ ---         ┆ ---          │
+    #   # │ str         ┆ str          │
+    #   # ╞═════════════╪══════════════╡
+    #   # │ Tiger here  ┆ Lion here    │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ Cat and dog ┆ Lion and cat │
+    #   # ├╌╌╌╌╌╌╌╌╌╌╌╌╌┼╌╌╌╌╌╌╌╌╌╌╌╌╌╌┤
+    #   # │ null        ┆ null         │
+    #   # └─────────────┴──────────────┘
+    def replace_many(patterns, replacements = nil, ascii_case_insensitive: false)
+      if patterns.is_a?(Hash) && replacements.nil?
+        replacements = patterns.values
+        patterns = patterns.keys
+      end
+      replacements = [replacements] if replacements.is_a?(String)
+      Utils.wrap_expr(_rbexpr.str_replace_many(patterns, replacements, ascii_case_insensitive))
     end
 
     # Check if string values end with a substring.
@@ -630,6 +802,36 @@ module Polars
       end
     end
 
+    # Convert a Utf8 column into an Int64 column using the given base.
+    #
+    # @param base [Integer]
+    #   Radix to use when parsing, between 2 and 36.
+    # @param strict [Boolean]
+    #   Raise an error if a value cannot be parsed. Otherwise, such values
+    #   become `nil`.
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"hex" => ["ff", "1a", nil]})
+    #   df.select(Polars.col("hex").str.to_integer(base: 16))
+    #   # =>
+    #   # shape: (3, 1)
+    #   # ┌──────┐
+    #   # │ hex  │
+    #   # │ ---  │
+    #   # │ i64  │
+    #   # ╞══════╡
+    #   # │ 255  │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ 26   │
+    #   # ├╌╌╌╌╌╌┤
+    #   # │ null │
+    #   # └──────┘
+    def to_integer(base: 10, strict: true)
+      Utils.wrap_expr(_rbexpr.str_to_integer(base, strict))
+    end
+
     # Encode a value using the provided encoding.
     #
     # @param encoding ["hex", "base64"]
@@ -696,6 +898,38 @@ module Polars
       Utils.wrap_expr(_rbexpr.str_extract(pattern, group_index))
     end
 
+    # Extract all capture groups for the given regex pattern.
+    #
+    # Each capture group becomes a field of the resulting struct. Named groups
+    # keep their name and unnamed groups are named by their index, starting at 1.
+    #
+    # @param pattern [String]
+    #   A valid regex pattern with at least one capture group
+    #
+    # @return [Expr]
+    #
+    # @example
+    #   df = Polars::DataFrame.new({"date" => ["2023-01-15", "2024-12-03", "n/a"]})
+    #   df.select(
+    #     Polars.col("date").str.extract_groups('(?<year>\d{4})-(?<month>\d{2})-(?<day>\d{2})')
+    #   ).unnest("date")
+    #   # =>
+    #   # shape: (3, 3)
+    #   # ┌──────┬───────┬──────┐
+    #   # │ year ┆ month ┆ day  │
+    #   # │ ---  ┆ ---   ┆ ---  │
+    #   # │ str  ┆ str   ┆ str  │
+    #   # ╞══════╪═══════╪══════╡
+    #   # │ 2023 ┆ 01    ┆ 15   │
+    #   # ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌┤
+    #   # │ 2024 ┆ 12    ┆ 03   │
+    #   # ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌┤
+    #   # │ null ┆ null  ┆ null │
+    #   # └──────┴───────┴──────┘
+    def extract_groups(pattern)
+      Utils.wrap_expr(_rbexpr.str_extract_groups(pattern))
+    end
+
     # Extracts all matches for the given regex pattern.
     #
     # Extracts each successive non-overlapping regex match in an individual string as
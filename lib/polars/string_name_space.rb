@@ -85,7 +85,7 @@ module Polars
     #   #         6
     #   # ]
     def lengths
-      super
+      Utils.wrap_s(_s.str_lengths)
     end
 
     # Get length of the string values in the Series (as number of chars).
@@ -132,6 +132,9 @@ module Polars
     #   A valid regex pattern.
     # @param literal [Boolean]
     #   Treat pattern as a literal string.
+    # @param case_insensitive [Boolean]
+    #   Ignore case when matching. When combined with `literal`, only ASCII
+    #   characters are case-folded.
     #
     # @return [Series]
     #
@@ -159,7 +162,59 @@ module Polars
     #   #         true
     #   #         null
     #   # ]
-    def contains(pattern, literal: false)
+    def contains(pattern, literal: false, case_insensitive: false)
+      Utils.wrap_s(_s.str_contains(pattern, literal, case_insensitive))
+    end
+
+    # Check if string values contain any of the given literal patterns.
+    #
+    # @param patterns [Array]
+    #   Literal substrings to look for.
+    # @param ascii_case_insensitive [Boolean]
+    #   Match ASCII characters regardless of their case.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new(["Tiger was here", "Cat and dog", "no pets", nil])
+    #   s.str.contains_any(["cat", "tiger"], ascii_case_insensitive: true)
+    #   # =>
+    #   # shape: (4,)
+    #   # Series: '' [bool]
+    #   # [
+    #   #         true
+    #   #         true
+    #   #         false
+    #   #         null
+    #   # ]
+    def contains_any(patterns, ascii_case_insensitive: false)
+      super
+    end
+
+    # Replace many literal patterns at once.
+    #
+    # @param patterns [Object]
+    #   Literal substrings to replace. Can also be a Hash mapping patterns to
+    #   replacements.
+    # @param replacements [Object]
+    #   Replacements for each pattern, or a single string to use for all of them.
+    # @param ascii_case_insensitive [Boolean]
+    #   Match ASCII characters regardless of their case.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new(["abc", "bca", nil])
+    #   s.str.replace_many({"a" => "1", "b" => "2"})
+    #   # =>
+    #   # shape: (3,)
+    #   # Series: '' [str]
+    #   # [
+    #   #         "12c"
+    #   #         "2c1"
+    #   #         null
+    #   # ]
+    def replace_many(patterns, replacements = nil, ascii_case_insensitive: false)
       super
     end
 
@@ -234,6 +289,31 @@ module Polars
       super
     end
 
+    # Convert a Utf8 Series into an Int64 Series using the given base.
+    #
+    # @param base [Integer]
+    #   Radix to use when parsing, between 2 and 36.
+    # @param strict [Boolean]
+    #   Raise an error if a value cannot be parsed. Otherwise, such values
+    #   become `nil`.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new("bin", ["110", "101", nil])
+    #   s.str.to_integer(base: 2)
+    #   # =>
+    #   # shape: (3,)
+    #   # Series: 'bin' [i64]
+    #   # [
+    #   #         6
+    #   #         5
+    #   #         null
+    #   # ]
+    def to_integer(base: 10, strict: true)
+      super
+    end
+
     # Encode a value using the provided encoding.
     #
     # @param encoding ["hex", "base64"]
@@ -317,6 +397,36 @@ module Polars
       super
     end
 
+    # Extract all capture groups for the given regex pattern.
+    #
+    # Each capture group becomes a field of the resulting struct. Named groups
+    # keep their name and unnamed groups are named by their index, starting at 1.
+    #
+    # @param pattern [String]
+    #   A valid regex pattern with at least one capture group
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new("date", ["2023-01-15", "2024-12-03", "n/a"])
+    #   s.str.extract_groups('(?<year>\d{4})-(?<month>\d{2})-(?<day>\d{2})').struct.to_frame
+    #   # =>
+    #   # shape: (3, 3)
+    #   # ┌──────┬───────┬──────┐
+    #   # │ year ┆ month ┆ day  │
+    #   # │ ---  ┆ ---   ┆ ---  │
+    #   # │ str  ┆ str   ┆ str  │
+    #   # ╞══════╪═══════╪══════╡
+    #   # │ 2023 ┆ 01    ┆ 15   │
+    #   # ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌┤
+    #   # │ 2024 ┆ 12    ┆ 03   │
+    #   # ├╌╌╌╌╌╌┼╌╌╌╌╌╌╌┼╌╌╌╌╌╌┤
+    #   # │ null ┆ null  ┆ null │
+    #   # └──────┴───────┴──────┘
+    def extract_groups(pattern)
+      super
+    end
+
     # Extracts all matches for the given regex pattern.
     #
     # Extract each successive non-overlapping regex match in an individual string as
@@ -634,18 +744,94 @@ module Polars
       super
     end
 
+    # Reverse the characters of each string.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new("text", ["foo", "bar", nil, "añb"])
+    #   s.str.reverse
+    #   # =>
+    #   # shape: (4,)
+    #   # Series: 'text' [str]
+    #   # [
+    #   #         "oof"
+    #   #         "rab"
+    #   #         null
+    #   #         "bña"
+    #   # ]
+    def reverse
+      super
+    end
+
+    # Pad the start of the string until it reaches the given length.
+    #
+    # Strings that are already at least `length` characters long are
+    # returned unchanged.
+    #
+    # @param length [Integer]
+    #   Pad the string until it reaches this length.
+    # @param fill_char [String]
+    #   The character to pad the string with.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new("a", ["cow", "monkey", nil, "hippopotamus"])
+    #   s.str.pad_start(8, "*")
+    #   # =>
+    #   # shape: (4,)
+    #   # Series: 'a' [str]
+    #   # [
+    #   #         "*****cow"
+    #   #         "**monkey"
+    #   #         null
+    #   #         "hippopotamus"
+    #   # ]
+    def pad_start(length, fill_char = " ")
+      super
+    end
+
+    # Pad the end of the string until it reaches the given length.
+    #
+    # Strings that are already at least `length` characters long are
+    # returned unchanged.
+    #
+    # @param length [Integer]
+    #   Pad the string until it reaches this length.
+    # @param fill_char [String]
+    #   The character to pad the string with.
+    #
+    # @return [Series]
+    #
+    # @example
+    #   s = Polars::Series.new("a", ["cow", "monkey", nil, "hippopotamus"])
+    #   s.str.pad_end(8, "*")
+    #   # =>
+    #   # shape: (4,)
+    #   # Series: 'a' [str]
+    #   # [
+    #   #         "cow*****"
+    #   #         "monkey**"
+    #   #         null
+    #   #         "hippopotamus"
+    #   # ]
+    def pad_end(length, fill_char = " ")
+      super
+    end
+
     # Modify the strings to their lowercase equivalent.
     #
     # @return [Series]
     def to_lowercase
-      super
+      Utils.wrap_s(_s.str_to_lowercase)
     end
 
     # Modify the strings to their uppercase equivalent.
     #
     # @return [Series]
     def to_uppercase
-      super
+      Utils.wrap_s(_s.str_to_uppercase)
     end
 
     # Create subslices of the string values of a Utf8 Series.
@@ -683,8 +869,7 @@ module Polars
     #   #         "onf"
     #   # ]
     def slice(offset, length = nil)
-      s = Utils.wrap_s(_s)
-      s.to_frame.select(Polars.col(s.name).str.slice(offset, length)).to_series
+      Utils.wrap_s(_s.str_slice(offset, length))
     end
   end
 end
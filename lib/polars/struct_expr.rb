@@ -25,7 +25,8 @@ module Polars
     # Retrieve one of the fields of this `Struct` as a new Series.
     #
     # @param name [String]
-    #   Name of the field
+    #   Name of the field. A dotted path such as `"a.b"` retrieves a field
+    #   of a nested struct, unless there is a field named exactly `"a.b"`.
     #
     # @return [Expr]
     #
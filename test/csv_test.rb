@@ -66,6 +66,20 @@ class CsvTest < Minitest::Test
     assert_frame expected, df.collect
   end
 
+  def test_scan_csv_streaming
+    path = temp_path
+    File.open(path, "w") do |f|
+      f.puts "a,b"
+      100_000.times do |i|
+        f.puts "#{i % 10},#{i}"
+      end
+    end
+    lf = Polars.scan_csv(path).groupby("a").agg(Polars.col("b").sum).sort("a")
+    df = lf.collect_streaming
+    assert_equal 10, df.height
+    assert_frame lf.collect, df
+  end
+
   def test_write_csv
     df = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => ["one", "two", "three"]})
     path = temp_path
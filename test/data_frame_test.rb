@@ -20,6 +20,38 @@ class DataFrameTest < Minitest::Test
     assert_equal ["a", "b"], df.columns
   end
 
+  def test_new_hashes
+    df = Polars::DataFrame.new([{"a" => 1, "b" => "one"}, {a: 2, c: true}])
+    assert_equal ["a", "b", "c"], df.columns
+    assert_series [1, 2], df["a"], dtype: :i64
+    assert_series ["one", nil], df["b"], dtype: :str
+    assert_series [nil, true], df["c"], dtype: :bool
+  end
+
+  def test_from_hashes_schema
+    data = [{"a" => 1, "b" => nil}, {"a" => 2, "b" => nil}, {"a" => 3, "b" => 1.5}]
+    df = Polars.from_hashes(data, infer_schema_length: 2, schema: {"b" => :f64, "c" => :i32})
+    assert_equal ["a", "b", "c"], df.columns
+    assert_series [1, 2, 3], df["a"], dtype: :i64
+    assert_series [nil, nil, 1.5], df["b"], dtype: :f64
+    assert_series [nil, nil, nil], df["c"], dtype: :i32
+
+    df = Polars.from_hashes(data, schema: {a: :unk})
+    assert_equal ({"a" => :i64, "b" => :f64}), df.schema
+  end
+
+  def test_from_hashes_infer_schema_length
+    data = [{"a" => 1}, {"a" => 2.5}, {"a" => 3, "b" => "x"}]
+    df = Polars.from_hashes(data, infer_schema_length: nil)
+    assert_series [1.0, 2.5, 3.0], df["a"], dtype: :f64
+    assert_series [nil, nil, "x"], df["b"], dtype: :str
+
+    df = Polars.from_hashes(data, infer_schema_length: 1)
+    assert_equal ["a", "b"], df.columns
+    assert_equal :i64, df["a"].dtype
+    assert_series [nil, nil, "x"], df["b"], dtype: :str
+  end
+
   def test_new_series
     df = Polars::DataFrame.new(Polars::Series.new("a", [1, 2, 3]))
     expected = Polars::DataFrame.new({"a" => [1, 2, 3]})
@@ -126,6 +158,16 @@ class DataFrameTest < Minitest::Test
     assert_equal data, df.to_h(as_series: false)
   end
 
+  def test_to_hashes
+    df = Polars::DataFrame.new({"a" => [1, 2], "b" => ["one", "two"], "c" => [[1, 2], []]})
+    df = df.with_column(Polars.struct(["a", "b"]).alias("d"))
+    expected = [
+      {"a" => 1, "b" => "one", "c" => [1, 2], "d" => {"a" => 1, "b" => "one"}},
+      {"a" => 2, "b" => "two", "c" => [], "d" => {"a" => 2, "b" => "two"}}
+    ]
+    assert_equal expected, df.to_hashes
+  end
+
   def test_to_series
     df = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => ["one", "two", "three"]})
     assert_series df["a"], df.to_series
@@ -212,6 +254,81 @@ class DataFrameTest < Minitest::Test
   def test_join
   end
 
+  def test_join_asof_tolerance_str
+    trades = Polars::DataFrame.new({
+      "time" => ["2023-01-01 09:00:05", "2023-01-01 09:00:30", "2023-01-01 09:01:00"],
+      "trade" => [1, 2, 3]
+    }).with_column(Polars.col("time").str.strptime(:datetime, "%Y-%m-%d %H:%M:%S"))
+    quotes = Polars::DataFrame.new({
+      "time" => ["2023-01-01 09:00:00", "2023-01-01 09:00:15", "2023-01-01 09:00:55"],
+      "quote" => [10, 20, 30]
+    }).with_column(Polars.col("time").str.strptime(:datetime, "%Y-%m-%d %H:%M:%S"))
+
+    out = trades.join_asof(quotes, on: "time", tolerance: "10s")
+    assert_series [1, 2, 3], out["trade"]
+    assert_series [10, nil, 30], out["quote"]
+
+    error = assert_raises(ArgumentError) do
+      trades.join_asof(quotes, on: "time", tolerance: 10)
+    end
+    assert_match "must be a duration string", error.message
+
+    error = assert_raises(ArgumentError) do
+      trades.join_asof(quotes, on: "time", tolerance: "10x")
+    end
+    assert_equal "invalid duration string: '10x'", error.message
+  end
+
+  def test_join_semi_anti
+    df = Polars::DataFrame.new({"id" => [1, 2, 3, 4], "a" => ["w", "x", "y", "z"]})
+    other = Polars::DataFrame.new({"id" => [2, 4, 4, 5], "b" => [1, 2, 3, 4]})
+
+    out = df.join(other, on: "id", how: "semi")
+    assert_equal ["id", "a"], out.columns
+    assert_series [2, 4], out["id"]
+
+    out = df.join(other, on: "id", how: "anti")
+    assert_equal ["id", "a"], out.columns
+    assert_series [1, 3], out["id"]
+
+    out = df.lazy.join(other.lazy, on: "id", how: "semi", coalesce: false).collect
+    assert_equal ["id", "a"], out.columns
+    assert_series [2, 4], out["id"]
+  end
+
+  def test_join_cross
+    df = Polars::DataFrame.new({"a" => [1, 2, 3]})
+    other = Polars::DataFrame.new({"b" => ["x", "y"]})
+    out = df.join(other, how: "cross")
+    assert_equal [6, 2], out.shape
+    assert_series [1, 1, 2, 2, 3, 3], out["a"]
+    assert_series ["x", "y", "x", "y", "x", "y"], out["b"]
+
+    error = assert_raises(ArgumentError) do
+      df.join(other, on: "a", how: "cross")
+    end
+    assert_equal "cross join does not take join keys", error.message
+  end
+
+  def test_join_coalesce
+    df = Polars::DataFrame.new({"key" => [1, 2], "a" => ["x", "y"]})
+    other = Polars::DataFrame.new({"key" => [2, 3], "b" => ["p", "q"]})
+
+    out = df.join(other, on: "key", how: "outer")
+    assert_equal ["key", "a", "b"], out.columns
+    assert_series [1, 2, 3], out["key"].sort
+
+    out = df.join(other, on: "key", how: "outer", coalesce: false)
+    assert_equal ["key", "a", "key_right", "b"], out.columns
+    assert_series [1, 2], out["key"].drop_nulls.sort
+    assert_series [2, 3], out["key_right"].drop_nulls.sort
+    assert_equal 1, out["key"].null_count
+
+    out = df.join(other, on: "key", coalesce: false)
+    assert_equal ["key", "a", "key_right", "b"], out.columns
+    assert_equal [[2, "y", 2, "p"]], out.rows
+  end
+
   def test_with_column
   end
 
@@ -306,4 +423,281 @@ class DataFrameTest < Minitest::Test
 
   def test_null_count
   end
+
+  def test_shrink_dtype
+    df = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => ["x", "y", "x"], "c" => [true, false, nil]})
+    assert_equal [:i8, :cat, :bool], df.shrink_dtype(categorical_threshold: 10).dtypes
+
+    lf = df.lazy.select(Polars.col("b").shrink_dtype(categorical_threshold: 10))
+    assert_equal({"b" => :unknown}, lf.schema)
+    assert_equal [:cat], lf.collect.dtypes
+
+    shrunk, report = df.shrink_dtype(report: true)
+    assert_equal [:i8, :str, :bool], shrunk.dtypes
+    assert_operator report["after"], :<, report["before"]
+  end
+
+  def test_iter_columns
+    df = Polars::DataFrame.new({"a" => [1, 2], "b" => [3, 4]})
+    names = []
+    df.iter_columns { |s| names << s.name }
+    assert_equal ["a", "b"], names
+  end
+
+  def test_sort_by_over
+    df = Polars::DataFrame.new({"g" => ["a", "a", "b", "b"], "t" => [2, 1, 4, 3], "v" => [10, 20, 30, 40]})
+    out = df.select(Polars.col("v").sort_by("t").first.over("g"))
+    assert_series [20, 20, 40, 40], out["v"]
+
+    out = df.groupby("g", maintain_order: true).agg(Polars.col("v").sort_by(["t", "v"]).first)
+    assert_series [20, 40], out["v"]
+  end
+
+  def test_set_sorted
+    df = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => [3, 1, 2]})
+    assert df.set_sorted("a")["a"].flags["SORTED_ASC"]
+    assert df.set_sorted("a", reverse: true)["a"].flags["SORTED_DESC"]
+    assert df.set_sorted("a", verify: true)["a"].flags["SORTED_ASC"]
+
+    error = assert_raises(ArgumentError) do
+      df.set_sorted("b", verify: true)
+    end
+    assert_equal "column 'b' is not sorted", error.message
+  end
+
+  def test_sample_by
+    df = Polars::DataFrame.new({"label" => ["a"] * 10 + ["b"] * 3 + ["c"] * 1, "x" => 0...14})
+    out = df.sample(n: 2, by: "label", seed: 1)
+    assert_equal({"a" => 2, "b" => 2, "c" => 1}, out["label"].to_a.tally)
+
+    out = df.sample(n: 2, by: "label", with_replacement: true, seed: 1)
+    assert_equal({"a" => 2, "b" => 2, "c" => 2}, out["label"].to_a.tally)
+
+    out = df.sample(frac: 0.5, by: "label", seed: 1)
+    assert_equal({"a" => 5, "b" => 1}, out["label"].to_a.tally)
+  end
+
+  def test_shuffle
+    df = Polars::DataFrame.new({"a" => 1..100, "b" => (1..100).map(&:to_s)})
+    out = df.shuffle(seed: 42)
+    assert_equal df.shuffle(seed: 42)["a"].to_a, out["a"].to_a
+    refute_equal df["a"].to_a, out["a"].to_a
+    assert_equal df["a"].to_a, out["a"].sort.to_a
+    assert_equal out["a"].to_a.map(&:to_s), out["b"].to_a
+  end
+
+  def test_pivot_count
+    df = Polars::DataFrame.new({"foo" => ["one", "one", "two", "one"], "bar" => ["B", "A", "B", "B"], "baz" => [1, 2, 3, 4]})
+    out = df.pivot(values: "baz", index: "foo", columns: "bar", aggregate_fn: "count", sort_columns: true)
+    assert_equal ["foo", "A", "B"], out.columns
+    assert_series [1, 0], out["A"]
+    assert_series [2, 1], out["B"]
+  end
+
+  def test_pivot_multiple_aggregations
+    df = Polars::DataFrame.new({"foo" => ["one", "one", "two", "one"], "bar" => ["B", "A", "B", "B"], "baz" => [1, 2, 3, 4]})
+    out = df.pivot(values: "baz", index: "foo", columns: "bar", aggregate_fn: ["sum", "count"], sort_columns: true)
+    assert_equal ["foo", "A_sum", "B_sum", "A_count", "B_count"], out.columns
+    assert_series [2, nil], out["A_sum"]
+    assert_series [5, 3], out["B_sum"]
+    assert_series [1, 0], out["A_count"]
+    assert_series [2, 1], out["B_count"]
+
+    out = df.pivot(values: "baz", index: "foo", columns: "bar", aggregate_fn: {"total" => Polars.element.sum}, sort_columns: true)
+    assert_equal ["foo", "A_total", "B_total"], out.columns
+    assert_series [5, 3], out["B_total"]
+
+    out = df.pivot(values: "baz", index: "foo", columns: "bar", aggregate_fn: ["sum"], sort_columns: true)
+    assert_equal ["foo", "A_sum", "B_sum"], out.columns
+
+    error = assert_raises(ArgumentError) do
+      df.pivot(values: "baz", index: "foo", columns: "bar", aggregate_fn: [Polars.element.sum])
+    end
+    assert_equal "aggregate_fn array must contain function names, use a hash to name expressions", error.message
+  end
+
+  def test_unpivot
+    df = Polars::DataFrame.new({"sensor" => ["x", "y"], "measure_a" => [1, 3], "measure_b" => [2, 4], "note" => [1.5, 2.5]})
+    expected = df.melt(id_vars: "sensor", value_vars: ["measure_a", "measure_b"], variable_name: "measure", value_name: "reading")
+    assert_frame expected, df.unpivot(["measure_a", "measure_b"], index: "sensor", variable_name: "measure", value_name: "reading")
+    assert_frame expected, df.unpivot(Polars.col("^measure_.*$"), index: "sensor", variable_name: "measure", value_name: "reading")
+    assert_frame df.melt(id_vars: "sensor"), df.unpivot(index: "sensor")
+  end
+
+  def test_melt_regex
+    df = Polars::DataFrame.new({"sensor" => ["x", "y"], "measure_a" => [1, 3], "measure_b" => [2, 4], "note" => [1.5, 2.5]})
+    out = df.melt(value_vars: "^measure_.*$")
+    assert_equal ["sensor", "note", "variable", "value"], out.columns
+    assert_series ["measure_a", "measure_a", "measure_b", "measure_b"], out["variable"]
+
+    out = df.melt(id_vars: "sensor", value_vars: :f64)
+    assert_equal ["sensor", "variable", "value"], out.columns
+    assert_series ["note", "note"], out["variable"]
+
+    error = assert_raises(ArgumentError) do
+      df.melt(value_vars: "^missing_.*$")
+    end
+    assert_equal "no columns matched value_vars", error.message
+  end
+
+  def test_transpose_batches
+    n = 25_000
+    df = Polars::DataFrame.new({"a" => n.times.to_a, "b" => n.times.map { |i| -i }})
+    out = df.transpose(include_header: true)
+    assert_equal [2, n + 1], out.shape
+    assert_equal "column_#{n - 1}", out.columns.last
+    assert_series ["a", "b"], out["column"]
+    assert_series [10_000, -10_000], out["column_10000"]
+    assert_series [n - 1, 1 - n], out["column_#{n - 1}"]
+  end
+
+  def test_to_arrow
+    require "arrow"
+
+    df = Polars::DataFrame.new({"a" => [1, 2], "b" => ["one", "two"]})
+    df = df.vstack(Polars::DataFrame.new({"a" => [3], "b" => [nil]}))
+    batches = df.to_arrow
+    assert_equal 2, batches.size
+    assert_kind_of Arrow::RecordBatch, batches.first
+    assert_equal ["a", "b"], batches.first.schema.fields.map(&:name)
+    assert_frame df, Polars.from_arrow(batches)
+    assert_frame df.head(2), Polars.from_arrow(batches.first)
+  end
+
+  def test_to_arrow_unaligned_chunks
+    require "arrow"
+
+    a = Polars::Series.new("a", [1, 2]).append(Polars::Series.new("a", [3]))
+    b = Polars::Series.new("b", ["x"]).append(Polars::Series.new("b", ["y", "z"]))
+    df = Polars::DataFrame.new([a, b])
+    assert_equal [2], df.n_chunks(strategy: "all").uniq
+    batches = df.to_arrow
+    assert_equal 1, batches.size
+    assert_frame df, Polars.from_arrow(batches)
+  end
+
+  def test_to_numo
+    df = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => [4.5, 5.5, 6.5], "c" => [7, 8, 9]})
+    out = df.to_numo
+    assert_kind_of Numo::DFloat, out
+    assert_equal [3, 3], out.shape
+    df.columns.each_with_index do |c, i|
+      assert_equal df[c].cast(:f64).to_a, out[true, i].to_a
+    end
+    assert_equal out.to_a, df.to_numo(order: "fortran").to_a
+
+    assert_kind_of Numo::Int64, df.select(["a", "c"]).to_numo
+  end
+
+  def test_to_numo_invalid
+    df = Polars::DataFrame.new({"a" => [1, 2], "b" => ["x", "y"]})
+    error = assert_raises(TypeError) do
+      df.to_numo
+    end
+    assert_equal "cannot convert column 'b' of dtype str to Numo", error.message
+
+    df = Polars::DataFrame.new({"a" => [1, nil]})
+    error = assert_raises(ArgumentError) do
+      df.to_numo
+    end
+    assert_equal "DataFrame contains nulls", error.message
+  end
+
+  def test_with_row_count
+    df = Polars::DataFrame.new({"a" => [1, 2, 3]})
+    assert_series [5, 6, 7], df.with_row_count(offset: 5)["row_nr"], dtype: :u32
+
+    out = df.with_row_count(offset: 2**32, dtype: :u64)
+    assert_series [2**32, 2**32 + 1, 2**32 + 2], out["row_nr"], dtype: :u64
+
+    error = assert_raises(ArgumentError) do
+      df.with_row_count(offset: 2**32 - 2)
+    end
+    assert_equal "row count offset 4294967294 overflows u32", error.message
+
+    assert_raises(ArgumentError) do
+      df.with_row_count(dtype: :i64)
+    end
+  end
+
+  def test_explode_empty_as_null
+    df = Polars::DataFrame.new({"a" => ["x", "y", "z"], "b" => [[1, 2], [], nil]})
+    out = df.explode("b")
+    assert_series ["x", "x", "y", "z"], out["a"]
+    assert_series [1, 2, nil, nil], out["b"]
+
+    out = df.explode("b", empty_as_null: false)
+    assert_series ["x", "x", "z"], out["a"]
+    assert_series [1, 2, nil], out["b"]
+  end
+
+  def test_extend_mismatch
+    df = Polars::DataFrame.new({"a" => [1], "b" => ["x"]})
+
+    error = assert_raises(ArgumentError) do
+      df.extend(Polars::DataFrame.new({"a" => [2]}))
+    end
+    assert_equal "cannot extend DataFrame of width 2 with DataFrame of width 1", error.message
+
+    error = assert_raises(ArgumentError) do
+      df.extend(Polars::DataFrame.new({"a" => [2], "c" => ["y"]}))
+    end
+    assert_equal "cannot extend: expected column 'b', got 'c'", error.message
+
+    error = assert_raises(TypeError) do
+      df.extend(Polars::DataFrame.new({"a" => [2], "b" => [3]}))
+    end
+    assert_equal "cannot extend: column 'b' has dtype str, got i64", error.message
+
+    df.extend(df)
+    assert_equal 2, df.height
+  end
+
+  def test_vstack_coerce
+    df1 = Polars::DataFrame.new({"a" => Polars::Series.new([1, 2], dtype: :i32), "b" => ["x", "y"]})
+    df2 = Polars::DataFrame.new({"a" => [3.5], "b" => ["z"]})
+    out = df1.vstack(df2, coerce: true)
+    assert_series [1.0, 2.0, 3.5], out["a"], dtype: :f64
+    assert_series ["x", "y", "z"], out["b"]
+
+    df1.vstack(Polars::DataFrame.new({"a" => [3], "b" => ["z"]}), in_place: true, coerce: true)
+    assert_series [1, 2, 3], df1["a"], dtype: :i64
+
+    error = assert_raises(TypeError) do
+      df1.vstack(Polars::DataFrame.new({"a" => [4], "b" => [5]}), coerce: true)
+    end
+    assert_equal "cannot vstack column 'b': incompatible dtypes str and i64", error.message
+
+    assert_raises(TypeError) do
+      df1.vstack(Polars::DataFrame.new({"a" => [4.5], "b" => [5]}), in_place: true, coerce: true)
+    end
+    assert_series [1, 2, 3], df1["a"], dtype: :i64
+  end
+
+  def test_hstack_suffix
+    df = Polars::DataFrame.new({"a" => [1, 2], "b" => [3, 4]})
+    assert_raises(RuntimeError) do
+      df.hstack([Polars::Series.new("a", [5, 6])])
+    end
+
+    out = df.hstack([Polars::Series.new("a", [5, 6]), Polars::Series.new("c", [7, 8])], suffix: "_right")
+    assert_equal ["a", "b", "a_right", "c"], out.columns
+    assert_series [5, 6], out["a_right"]
+
+    df.hstack([Polars::Series.new("b", [9, 10])], in_place: true, suffix: "_2")
+    assert_equal ["a", "b", "b_2"], df.columns
+  end
+
+  def test_group_by_map
+    df = Polars::DataFrame.new({"g" => ["b", "a", "b", "a", "b"], "x" => [5, 2, 1, 4, 3]})
+    out = df.group_by_map("g") { |gdf| gdf.with_column(Polars.col("x").rank(method: "ordinal").alias("rank")) }
+    assert_series ["b", "b", "b", "a", "a"], out["g"]
+    assert_series [5, 1, 3, 2, 4], out["x"]
+    assert_series [3, 1, 2, 1, 2], out["rank"], dtype: :u32
+
+    error = assert_raises(ArgumentError) do
+      df.group_by_map("g") { |gdf| gdf["g"][0] == "a" ? gdf.select("x") : gdf }
+    end
+    assert_match "groups returned frames with different schemas", error.message
+  end
 end
@@ -1,6 +1,19 @@
 require_relative "test_helper"
 
 class DateTimeExprTest < Minitest::Test
+  def test_round
+    assert_expr dt_expr.round("1h", offset: "30m")
+
+    s = Polars.date_range(DateTime.new(2001, 1, 1), DateTime.new(2001, 1, 1, 1, 10), "10m")
+    out = s.dt.round("1h", offset: "30m")
+    assert_series ["00:30"] * 6 + ["01:30"] * 2, out.dt.strftime("%H:%M")
+
+    error = assert_raises(ArgumentError) do
+      dt_expr.round("1h", offset: "5x")
+    end
+    assert_equal "invalid duration string: '5x'", error.message
+  end
+
   def test_strftime
     assert_expr dt_expr.strftime("fmt")
   end
@@ -9,6 +22,13 @@ class DateTimeExprTest < Minitest::Test
     assert_expr dt_expr.year
   end
 
+  def test_is_leap_year
+    assert_expr dt_expr.is_leap_year
+
+    s = Polars::Series.new([Date.new(1900, 1, 1), Date.new(2000, 6, 1), Date.new(2020, 2, 29), Date.new(2021, 12, 31), nil])
+    assert_series [false, true, true, false, nil], s.dt.is_leap_year
+  end
+
   def test_iso_year
     assert_expr dt_expr.iso_year
   end
@@ -123,6 +143,97 @@ class DateTimeExprTest < Minitest::Test
     assert_expr dt_expr.offset_by("1y")
   end
 
+  def test_month_start
+    assert_expr dt_expr.month_start
+  end
+
+  def test_month_end
+    assert_expr dt_expr.month_end
+  end
+
+  def test_combine
+    assert_expr dt_expr.combine(Time.utc(2000, 1, 1, 9, 30))
+
+    df = Polars::DataFrame.new({"d" => [Date.new(2024, 1, 1), Date.new(2024, 2, 29), nil]})
+    out = df.select([
+      Polars.col("d").dt.combine(Time.utc(2000, 1, 1, 9, 30)).alias("us"),
+      Polars.col("d").dt.combine(Time.utc(2000, 1, 1, 9, 30), "ms").alias("ms")
+    ])
+    assert_equal :"datetime[μs]", out["us"].dtype
+    assert_equal :"datetime[ms]", out["ms"].dtype
+    assert_series ["2024-01-01 09:30:00", "2024-02-29 09:30:00", nil], out["us"].dt.strftime("%Y-%m-%d %H:%M:%S")
+    assert_series ["2024-01-01 09:30:00", "2024-02-29 09:30:00", nil], out["ms"].dt.strftime("%Y-%m-%d %H:%M:%S")
+
+    df = Polars::DataFrame.new({"d" => [Date.new(2024, 1, 1), Date.new(2024, 1, 2)], "t" => [34_200_000_000_000, 0]})
+    out = df.select(Polars.col("d").dt.combine(Polars.col("t").cast(:time)))
+    assert_series ["2024-01-01 09:30:00", "2024-01-02 00:00:00"], out["d"].dt.strftime("%Y-%m-%d %H:%M:%S")
+
+    error = assert_raises(RuntimeError) do
+      df.select(Polars.col("d").dt.combine(-1))
+    end
+    assert_match "time must be within a day", error.message
+
+    error = assert_raises(RuntimeError) do
+      df.select(Polars.col("d").dt.combine(86_400_000_000_000))
+    end
+    assert_match "time must be within a day", error.message
+  end
+
+  def test_combine_time_zone
+    s = Polars::Series.new(["2024-01-02 03:00"])
+      .str.strptime(:datetime, "%Y-%m-%d %H:%M")
+      .dt.with_time_zone("US/Eastern")
+    out = s.dt.combine(Time.utc(2000, 1, 1, 9, 30))
+    assert_equal :"datetime[μs, US/Eastern]", out.dtype
+    # 03:00 UTC is still January 1 in US/Eastern
+    assert_series [Time.utc(2024, 1, 1, 14, 30).to_i], out.dt.epoch("s")
+  end
+
+  def test_utc_offsets
+    assert_expr dt_expr.base_utc_offset
+    assert_expr dt_expr.dst_offset
+
+    s = Polars.date_range(DateTime.new(2024, 3, 9, 12), DateTime.new(2024, 3, 11, 12), "1d")
+    eastern = s.dt.with_time_zone("US/Eastern")
+    assert_series [-18000, -18000, -18000], eastern.dt.base_utc_offset.dt.seconds
+    assert_series [0, 3600, 3600], eastern.dt.dst_offset.dt.seconds
+
+    error = assert_raises(RuntimeError) do
+      s.dt.dst_offset
+    end
+    assert_match "dst_offset requires a time zone aware datetime", error.message
+  end
+
+  def test_month_boundaries
+    df = Polars::DataFrame.new({
+      "dt" => ["2023-12-31 23:59", "2024-01-01 00:00", "2024-02-10 06:30", nil],
+      "d" => [Date.new(2023, 12, 31), Date.new(2024, 1, 1), Date.new(2024, 2, 10), Date.new(2023, 2, 10)]
+    }).with_column(Polars.col("dt").str.strptime(:datetime, "%Y-%m-%d %H:%M"))
+    out = df.select([
+      Polars.col("dt").dt.month_start.alias("dt_start"),
+      Polars.col("dt").dt.month_end.alias("dt_end"),
+      Polars.col("d").dt.month_start.alias("d_start"),
+      Polars.col("d").dt.month_end.alias("d_end")
+    ])
+    assert_equal df["dt"].dtype, out["dt_start"].dtype
+    assert_equal df["dt"].dtype, out["dt_end"].dtype
+    assert_series ["2023-12-01 23:59", "2024-01-01 00:00", "2024-02-01 06:30", nil], out["dt_start"].dt.strftime("%Y-%m-%d %H:%M")
+    assert_series ["2023-12-31 23:59", "2024-01-31 00:00", "2024-02-29 06:30", nil], out["dt_end"].dt.strftime("%Y-%m-%d %H:%M")
+    assert_series [Date.new(2023, 12, 1), Date.new(2024, 1, 1), Date.new(2024, 2, 1), Date.new(2023, 2, 1)], out["d_start"], dtype: :date
+    assert_series [Date.new(2023, 12, 31), Date.new(2024, 1, 31), Date.new(2024, 2, 29), Date.new(2023, 2, 28)], out["d_end"], dtype: :date
+  end
+
+  def test_month_boundaries_time_zone
+    s = Polars::Series.new(["2024-02-01 03:00", "2024-03-15 16:00"])
+      .str.strptime(:datetime, "%Y-%m-%d %H:%M")
+      .dt.with_time_zone("US/Eastern")
+    starts = [Time.utc(2024, 1, 2, 3), Time.utc(2024, 3, 1, 17)].map(&:to_i)
+    ends = [Time.utc(2024, 2, 1, 3), Time.utc(2024, 3, 31, 16)].map(&:to_i)
+    assert_equal s.dtype, s.dt.month_start.dtype
+    assert_series starts, s.dt.month_start.dt.epoch("s")
+    assert_series ends, s.dt.month_end.dt.epoch("s")
+  end
+
   def dt_expr
     Polars.col("a").dt
   end
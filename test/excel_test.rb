@@ -0,0 +1,46 @@
+require_relative "test_helper"
+
+class ExcelTest < Minitest::Test
+  def test_read_excel
+    df = Polars.read_excel("test/support/data.xlsx")
+    expected = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => ["one", "two", "three"]})
+    assert_frame expected, df
+  end
+
+  def test_read_excel_mixed
+    df = Polars.read_excel("test/support/data.xlsx", sheet_name: "Mixed")
+    assert_equal [:i64, :f64, :str, :bool], df.dtypes
+    assert_series [1, nil, 3], df["int"]
+    assert_series [1.5, 2.5, nil], df["float"]
+    assert_series ["one", nil, "three"], df["str"]
+    assert_series [true, false, true], df["bool"]
+
+    assert_frame df, Polars.read_excel("test/support/data.xlsx", sheet_id: 2)
+  end
+
+  def test_read_excel_missing_sheet
+    error = assert_raises(ArgumentError) do
+      Polars.read_excel("test/support/data.xlsx", sheet_name: "Missing")
+    end
+    assert_equal "sheet not found", error.message
+  end
+
+  def test_read_excel_sheet_id_zero
+    error = assert_raises(ArgumentError) do
+      Polars.read_excel("test/support/data.xlsx", sheet_id: 0)
+    end
+    assert_equal "sheet_id must be greater than 0", error.message
+  end
+
+  def test_write_excel
+    df = Polars::DataFrame.new({
+      "a" => [1, 2, nil],
+      "b" => [1.5, nil, 3.5],
+      "c" => ["one", "two", "three"],
+      "d" => [Date.new(2022, 1, 1), Date.new(2022, 1, 2), nil]
+    })
+    path = "#{temp_path}.xlsx"
+    assert_nil df.write_excel(path, sheet_name: "Data")
+    assert_frame df, Polars.read_excel(path, sheet_name: "Data")
+  end
+end
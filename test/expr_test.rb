@@ -0,0 +1,298 @@
+require_relative "test_helper"
+
+class ExprTest < Minitest::Test
+  def test_not_
+    df = Polars::DataFrame.new({"a" => [true, false, nil], "b" => [1, 2, 3]})
+    assert_series [false, true, nil], df.select(Polars.col("a").not_)["a"], dtype: :bool
+
+    error = assert_raises(RuntimeError) do
+      df.select(Polars.col("b").not_)
+    end
+    assert_match "`not_` operation not supported for dtype `i64`", error.message
+  end
+
+  def test_int_range
+    assert_series [0, 1, 2], Polars.int_range(0, 3, eager: true)
+    assert_series [5, 3, 1], Polars.int_range(5, 0, step: -2, eager: true)
+    assert_series [], Polars.int_range(3, 0, eager: true)
+    assert_series [], Polars.int_range(9223372036854775807, 9223372036854775807, step: -1, eager: true)
+    assert_series [1, 2], Polars.int_range(1, 3, eager: true, dtype: :u8), dtype: :u8
+
+    error = assert_raises(ArgumentError) do
+      Polars.int_range(0, 3, step: 0, eager: true)
+    end
+    assert_equal "step must not be 0", error.message
+  end
+
+  def test_int_range_lazy
+    df = Polars::DataFrame.new({"a" => [1, 2, 3]})
+    out = df.select(Polars.int_range(0, 3))
+    assert_series [0, 1, 2], out["int_range"]
+
+    out = df.lazy.select(Polars.int_range(5, 0, step: -2, dtype: :i16)).collect
+    assert_series [5, 3, 1], out["int_range"], dtype: :i16
+
+    out = df.select(Polars.int_range(0, Polars.col("a").max))
+    assert_series [0, 1, 2], out["int_range"]
+
+    error = assert_raises(ArgumentError) do
+      Polars.int_range(0, 3, step: 0)
+    end
+    assert_equal "step must not be 0", error.message
+
+    error = assert_raises(ArgumentError) do
+      Polars.int_range(0, 3, dtype: :f64)
+    end
+    assert_equal "dtype must be an integer type, got f64", error.message
+  end
+
+  def test_gather
+    df = Polars::DataFrame.new({"a" => [10, 20, 30], "i" => [2, 0, 1]})
+    assert_series [30, 10], df.select(Polars.col("a").gather([2, 0]))["a"]
+    assert_series [30, 10, 20], df.select(Polars.col("a").gather(Polars.col("i")))["a"]
+  end
+
+  def test_gather_out_of_bounds
+    df = Polars::DataFrame.new({"a" => [10, 20, 30]})
+    assert_raises(RuntimeError) do
+      df.select(Polars.col("a").gather([0, 3]))
+    end
+  end
+
+  def test_gather_null_on_oob
+    df = Polars::DataFrame.new({"a" => [10, 20, 30]})
+    out = df.select(Polars.col("a").gather([0, -1, 3], null_on_oob: true))
+    assert_series [10, nil, nil], out["a"]
+  end
+
+  def test_gather_groupby
+    df = Polars::DataFrame.new({"g" => ["one", "one", "two", "two", "two"], "a" => [1, 2, 3, 4, 5]})
+    out = df.groupby("g").agg(Polars.col("a").gather([1, 2], null_on_oob: true)).sort("g")
+    assert_equal [[2, nil], [4, 5]], out["a"].to_a
+  end
+
+  def test_forward_fill_limit
+    df = Polars::DataFrame.new({"a" => [1, nil, nil, nil, 5, nil]})
+    out = df.select([
+      Polars.col("a").forward_fill(limit: 2).alias("forward"),
+      Polars.col("a").backward_fill(limit: 1).alias("backward"),
+      Polars.col("a").forward_fill.alias("unlimited")
+    ])
+    assert_series [1, 1, 1, nil, 5, 5], out["forward"]
+    assert_series [1, nil, nil, 5, 5, nil], out["backward"]
+    assert_series [1, 1, 1, 1, 5, 5], out["unlimited"]
+  end
+
+  def test_repeat_by
+    df = Polars::DataFrame.new({"a" => ["x", "y", "z"], "n" => [2, nil, 0]})
+    out = df.select(Polars.col("a").repeat_by("n"))
+    assert_series [["x", "x"], nil, []], out["a"]
+
+    df = Polars::DataFrame.new({"a" => [1, 2], "n" => [1, -1]})
+    error = assert_raises(RuntimeError) do
+      df.select(Polars.col("a").repeat_by("n"))
+    end
+    assert_match "repeat_by count must not be negative, got -1", error.message
+
+    out = df.select(Polars.col("a").repeat_by("n", negative_as_empty: true))
+    assert_series [[1], []], out["a"]
+  end
+
+  def test_repeat
+    s = Polars.repeat(1, 3, eager: true, name: "a", dtype: :i8)
+    assert_series [1, 1, 1], s, dtype: :i8
+    assert_equal "a", s.name
+
+    s = Polars.repeat("x", 2, eager: true)
+    assert_series ["x", "x"], s
+
+    s = Polars.repeat(nil, 1_000_000, eager: true, dtype: :f64)
+    assert_equal 1_000_000, s.null_count
+    assert_equal :f64, s.dtype
+  end
+
+  def test_replace
+    df = Polars::DataFrame.new({"a" => [1, 2, 3, nil, 1]})
+    out = df.select(Polars.col("a").replace({1 => "a", 2 => "b"}, default: "other"))
+    assert_series ["a", "b", "other", nil, "a"], out["a"]
+
+    out = df.select(Polars.col("a").replace([1, 2], [10, 20]))
+    assert_series [10, 20, 3, nil, 10], out["a"]
+
+    out = df.select(Polars.col("a").replace([1], [5], return_dtype: :f64))
+    assert_series [5.0, 2.0, 3.0, nil, 5.0], out["a"], dtype: :f64
+
+    error = assert_raises(ArgumentError) do
+      Polars.col("a").replace([1, 2], ["a"])
+    end
+    assert_equal "old and new must have the same length, got 2 and 1", error.message
+  end
+
+  def test_int_ranges
+    df = Polars::DataFrame.new({"start" => [1, -1, 3, nil], "end" => [3, 2, 1, 2]})
+    out = df.select(Polars.int_ranges("start", "end"))
+    assert_series [[1, 2], [-1, 0, 1], [], nil], out["start"]
+
+    out = df.select(Polars.int_ranges("end", 0, step: -1, dtype: :i32))
+    assert_series [[3, 2, 1], [2, 1], [1], [2, 1]], out["end"]
+    assert_equal :i32, out["end"].inner_dtype
+
+    error = assert_raises(ArgumentError) do
+      Polars.int_ranges("start", "end", step: 0)
+    end
+    assert_equal "step must not be 0", error.message
+  end
+
+  def test_peak_over
+    df = Polars::DataFrame.new({"id" => [1, 1, 1, 2, 2, 2], "a" => [1, 2, 3, 5, 4, 6]})
+    out = df.select([
+      Polars.col("a").peak_max.over("id").alias("max"),
+      Polars.col("a").peak_min.over("id").alias("min")
+    ])
+    assert_series [false, false, true, true, false, true], out["max"]
+    assert_series [false, false, false, false, true, false], out["min"]
+  end
+
+  def test_gather_every
+    df = Polars::DataFrame.new({"a" => [1, 2, 3, 4, 5, 6, 7, 8]})
+    assert_series [2, 5, 8], df.select(Polars.col("a").gather_every(3, 1))["a"]
+    assert_series [1, 4, 7], df.select(Polars.col("a").take_every(3))["a"]
+
+    error = assert_raises(ArgumentError) do
+      Polars.col("a").take_every(0)
+    end
+    assert_equal "step must be greater than 0", error.message
+  end
+
+  def test_search_sorted_side
+    df = Polars::DataFrame.new({"a" => [1, 2, 2, 3]})
+    out = df.select(Polars.col("a").search_sorted(Polars.lit(Polars::Series.new([0, 2, 9])), side: "right"))
+    assert_series [0, 3, 4], out["a"], dtype: :u32
+  end
+
+  def test_search_sorted_side_datetime
+    df = Polars::DataFrame.new({"t" => ["2023-01-01", "2023-01-02", "2023-01-02", "2023-01-03"]})
+      .with_column(Polars.col("t").str.strptime(:datetime, "%Y-%m-%d"))
+    element = Polars.lit("2023-01-02").str.strptime(:datetime, "%Y-%m-%d")
+    assert_series [1], df.select(Polars.col("t").search_sorted(element, side: "left"))["t"]
+    assert_series [3], df.select(Polars.col("t").search_sorted(element, side: "right"))["t"]
+  end
+
+  def test_replace_mapping_frame
+    mapping = Polars::DataFrame.new({"from" => (0...10_000).to_a, "to" => (0...10_000).map { |i| "v#{i}" }})
+    df = Polars::DataFrame.new({"a" => [1, 9999, 20_000, nil], "b" => ["x", "y", "z", "w"]})
+    out = df.select(Polars.col("a").replace(mapping, default: Polars.col("b")))
+    assert_series ["v1", "v9999", "z", nil], out["a"]
+
+    out = df.select(Polars.col("a").replace(mapping.to_series(0), mapping.to_series(1), default: "other"))
+    assert_series ["v1", "v9999", "other", nil], out["a"]
+
+    assert_series ["v1", "v9999", "20000", nil], df["a"].replace(mapping, return_dtype: :str)
+
+    error = assert_raises(ArgumentError) do
+      Polars.col("a").replace(mapping.to_series(0), mapping.to_series(1).head(5))
+    end
+    assert_equal "old and new must have the same length, got 10000 and 5", error.message
+  end
+
+  def test_map_dict
+    df = Polars::DataFrame.new({"a" => [1, 2, 3, nil], "b" => ["x", "y", "z", "w"]})
+    out = df.select(Polars.col("a").map_dict({1 => "one", 2 => "two"}))
+    assert_series ["one", "two", nil, nil], out["a"]
+
+    mapping = Polars::DataFrame.new({"key" => [1, 3], "value" => ["one", "three"]}).to_struct("mapping")
+    out = df.select(Polars.col("a").map_dict(mapping, default: Polars.col("b")))
+    assert_series ["one", "y", "three", nil], out["a"]
+  end
+
+  def test_cum_count
+    df = Polars::DataFrame.new({"a" => [nil, 1, nil, 3, 4, nil]})
+    out = df.select([
+      Polars.col("a").cum_count.alias("forward"),
+      Polars.col("a").cum_count(reverse: true).alias("reverse"),
+      Polars.col("a").cumcount.alias("index")
+    ])
+    assert_series [0, 1, 1, 2, 3, 3], out["forward"], dtype: :u32
+    assert_series [3, 3, 2, 2, 1, 0], out["reverse"], dtype: :u32
+    assert_series [0, 1, 2, 3, 4, 5], out["index"], dtype: :u32
+  end
+
+  def test_hist
+    df = Polars::DataFrame.new({"a" => [1.5, nil, 2.0, 3.25, nil, 4.0, 0.5, 2.5]})
+    out = df.select(Polars.col("a").hist(bin_count: 5)).unnest("a")
+    assert_series [1, 1, 2, 1, 1], out["count"], dtype: :u32
+    assert_equal 6, out["count"].sum
+    assert_in_delta 4.0, out["break_point"][-1]
+
+    out = df.select(Polars.col("a").hist(bins: [1, 2, 3], include_category: false, include_breakpoint: false))
+    assert_series [2, 1], out["a"], dtype: :u32
+
+    error = assert_raises(ArgumentError) do
+      Polars.col("a").hist(bins: [1, 2], bin_count: 2)
+    end
+    assert_equal "can only provide one of bins or bin_count", error.message
+  end
+
+  def test_to_dummies
+    lf = Polars::DataFrame.new({"a" => ["y", "x", nil, "z"]}).lazy
+    categories = lf.select(Polars.col("a").drop_nulls.unique.sort).collect["a"]
+    out = lf.with_columns(Polars.col("a").to_dummies(categories, separator: ":", drop_first: true)).collect
+    assert_equal ["a", "a:y", "a:z"], out.columns
+    assert_series [true, false, false, false], out["a:y"], dtype: :bool
+    assert_series [false, false, false, true], out["a:z"], dtype: :bool
+  end
+
+  def test_rolling_rank
+    df = Polars::DataFrame.new({"a" => [3, 6, 1, 1, nil, 6, 2]})
+    out = df.select([
+      Polars.col("a").rolling_rank(3).alias("average"),
+      Polars.col("a").rolling_rank(3, min_periods: 1, method: "min").alias("min"),
+      Polars.col("a").rolling_rank(3, min_periods: 2, method: "dense").alias("dense")
+    ])
+    assert_series [nil, nil, 1, 1.5, nil, nil, nil], out["average"], dtype: :f32
+    assert_series [1, 2, 1, 1, nil, 2, 1], out["min"], dtype: :u32
+    assert_series [nil, 2, 1, 1, nil, 2, 1], out["dense"], dtype: :u32
+
+    error = assert_raises(ArgumentError) do
+      Polars.col("a").rolling_rank(3, method: "random")
+    end
+    assert_equal "rolling_rank does not support method 'random'", error.message
+  end
+
+  def test_diff
+    df = Polars::DataFrame.new({"a" => [1, 4, 9, 16, 25], "lag" => [1, 1, 2, -1, 5]})
+    out = df.select(Polars.col("a").diff(n: Polars.col("lag")))
+    assert_series [nil, 3, 8, -9, nil], out["a"]
+
+    out = df.select(Polars.col("a").diff(n: Polars.lit(-2)))
+    assert_series [-8, -12, -16, nil, nil], out["a"]
+
+    error = assert_raises(ArgumentError) do
+      Polars.col("a").diff(n: Polars.col("lag"), null_behavior: "drop")
+    end
+    assert_equal "null_behavior must be 'ignore' when n is an expression", error.message
+  end
+
+  def test_shift_fill_value
+    df = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => [10, 20, 30]})
+    out = df.select(Polars.col("a").shift(1, fill_value: Polars.col("b")))
+    assert_series [10, 1, 2], out["a"]
+  end
+
+  def test_pct_change_fill_method
+    df = Polars::DataFrame.new({"a" => [10, nil, 12, 15]})
+    out = df.select([
+      Polars.col("a").pct_change.alias("forward"),
+      Polars.col("a").pct_change(fill_method: "backward").alias("backward"),
+      Polars.col("a").pct_change(fill_method: nil).alias("none")
+    ])
+    assert_series [nil, 0.0, 0.2, 0.25], out["forward"]
+    assert_series [nil, 0.2, 0.0, 0.25], out["backward"]
+    assert_series [nil, nil, nil, 0.25], out["none"]
+
+    error = assert_raises(ArgumentError) do
+      Polars.col("a").pct_change(fill_method: "pad")
+    end
+    assert_equal "fill_method must be one of {'forward', 'backward', nil}, got pad", error.message
+  end
+end
@@ -30,4 +30,32 @@ class IpcTest < Minitest::Test
     df.write_ipc(path)
     assert_frame df, Polars.read_ipc(path)
   end
+
+  def test_write_ipc_to_string
+    df = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => ["one", "two", "three"]})
+    ["uncompressed", "lz4", "zstd"].each do |compression|
+      bytes = df.write_ipc(compression: compression)
+      assert_equal Encoding::BINARY, bytes.encoding
+      assert_frame df, Polars.read_ipc(StringIO.new(bytes))
+    end
+
+    io = StringIO.new
+    assert_nil df.write_ipc(io)
+    io.rewind
+    assert_frame df, Polars.read_ipc(io)
+
+    error = assert_raises(ArgumentError) do
+      df.write_ipc(compression: "gzip")
+    end
+    assert_equal "compression must be one of {'uncompressed', 'lz4', 'zstd'}, got gzip", error.message
+  end
+
+  def test_sink_ipc
+    df = Polars::DataFrame.new({"a" => [1, 2], "b" => ["one", "two"]})
+    df = df.vstack(Polars::DataFrame.new({"a" => [3], "b" => ["three"]}))
+    assert_equal 2, df.n_chunks
+    path = temp_path
+    assert_nil df.lazy.sink_ipc(path, compression: "lz4")
+    assert_frame df, Polars.read_ipc(path)
+  end
 end
@@ -59,4 +59,75 @@ class LazyFrameTest < Minitest::Test
     df = Polars::DataFrame.new({"a" => [1, 2, 3]}).lazy
     assert_match "FAST_PROJECT", df.select("a").describe_optimized_plan
   end
+
+  def test_with_predicate_pushdown
+    lf = Polars::DataFrame.new({"a" => [1, 2, 3]}).lazy.filter(Polars.col("a") > 1)
+    refute_equal lf.describe_optimized_plan, lf.with_predicate_pushdown(false).describe_optimized_plan
+    assert_frame lf.collect, lf.with_predicate_pushdown(false).collect
+  end
+
+  def test_with_comm_subplan_elim
+    lf = Polars::DataFrame.new({"a" => [1, 2, 3]}).lazy.with_column(Polars.col("a") * 2)
+    joined = lf.join(lf, on: "a")
+    assert_match "CACHE", joined.describe_optimized_plan
+    refute_match "CACHE", joined.with_comm_subplan_elim(false).describe_optimized_plan
+  end
+
+  def test_collect_return_plan
+    df = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => [4, 5, 6]}).lazy
+    out, plan = df.select("a").collect(return_plan: true)
+    assert_frame ({"a" => [1, 2, 3]}), out
+    assert_match "FAST_PROJECT", plan
+  end
+
+  def test_set_sorted
+    lf = Polars::DataFrame.new({"a" => [3, 1, 2]}).lazy
+    out = lf.sort("a").select(Polars.col("a").set_sorted(verify: true)).collect
+    assert out["a"].flags["SORTED_ASC"]
+
+    out = lf.select(Polars.col("a").set_sorted(reverse: true)).collect
+    assert out["a"].flags["SORTED_DESC"]
+
+    error = assert_raises(RuntimeError) do
+      lf.select(Polars.col("a").set_sorted(verify: true)).collect
+    end
+    assert_match "series 'a' is not sorted", error.message
+  end
+
+  def test_shift_fill_value
+    lf = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => ["x", "y", "z"]}).lazy
+    out = lf.shift(1, fill_value: {"a" => 0, "b" => Polars.lit("-")}).collect
+    assert_series [0, 1, 2], out["a"]
+    assert_series ["-", "x", "y"], out["b"]
+
+    out = lf.shift(-1, fill_value: {"a" => 9}).collect
+    assert_series [2, 3, 9], out["a"]
+    assert_series ["y", "z", nil], out["b"]
+
+    out = lf.select("a").shift(-2, fill_value: 0).collect
+    assert_series [3, 0, 0], out["a"]
+
+    error = assert_raises(ArgumentError) do
+      lf.shift(1, fill_value: {"c" => 0})
+    end
+    assert_equal "column 'c' not found", error.message
+  end
+
+  def test_groupby_apply
+    df = Polars::DataFrame.new({"g" => ["a", "b", "a", "b"], "x" => [1.0, 10.0, 3.0, 30.0]})
+    out =
+      df.lazy
+        .groupby("g", maintain_order: true)
+        .apply(schema: {"g" => :str, "x" => :f64}) { |gdf| gdf.with_column(Polars.col("x") / Polars.col("x").sum) }
+        .collect
+    assert_series ["a", "a", "b", "b"], out["g"]
+    assert_series [0.25, 0.75, 0.25, 0.75], out["x"]
+
+    ldf = df.lazy.groupby("g").apply(schema: {"g" => :str}) { |gdf| gdf }
+    assert_equal({"g" => :str}, ldf.schema)
+    error = assert_raises(RuntimeError) do
+      ldf.collect
+    end
+    assert_match "expected schema {g: str}, got {g: str, x: f64}", error.message
+  end
 end
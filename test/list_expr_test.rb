@@ -90,6 +90,36 @@ class ListExprTest < Minitest::Test
     assert_expr arr_expr.eval(rank_pct)
   end
 
+  def test_to_struct_name_generator
+    df = Polars::DataFrame.new({"a" => [[1, 2], [3, 4, 5]]})
+    out = df.select(Polars.col("a").arr.to_struct(upper_bound: 2) { |i| "x#{i}" }).unnest("a")
+    assert_equal ["x0", "x1"], out.columns
+    assert_series [1, 3], out["x0"]
+    assert_series [2, 4], out["x1"]
+
+    out = df["a"].arr.to_struct(name_generator: ->(i) { "y#{i}" }, upper_bound: 3).struct.to_frame
+    assert_equal ["y0", "y1", "y2"], out.columns
+    assert_series [nil, 5], out["y2"]
+
+    df2 = Polars::DataFrame.new({"a" => [[1], [2, 3], nil]})
+    lf = df2.lazy.select(Polars.col("a").arr.to_struct(upper_bound: 3))
+    out = lf.collect.unnest("a")
+    assert_equal ["field_0", "field_1", "field_2"], out.columns
+    assert_equal lf.schema["a"], lf.collect.schema["a"]
+    assert_series [1, 2, nil], out["field_0"]
+    assert_series [nil, 3, nil], out["field_1"]
+    assert_series [nil, nil, nil], out["field_2"]
+
+    assert_raises(RuntimeError) do
+      df.select(Polars.col("a").arr.to_struct(upper_bound: 2, truncate: false))
+    end
+
+    error = assert_raises(ArgumentError) do
+      Polars.col("a").arr.to_struct { |i| "x#{i}" }
+    end
+    assert_equal "name_generator requires upper_bound to be set", error.message
+  end
+
   def arr_expr
     Polars.col("a").arr
   end
@@ -19,6 +19,109 @@ class ParquetTest < Minitest::Test
     assert_frame expected, df.collect
   end
 
+  def test_scan_parquet_glob
+    dir = temp_path
+    Dir.mkdir(dir)
+    3.times do |i|
+      Polars::DataFrame.new({"a" => [i * 2, i * 2 + 1], "b" => ["x", "y"]}).write_parquet(File.join(dir, "part-#{i}.parquet"))
+    end
+
+    df = Polars.scan_parquet(File.join(dir, "*.parquet"), low_memory: true).collect
+    assert_series [0, 1, 2, 3, 4, 5], df["a"]
+
+    df = Polars.scan_parquet(File.join(dir, "*.parquet"), n_rows: 3, row_count_name: "row_nr").collect
+    assert_equal ["row_nr", "a", "b"], df.columns
+    assert_series [0, 1, 2], df["row_nr"]
+
+    Polars::DataFrame.new({"a" => [6], "c" => [1.5]}).write_parquet(File.join(dir, "part-3.parquet"))
+    error = assert_raises(ArgumentError) do
+      Polars.scan_parquet(File.join(dir, "*.parquet"))
+    end
+    assert_match "does not match schema of file", error.message
+
+    error = assert_raises(ArgumentError) do
+      Polars.scan_parquet(File.join(dir, "*.csv"))
+    end
+    assert_match "no files matched glob pattern", error.message
+  end
+
+  def test_scan_parquet_literal_brackets
+    dir = temp_path
+    Dir.mkdir(dir)
+    path = File.join(dir, "data[1].parquet")
+    Polars::DataFrame.new({"a" => [1, 2]}).write_parquet(path)
+    assert_series [1, 2], Polars.scan_parquet(path).collect["a"]
+  end
+
+  def test_scan_parquet_hive_partitioning
+    dir = temp_path
+    [["2023", "01"], ["2023", "02"], ["2024", "01"]].each_with_index do |(year, month), i|
+      path = File.join(dir, "year=#{year}", "month=#{month}")
+      FileUtils.mkdir_p(path)
+      Polars::DataFrame.new({"a" => [i]}).write_parquet(File.join(path, "data.parquet"))
+    end
+
+    df = Polars.scan_parquet(File.join(dir, "**", "*.parquet"), hive_partitioning: true).collect
+    assert_equal ["a", "year", "month"], df.columns
+    assert_series [0, 1, 2], df["a"]
+    assert_series [2023, 2023, 2024], df["year"]
+    assert_series [1, 2, 1], df["month"]
+
+    df = Polars.scan_parquet(File.join(dir, "year=2024", "month=01", "data.parquet"), hive_partitioning: true).collect
+    assert_series [2024], df["year"]
+  end
+
+  def test_write_parquet_partitioned
+    df = Polars::DataFrame.new({"a" => [1, 2, 3, 4], "year" => [2022, 2023, 2022, 2023], "b" => ["x", "y", "x", "z"]})
+    dir = temp_path
+    df.write_parquet(dir, partition_by: ["year", "b"])
+
+    assert File.exist?(File.join(dir, "year=2022", "b=x", "data.parquet"))
+    assert_equal ["a"], Polars.read_parquet(File.join(dir, "year=2023", "b=z", "data.parquet")).columns
+
+    out = Polars.scan_parquet(File.join(dir, "**", "*.parquet"), hive_partitioning: true).collect.sort("a")
+    assert_frame df.select(["a", "year", "b"]), out
+
+    dir = temp_path
+    df.write_parquet(dir, partition_by: "year", include_partition_columns: true)
+    assert_equal ["a", "year", "b"], Polars.read_parquet(File.join(dir, "year=2022", "data.parquet")).columns
+  end
+
+  def test_write_parquet_partitioned_special_values
+    df = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => ["x/y", "k=v", nil]})
+    dir = temp_path
+    df.write_parquet(dir, partition_by: "b", statistics: true, row_group_size: 1)
+
+    assert File.exist?(File.join(dir, "b=x%2Fy", "data.parquet"))
+    assert File.exist?(File.join(dir, "b=k%3Dv", "data.parquet"))
+    assert File.exist?(File.join(dir, "b=__HIVE_DEFAULT_PARTITION__", "data.parquet"))
+
+    out = Polars.scan_parquet(File.join(dir, "**", "*.parquet"), hive_partitioning: true).collect.sort("a")
+    assert_frame df, out
+  end
+
+  def test_read_parquet_columns
+    df = Polars::DataFrame.new({"a" => [1, 2], "b" => [3, 4], "c" => [5, 6], "d" => [7, 8], "e" => [9, 10]})
+    path = temp_path
+    df.write_parquet(path)
+
+    out = Polars.read_parquet(path, columns: ["b", "d"], row_count_name: "row_nr", row_count_offset: 10)
+    assert_equal ["row_nr", "b", "d"], out.columns
+    assert_series [10, 11], out["row_nr"]
+
+    assert_equal 2, Polars.read_parquet(path, columns: [0, 4]).width
+
+    error = assert_raises(ArgumentError) do
+      Polars.read_parquet(path, columns: ["b", "z"])
+    end
+    assert_equal "column 'z' not found in parquet schema", error.message
+
+    error = assert_raises(ArgumentError) do
+      Polars.read_parquet(path, columns: [5])
+    end
+    assert_equal "projection index 5 is out of bounds for 5 columns", error.message
+  end
+
   def test_read_parquet_schema
     schema = Polars.read_parquet_schema("test/support/data.parquet")
     assert_equal ({"a" => :i64, "b" => :str}), schema
@@ -29,6 +132,29 @@ class ParquetTest < Minitest::Test
     assert_nil df.write_parquet(temp_path)
   end
 
+  def test_write_parquet_to_string
+    df = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => ["one", "two", "three"]})
+    bytes = df.write_parquet
+    assert_equal Encoding::BINARY, bytes.encoding
+    assert bytes.start_with?("PAR1")
+    assert_frame df, Polars.read_parquet(StringIO.new(bytes))
+  end
+
+  def test_write_parquet_io
+    df = Polars::DataFrame.new({"a" => [1, 2, 3], "b" => ["one", "two", "three"]})
+    io = StringIO.new
+    io.set_encoding(Encoding::BINARY)
+    assert_nil df.write_parquet(io)
+    io.rewind
+    assert_frame df, Polars.read_parquet(io)
+  end
+
+  def test_sink_parquet
+    path = temp_path
+    assert_nil Polars.scan_csv("test/support/data.csv").filter(Polars.col("a") > 1).sink_parquet(path)
+    assert_equal 2, Polars.read_parquet(path).height
+  end
+
   def test_types
     df = Polars.read_parquet("test/support/types.parquet")
     assert_nil df.write_parquet(temp_path)
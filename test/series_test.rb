@@ -249,6 +249,14 @@ class SeriesTest < Minitest::Test
     refute Polars::Series.new([true, true, false]).all
   end
 
+  def test_any_all_kleene
+    s = Polars::Series.new([false, nil])
+    refute s.any
+    assert_nil s.any(ignore_nulls: false)
+    assert_nil Polars::Series.new([true, nil]).all(ignore_nulls: false)
+    refute Polars::Series.new([false, nil]).all(ignore_nulls: false)
+  end
+
   def test_log
     s = Polars::Series.new([1, 2, 4])
     assert_series [0, 1, 2], s.log(2)
@@ -336,12 +344,33 @@ class SeriesTest < Minitest::Test
     assert_equal "invalid quantile", error.message
   end
 
+  def test_shuffle_nulls
+    s = Polars::Series.new("a", [1, nil, 3, 4, 5, nil, 7, 8, 9, 10])
+    out = s.shuffle(seed: 7)
+    assert_equal "a", out.name
+    assert_equal s.shuffle(seed: 7).to_a, out.to_a
+    assert_equal 2, out.null_count
+    assert_equal s.drop_nulls.sort.to_a, out.drop_nulls.sort.to_a
+  end
+
   # TODO improve
   def test_to_dummies
     s = Polars::Series.new(["a", "b", "b"])
     assert_equal [3, 2], s.to_dummies.shape
   end
 
+  def test_to_dummies_options
+    s = Polars::Series.new("s", ["b", "a", nil, "c"])
+    df = s.to_dummies(separator: ":", drop_first: true)
+    assert_equal ["s:b", "s:c"], df.columns
+    assert_series [1, 0, 0, 0], df["s:b"], dtype: :u8
+
+    df = s.to_dummies(include_null: true)
+    assert_equal ["s_a", "s_b", "s_c", "s_null"], df.columns
+    assert_series [0, 0, 1, 0], df["s_null"], dtype: :u8
+    assert_series [0, 1, 0, 0], df["s_a"], dtype: :u8
+  end
+
   # TODO improve
   def test_value_counts
     s = Polars::Series.new(["a", "b", "b"])
@@ -421,6 +450,29 @@ class SeriesTest < Minitest::Test
     assert_series [1, 2, 3, 4], a
   end
 
+  def test_append_rechunk
+    a = Polars::Series.new([1, 2])
+    a.append(Polars::Series.new([3]))
+    assert_equal 2, a.n_chunks
+    a.append(Polars::Series.new([4]), rechunk: true)
+    assert_equal 1, a.n_chunks
+    assert_series [1, 2, 3, 4], a
+  end
+
+  def test_append_supertype
+    a = Polars::Series.new([1, 2], dtype: :i32)
+    a.append(Polars::Series.new([3], dtype: :i64))
+    assert_series [1, 2, 3], a, dtype: :i64
+
+    a = Polars::Series.new([1, 2], dtype: :i64)
+    a.append(Polars::Series.new([2.5]), append_chunks: false)
+    assert_series [1, 2, 2.5], a, dtype: :f64
+
+    assert_raises(RuntimeError) do
+      a.append(Polars::Series.new(["a"]))
+    end
+  end
+
   def test_filter
     a = Polars::Series.new([1, 2, 3])
     b = Polars::Series.new([true, false, true])
@@ -631,6 +683,28 @@ class SeriesTest < Minitest::Test
     assert_series [1, 2, 3, 99, 99], s.extend_constant(99, 2)
   end
 
+  def test_extend_constant_nil
+    s = Polars::Series.new("a", [1, 2, 3])
+    assert_series [1, 2, 3, nil], s.extend_constant(nil, 1)
+  end
+
+  def test_extend_constant_incompatible
+    s = Polars::Series.new("a", [1, 2, 3])
+    error = assert_raises do
+      s.extend_constant("a", 1)
+    end
+    assert_match "cannot extend series of dtype i64 with value of dtype str", error.message
+  end
+
+  def test_is_sorted
+    assert Polars::Series.new([1, 2, 2, 3]).is_sorted
+    refute Polars::Series.new([1, 3, 2]).is_sorted
+    assert Polars::Series.new([3, 2, 1]).is_sorted(reverse: true)
+    assert Polars::Series.new([nil, 1, 2]).is_sorted
+    refute Polars::Series.new([nil, 1, 2]).is_sorted(nulls_last: true)
+    assert Polars::Series.new([1, 2, nil]).is_sorted(nulls_last: true)
+  end
+
   def test_set_sorted
     s = Polars::Series.new([1, 2, 3])
     refute s.flags["SORTED_ASC"]
@@ -646,4 +720,269 @@ class SeriesTest < Minitest::Test
     s = Polars::Series.new([1, 2, 3])
     assert_series [1, 2, 3], s.shrink_dtype, dtype: :i8
   end
+
+  def test_str_lengths
+    s = Polars::Series.new(["a", "bc", nil])
+    assert_series [1, 2, nil], s.str.lengths
+  end
+
+  def test_str_special_name
+    s = Polars::Series.new("^a.*$", ["a", "bc"])
+    out = s.str.to_uppercase
+    assert_equal "^a.*$", out.name
+    assert_series ["A", "BC"], out
+
+    assert_equal "*", Polars::Series.new("*", ["x"]).str.lengths.name
+  end
+
+  def test_str_non_utf8
+    error = assert_raises(TypeError) do
+      Polars::Series.new([1, 2, 3]).str.to_uppercase
+    end
+    assert_equal "invalid series dtype: expected `Utf8`, got `i64`", error.message
+  end
+
+  def test_dt_year
+    s = Polars::Series.new([Date.new(2022, 1, 1), Date.new(2023, 6, 15)])
+    assert_series [2022, 2023], s.dt.year
+    assert_series [1, 6], s.dt.month
+  end
+
+  def test_dt_non_temporal
+    error = assert_raises(TypeError) do
+      Polars::Series.new(["a"]).dt.year
+    end
+    assert_equal "invalid series dtype: expected `Date`, `Datetime`, or `Time`, got `str`", error.message
+  end
+
+  def test_cast_overflow
+    s = Polars::Series.new([1, 300])
+    assert_raises do
+      s.cast(:u8)
+    end
+    assert_series [1, nil], s.cast(:u8, strict: false)
+    assert_series [1, 44], s.cast(:u8, wrap_numerical: true), dtype: :u8
+  end
+
+  def test_shrink_dtype_categorical
+    s = Polars::Series.new(["a", "b", "a"])
+    assert_equal :str, s.shrink_dtype.dtype
+    assert_equal :cat, s.shrink_dtype(categorical_threshold: 3).dtype
+    assert_equal :str, s.shrink_dtype(categorical_threshold: 2).dtype
+  end
+
+  def test_reinterpret_unsigned
+    s = Polars::Series.new([-1], dtype: :i64)
+    assert_series [18446744073709551615], s.reinterpret(signed: false), dtype: :u64
+    s = Polars::Series.new([-1], dtype: :i32)
+    assert_series [4294967295], s.reinterpret(signed: false), dtype: :u32
+    assert_raises do
+      Polars::Series.new([1], dtype: :i16).reinterpret
+    end
+  end
+
+  def test_cut
+    df = Polars::DataFrame.new({"a" => [0.5, 1.0, 1.5, 2.5, nil]})
+    out = df.select(Polars.col("a").cut([1, 2], labels: ["low", "mid", "high"]))
+    assert_series ["low", "low", "mid", "high", nil], out["a"], dtype: :cat
+
+    out = df.select(Polars.col("a").cut([1, 2], labels: ["low", "mid", "high"], left_closed: true))
+    assert_series ["low", "mid", "mid", "high", nil], out["a"].cast(:str)
+
+    out = df.select(Polars.col("a").cut([1, 2], include_breaks: true)).unnest("a")
+    assert_series [1.0, 1.0, 2.0, Float::INFINITY, nil], out["break_point"]
+    assert_series ["(-inf, 1.0]", "(-inf, 1.0]", "(1.0, 2.0]", "(2.0, inf]", nil], out["category"].cast(:str)
+  end
+
+  def test_cut_invalid
+    error = assert_raises(ArgumentError) do
+      Polars.col("a").cut([])
+    end
+    assert_equal "breaks must not be empty", error.message
+
+    error = assert_raises(ArgumentError) do
+      Polars.col("a").cut([1, 2], labels: ["a", "b"])
+    end
+    assert_equal "expected 3 labels, got 2", error.message
+  end
+
+  def test_series_hist
+    s = Polars::Series.new("a", 0...100)
+    df = s.hist(bin_count: 4)
+    assert_equal ["break_point", "category", "count"], df.columns
+    assert_series [25, 25, 25, 25], df["count"], dtype: :u32
+
+    df = Polars::Series.new("a", [1, 3, 8, 8, 2, 1, 3]).hist(bins: [1, 2, 3])
+    assert_equal ["[1.0, 2.0]", "(2.0, 3.0]"], df["category"].cast(:str).to_a
+
+    error = assert_raises(ArgumentError) do
+      s.hist(bins: [2, 1])
+    end
+    assert_equal "bins must be strictly increasing", error.message
+  end
+
+  def test_qcut
+    df = Polars::DataFrame.new({"a" => [1, 2, 3, 4, 5, 6, 7, 8]})
+    out = df.select(Polars.col("a").qcut([0.25, 0.5, 0.75], labels: ["q1", "q2", "q3", "q4"]))
+    assert_series ["q1", "q1", "q2", "q2", "q3", "q3", "q4", "q4"], out["a"].cast(:str)
+
+    out = df.select(Polars.col("a").qcut(2, include_breaks: true)).unnest("a")
+    assert_series [4.5] * 4 + [Float::INFINITY] * 4, out["break_point"]
+    assert_series ["(-inf, 4.5]"] * 4 + ["(4.5, inf]"] * 4, out["category"].cast(:str)
+  end
+
+  def test_qcut_duplicates
+    df = Polars::DataFrame.new({"a" => [1, 1, 1, 1, 2]})
+    assert_raises(RuntimeError) do
+      df.select(Polars.col("a").qcut([0.25, 0.5]))
+    end
+
+    out = df.select(Polars.col("a").qcut([0.25, 0.5], allow_duplicates: true))
+    assert_series ["(-inf, 1.0]"] * 4 + ["(1.0, inf]"], out["a"].cast(:str)
+  end
+
+  def test_rle
+    df = Polars::DataFrame.new({"a" => [1, 1, 2, 2, 2, 1]})
+    out = df.select(Polars.col("a").rle).unnest("a")
+    assert_series [2, 3, 1], out["lengths"], dtype: :u32
+    assert_series [1, 2, 1], out["values"]
+
+    out = df.select(Polars.col("a").rle_id)
+    assert_series [0, 0, 1, 1, 1, 2], out["a"], dtype: :u32
+  end
+
+  def test_rle_nulls
+    df = Polars::DataFrame.new({"a" => [nil, nil, 1, nil]})
+    out = df.select(Polars.col("a").rle).unnest("a")
+    assert_series [2, 1, 1], out["lengths"]
+    assert_series [nil, 1, nil], out["values"]
+  end
+
+  def test_series_replace
+    s = Polars::Series.new("a", [1, 2, 3, nil])
+    assert_series [10, 2, 30, nil], s.replace({1 => 10, 3 => 30})
+    assert_series [10, -1, -1, nil], s.replace([1], [10], default: -1)
+    assert_equal "a", s.replace([1], [10]).name
+
+    s = Polars::Series.new(["x", "y", nil, "z"])
+    assert_series ["a", "b", nil, "z"], s.replace(["x", "y"], ["a", "b"])
+
+    s = Polars::Series.new(["x", "y", "x"], dtype: :cat)
+    out = s.replace({"x" => "a"})
+    assert_series ["a", "y", "a"], out, dtype: :cat
+  end
+
+  def test_shift_fill_value
+    s = Polars::Series.new("a", [1, 2, 3, 4])
+    assert_series [0, 0, 1, 2], s.shift(2, fill_value: 0)
+    assert_series [2, 3, 4, 9], s.shift(-1, fill_value: 9)
+    assert_series [nil, 1, 2, 3], s.shift(1)
+  end
+
+  def test_cum_reduce
+    s = Polars::Series.new([1, 2, nil, 3])
+    assert_series [1, 3, nil, 6], s.cum_reduce(0) { |acc, x| acc + x }
+    assert_series [1.0, 2.0, nil, 6.0], s.cum_reduce(1, return_dtype: :f64) { |acc, x| acc * x * 1.0 }
+  end
+
+  def test_rolling_apply
+    s = Polars::Series.new("a", [1, 5, 2, 8, 3])
+    assert_series [nil, nil, 4, 6, 6], s.rolling_apply(3) { |w| w.max - w.min }
+    assert_series [0, 4, 4, 6, 6], s.rolling_apply(3, min_periods: 1) { |w| w.max - w.min }
+    assert_series [4, 4, 6, 6, 5], s.rolling_apply(3, min_periods: 1, center: true) { |w| w.max - w.min }
+
+    s = Polars::Series.new([1, nil, 3, 4])
+    assert_series [1, 1, 3, 7], s.rolling_apply(2, min_periods: 1) { |w| w.sum }
+    assert_series [nil, nil, nil, 7], s.rolling_apply(2) { |w| w.sum }
+  end
+
+  def test_search_sorted_side
+    s = Polars::Series.new([1, 2, 2, 2, 3, 5])
+    assert_equal 1, s.search_sorted(2, side: "left")
+    assert_equal 4, s.search_sorted(2, side: "right")
+    assert_equal 5, s.search_sorted(4, side: "left")
+    assert_equal 5, s.search_sorted(4, side: "right")
+
+    error = assert_raises(ArgumentError) do
+      s.search_sorted(2, side: "middle")
+    end
+    assert_equal "side must be one of {'any', 'left', 'right'}, got middle", error.message
+  end
+
+  def test_search_sorted_side_large_int
+    big = 2**62
+    s = Polars::Series.new([big, big + 1, big + 1, big + 2])
+    assert_equal 1, s.search_sorted(big + 1, side: "left")
+    assert_equal 3, s.search_sorted(big + 1, side: "right")
+
+    s = Polars::Series.new([0, 2**63, 2**64 - 1], dtype: :u64)
+    assert_equal 2, s.search_sorted(Polars::Series.new([2**63], dtype: :u64), side: "right")
+    assert_equal 0, s.search_sorted(-1, side: "left")
+  end
+
+  def test_to_numo
+    s = Polars::Series.new([1, 2, 3])
+    assert_kind_of Numo::Int64, s.to_numo
+    assert_equal [1, 2, 3], s.to_numo.to_a
+
+    s = Polars::Series.new([1.5, 2.5], dtype: :f32)
+    assert_kind_of Numo::SFloat, s.to_numo
+    assert_equal [1.5, 2.5], s.to_numo.to_a
+
+    s = Polars::Series.new(["a", "b"])
+    assert_kind_of Numo::RObject, s.to_numo
+    assert_equal ["a", "b"], s.to_numo.to_a
+  end
+
+  def test_to_numo_nulls
+    s = Polars::Series.new([1, nil, 3])
+    error = assert_raises(ArgumentError) do
+      s.to_numo
+    end
+    assert_equal "Series contains nulls; set fill_null to convert it", error.message
+    assert_equal [1, 0, 3], s.to_numo(fill_null: 0).to_a
+  end
+
+  def test_to_numo_large
+    s = Polars::Series.new(1_000_000.times.to_a)
+    numo = s.to_numo
+    assert_kind_of Numo::Int64, numo
+    assert_equal 1_000_000, numo.size
+    assert_equal s.to_a, numo.to_a
+  end
+
+  def test_to_struct
+    s = Polars::Series.new("a", ["x", "1", "y", "2", "z", "3"])
+    out = s.to_struct(["name", "value"])
+    assert_equal "a", out.name
+    df = out.struct.to_frame
+    assert_equal ["name", "value"], df.columns
+    assert_series ["x", "y", "z"], df["name"]
+    assert_series ["1", "2", "3"], df["value"]
+
+    error = assert_raises(ArgumentError) do
+      s.to_struct(["a", "b", "c", "d"])
+    end
+    assert_equal "length 6 is not divisible by the number of fields (4)", error.message
+  end
+
+  def test_gather_every
+    s = Polars::Series.new([1, 2, 3, 4, 5])
+    assert_series [1, 3, 5], s.gather_every(2)
+    assert_series [2, 5], s.gather_every(3, 1)
+    assert_series [], s.gather_every(2, 10)
+
+    error = assert_raises(ArgumentError) do
+      s.gather_every(0)
+    end
+    assert_equal "step must be greater than 0", error.message
+  end
+
+  def test_series_rle
+    s = Polars::Series.new([1, 1, 2, 2, 2, 1, nil, nil])
+    out = s.rle.struct.to_frame
+    assert_series [2, 3, 1, 2], out["lengths"]
+    assert_series [1, 2, 1, nil], out["values"]
+    assert_series [0, 0, 1, 1, 1, 2, 3, 3], s.rle_id, dtype: :u32
+  end
 end
@@ -49,6 +49,12 @@ class StringExprTest < Minitest::Test
     assert_expr str_expr.contains("pattern")
   end
 
+  def test_contains_case_insensitive
+    s = Polars::Series.new(["ERROR: disk", "error: net", "warn", nil])
+    assert_series [true, true, false, nil], s.str.contains("error", case_insensitive: true)
+    assert_series [true, true, false, nil], s.str.contains("error:", literal: true, case_insensitive: true)
+  end
+
   def test_ends_with
     assert_expr str_expr.ends_with("sub")
   end
@@ -65,6 +71,27 @@ class StringExprTest < Minitest::Test
     assert_expr str_expr.extract_all("pattern")
   end
 
+  def test_extract_groups
+    assert_expr str_expr.extract_groups("(a)(b)")
+
+    df = Polars::DataFrame.new({"date" => ["2023-01-15", "2024-12-03", nil]})
+    out = df.select(
+      Polars.col("date").str.extract_groups('(?<year>\d{4})-(?<month>\d{2})-(?<day>\d{2})')
+    ).unnest("date")
+    assert_equal ["year", "month", "day"], out.columns
+    assert_series ["2023", "2024", nil], out["year"]
+    assert_series ["01", "12", nil], out["month"]
+    assert_series ["15", "03", nil], out["day"]
+
+    s = Polars::Series.new(["a1", "b2"])
+    assert_equal ["1", "2"], s.str.extract_groups('([a-z])(\d)').struct.fields
+
+    error = assert_raises(ArgumentError) do
+      str_expr.extract_groups("abc")
+    end
+    assert_equal "pattern must contain at least one capture group", error.message
+  end
+
   def test_count_match
     assert_expr str_expr.count_match("pattern")
   end
@@ -95,6 +122,66 @@ class StringExprTest < Minitest::Test
     assert_expr str_expr.slice(1)
   end
 
+  def test_contains_any
+    assert_expr str_expr.contains_any(["cat", "dog"])
+
+    s = Polars::Series.new(["my cat", "Hotdog", "DOG", "bird", nil])
+    assert_series [true, true, false, false, nil], s.str.contains_any(["cat", "dog"])
+    assert_series [true, true, true, false, nil], s.str.contains_any(["cat", "dog"], ascii_case_insensitive: true)
+    assert_series [false, false, false, false, nil], s.str.contains_any([])
+  end
+
+  def test_replace_many
+    assert_expr str_expr.replace_many(["a", "b"], ["1", "2"])
+
+    s = Polars::Series.new(["abc", "cab", "ABC", nil])
+    assert_series ["12c", "c12", "ABC", nil], s.str.replace_many({"a" => "1", "b" => "2"})
+    assert_series ["12c", "c12", "12C", nil], s.str.replace_many({"a" => "1", "b" => "2"}, ascii_case_insensitive: true)
+    assert_series ["__c", "c__", "ABC", nil], s.str.replace_many(["a", "b"], "_")
+    # replacements are not matched again
+    assert_series ["bac", "cba", "ABC", nil], s.str.replace_many(["a", "b"], ["b", "a"])
+
+    error = assert_raises(ArgumentError) do
+      s.str.replace_many(["a", "b", "c"], ["1", "2"])
+    end
+    assert_equal "expected 1 or 3 replacements, got 2", error.message
+  end
+
+  def test_reverse
+    assert_expr str_expr.reverse
+
+    s = Polars::Series.new(["abc", nil, "", "añ😀b"])
+    assert_series ["cba", nil, "", "b😀ña"], s.str.reverse
+  end
+
+  def test_pad_start
+    assert_expr str_expr.pad_start(8, "*")
+
+    s = Polars::Series.new(["ab", "abcd", nil])
+    assert_series ["**ab", "abcd", nil], s.str.pad_start(4, "*")
+    assert_series ["ab**", "abcd", nil], s.str.pad_end(4, "*")
+    assert_series ["  ab", "abcd", nil], s.str.pad_start(4)
+  end
+
+  def test_to_integer
+    assert_expr str_expr.to_integer(base: 16)
+
+    df = Polars::DataFrame.new({"hex" => ["ff", "0A", "zz", nil], "bin" => ["101", "1", "12", nil]})
+    out = df.select([
+      Polars.col("hex").str.to_integer(base: 16, strict: false),
+      Polars.col("bin").str.to_integer(base: 2, strict: false)
+    ])
+    assert_series [255, 10, nil, nil], out["hex"], dtype: :i64
+    assert_series [5, 1, nil, nil], out["bin"], dtype: :i64
+
+    [["hex", 16, "zz"], ["bin", 2, "12"]].each do |name, base, value|
+      error = assert_raises(RuntimeError) do
+        df.select(Polars.col(name).str.to_integer(base: base))
+      end
+      assert_match "strict integer parsing failed for value '#{value}' with base #{base}", error.message
+    end
+  end
+
   def str_expr
     Polars.col("a").str
   end
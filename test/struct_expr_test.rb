@@ -15,6 +15,31 @@ class StructExprTest < Minitest::Test
     assert_expr struct_expr.field("a")
   end
 
+  def test_field_path
+    df = Polars::DataFrame.new({"b" => [1, 2], "c" => ["x", "y"]})
+      .select(Polars.struct(["b", Polars.struct(["c"]).alias("d")]).alias("a"))
+      .select(Polars.struct(["a"]).alias("s"))
+
+    out = df.select(Polars.col("s").struct.field("a.d.c"))
+    assert_equal ["c"], out.columns
+    assert_series ["x", "y"], out["c"]
+    assert_series [1, 2], df["s"].struct.field("a.b")
+
+    error = assert_raises(RuntimeError) do
+      df.select(Polars.col("s").struct.field("a.e.c"))
+    end
+    assert_match "struct field 'e' not found", error.message
+  end
+
+  def test_field_dotted_name
+    df = Polars::DataFrame.new({"a.b" => [1, 2], "c" => ["x", "y"]})
+      .select(Polars.struct(["a.b", "c"]).alias("s"))
+    out = df.select(Polars.col("s").struct.field("a.b"))
+    assert_equal ["a.b"], out.columns
+    assert_series [1, 2], out["a.b"]
+    assert_series [1, 2], df["s"].struct.field("a.b")
+  end
+
   def test_rename_fields
     assert_expr struct_expr.rename_fields(["a"])
   end